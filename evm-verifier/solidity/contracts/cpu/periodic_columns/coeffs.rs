@@ -1,2 +0,0 @@
-const COEFFS: [U256; 512] = [];
-// COEFFS LEN: 512;
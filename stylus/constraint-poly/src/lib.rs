@@ -5,10 +5,11 @@
 #[macro_use]
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
-use stylus_sdk::alloy_primitives::{Address, U256};
+use stylus_sdk::alloy_primitives::{Address, FixedBytes, U256};
 use stylus_sdk::{prelude::*, storage::StorageAddress};
+use utils::{dependencies::Dependencies, ownable::Ownable};
 
 mod interfaces;
 use crate::interfaces::{IConstraintPolyPreparer, IConstraintPolyFinalizer};
@@ -18,21 +19,25 @@ use crate::interfaces::{IConstraintPolyPreparer, IConstraintPolyFinalizer};
 #[storage]
 #[entrypoint]
 pub struct ConstraintPoly {
+    #[borrow]
+    ownable: Ownable,
+    dependencies: Dependencies,
     preparer_address: StorageAddress,
     finalizer_address: StorageAddress,
 }
 
 #[public]
+#[inherit(Ownable)]
 impl ConstraintPoly {
-    
+    #[constructor]
+    fn constructor(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable.init_owner(owner)
+    }
+
     #[inline]
     fn compute(&mut self, _calldata: Vec<U256>) -> Result<U256, Vec<u8>> {
-        if self.preparer_address.get().is_zero() {
-            return Err(format!("Preparer address not set",).into());
-        }
-        if self.finalizer_address.get().is_zero() {
-            return Err(format!("Finalizer address not set",).into());
-        }
+        self.ownable.only_initialized()?;
+        self.ownable.when_not_paused()?;
         
         let preparer: IConstraintPolyPreparer = IConstraintPolyPreparer { address: self.preparer_address.get() };
         let finalizer: IConstraintPolyFinalizer = IConstraintPolyFinalizer { address: self.finalizer_address.get() };
@@ -43,8 +48,28 @@ impl ConstraintPoly {
         Ok(poly_data_result)
     }
 
-    fn set_addresses(&mut self, preparer_address: Address, finalizer_address: Address) {
-        self.preparer_address.set(preparer_address);
-        self.finalizer_address.set(finalizer_address);
+    // Can only be called once, by the owner.
+    fn set_addresses(&mut self, preparer_address: Address, finalizer_address: Address) -> Result<(), Vec<u8>> {
+        self.ownable.initialize()?;
+        self.dependencies.set(&mut self.preparer_address, "preparer", preparer_address);
+        self.dependencies.set(&mut self.finalizer_address, "finalizer", finalizer_address);
+        Ok(())
+    }
+
+    // Reverts with DependencyCodeChanged if the code of a dependency is not the one pinned by
+    // setAddresses.
+    fn check_wiring(&self) -> Result<(), Vec<u8>> {
+        self.dependencies.check_all(&self.wiring())
+    }
+
+    // The names, addresses and pinned code hashes of the dependencies.
+    fn verifier_config(&self) -> (Vec<String>, Vec<Address>, Vec<FixedBytes<32>>) {
+        self.dependencies.config(&self.wiring())
+    }
+}
+
+impl ConstraintPoly {
+    fn wiring(&self) -> [(&'static str, Address); 2] {
+        [("preparer", self.preparer_address.get()), ("finalizer", self.finalizer_address.get())]
     }
 }
@@ -21,11 +21,20 @@ sol_interface! {
         function compute(uint256 value) external view returns(uint256);
     }
 
+    interface IPeriodicColumns {
+        function computeAll(uint256 x) external view returns(uint256[] memory);
+    }
+
     interface IInitVerifier {
         function initVerifierParams(uint256[] memory public_input, uint256[] memory proof_params) external view returns(uint256[] memory ctx, uint256[] memory fri_step_sizes);
     }
 
+    interface IMemoryPageFactRegistry {
+        function isValid(bytes32 fact) external view returns(bool);
+    }
+
     interface IFriStatementVerifier {
         function verify(uint256[] memory proof, uint256[] memory ctx, uint256[] memory fri_step_sizes) external view returns(uint256[] memory);
+        function verifyCompact(bytes calldata proof, uint256[] memory ctx, uint256[] memory fri_step_sizes) external view returns(uint256[] memory);
     }
 }
@@ -1,41 +1,36 @@
 #![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
 
-#[cfg(test)]
-#[path = "tests/test_constants.rs"]
-pub mod test_constants;
-
-
-#[path = "stark-verifier.rs"]
-pub mod stark_verifier;
-#[path = "layout-specific.rs"]
-pub mod layout_specific;
-#[path = "verifier-channel.rs"]
-pub mod verifier_channel;
 mod interfaces;
 
 #[macro_use]
 extern crate alloc;
-use alloc::vec::Vec;
-use utils::{
-    require,
-    prime_field_element0::PrimeFieldElement0,
-    public_memory_offset::PublicMemoryOffset
-};
-
-use crate::stark_verifier::StarkVerifier;
-use crate::layout_specific::LayoutSpecific;
-use crate::interfaces::{IConstraint, IConstraintPoly, IInitVerifier, IFriStatementVerifier};
+use alloc::{string::String, vec::Vec};
+
+use stark_verifier_core::layout_specific::{self, LayoutSpecific};
+use stark_verifier_core::periodic_columns::PeriodicColumn;
+use stark_verifier_core::compact::StarkInput;
+use stark_verifier_core::proof::ProofWords;
+use stark_verifier_core::StarkVerifier;
+#[cfg(not(feature = "inline-periodic-columns"))]
+use crate::interfaces::{IConstraint, IPeriodicColumns};
+#[cfg(not(feature = "inline-periodic-columns"))]
+use stark_verifier_core::periodic_columns::PeriodicColumnFamily;
+use crate::interfaces::{IConstraintPoly, IInitVerifier, IFriStatementVerifier, IMemoryPageFactRegistry};
 
 use stylus_sdk::{
-    alloy_primitives::{FixedBytes, U256, uint, Address},
-    crypto::keccak,
+    abi::Bytes,
+    alloy_primitives::{FixedBytes, U256, Address},
     prelude::*,
 };
+use utils::{dependencies::Dependencies, ownable::Ownable};
 
 
 sol_storage! {
     #[entrypoint]
     pub struct CpuVerifier {
+        #[borrow]
+        Ownable ownable;
+        Dependencies dependencies;
         address oods;
         address merkle_statement;
         address fri_statement;
@@ -49,158 +44,101 @@ sol_storage! {
         address poseidon_poseidon_partial_round_key1;
         address init_verifier;
         address fri_statement_verifier;
+        address memory_page_fact_registry;
+        address pedersen_columns;
+        address poseidon_columns;
     }
 }
 
 impl LayoutSpecific for CpuVerifier {
-    fn get_pedersen_points_x(&self) -> IConstraint {
-        IConstraint { address: self.pedersen_points_x.get() }
+    // The column addresses set by init are not used in this mode.
+    #[cfg(feature = "inline-periodic-columns")]
+    fn compute_periodic_column(&mut self, column: PeriodicColumn, x: U256) -> Result<U256, Vec<u8>> {
+        Ok(stark_verifier_core::periodic_columns::compute(column, x))
     }
 
-    fn get_pedersen_points_y(&self) -> IConstraint {
-        IConstraint { address: self.pedersen_points_y.get() }
+    #[cfg(not(feature = "inline-periodic-columns"))]
+    fn compute_periodic_column(&mut self, column: PeriodicColumn, x: U256) -> Result<U256, Vec<u8>> {
+        let address = match column {
+            PeriodicColumn::PedersenPointsX => self.pedersen_points_x.get(),
+            PeriodicColumn::PedersenPointsY => self.pedersen_points_y.get(),
+            PeriodicColumn::PoseidonFullRoundKey0 => self.poseidon_poseidon_full_round_key0.get(),
+            PeriodicColumn::PoseidonFullRoundKey1 => self.poseidon_poseidon_full_round_key1.get(),
+            PeriodicColumn::PoseidonFullRoundKey2 => self.poseidon_poseidon_full_round_key2.get(),
+            PeriodicColumn::PoseidonPartialRoundKey0 => self.poseidon_poseidon_partial_round_key0.get(),
+            PeriodicColumn::PoseidonPartialRoundKey1 => self.poseidon_poseidon_partial_round_key1.get(),
+        };
+        Ok(IConstraint { address }.compute(&mut *self, x)?)
     }
 
-    fn get_poseidon_poseidon_full_round_key0(&self) -> IConstraint {
-        IConstraint { address: self.poseidon_poseidon_full_round_key0.get() }
-    }
-
-    fn get_poseidon_poseidon_full_round_key1(&self) -> IConstraint {
-        IConstraint { address: self.poseidon_poseidon_full_round_key1.get() }
-    }
-
-    fn get_poseidon_poseidon_full_round_key2(&self) -> IConstraint {
-        IConstraint { address: self.poseidon_poseidon_full_round_key2.get() }
-    }
-
-    fn get_poseidon_poseidon_partial_round_key0(&self) -> IConstraint {
-        IConstraint { address: self.poseidon_poseidon_partial_round_key0.get() }
-    }
-
-    fn get_poseidon_poseidon_partial_round_key1(&self) -> IConstraint {
-        IConstraint { address: self.poseidon_poseidon_partial_round_key1.get() }
+    // One call to the batched contract of the family if it is set, otherwise one call per column.
+    #[cfg(not(feature = "inline-periodic-columns"))]
+    fn compute_periodic_columns(&mut self, family: PeriodicColumnFamily, x: U256) -> Result<Vec<U256>, Vec<u8>> {
+        let address = match family {
+            PeriodicColumnFamily::Pedersen => self.pedersen_columns.get(),
+            PeriodicColumnFamily::Poseidon => self.poseidon_columns.get(),
+        };
+        if address.is_zero() {
+            return family.columns().iter().map(|&column| self.compute_periodic_column(column, x)).collect();
+        }
+        Ok(IPeriodicColumns { address }.compute_all(&mut *self, x)?)
     }
-    
-}   
+}
 
 impl StarkVerifier for CpuVerifier {
-    
-    fn oods_consistency_check(&mut self, ctx: &mut [U256], public_input: &[U256]) -> Result<(), Vec<u8>> {
-        CpuVerifier::verify_memory_page_facts(ctx, public_input);
-        ctx[331] = ctx[352];
-        ctx[332] = ctx[353];
-        ctx[334] = ctx[354];
-        
-        let public_memory_prod = CpuVerifier::compute_public_memory_quotient(ctx, public_input)?;
-        ctx[333] = public_memory_prod;
-
-        self.prepare_for_oods_check(ctx)?;
-
-        let constraint_poly_contract: IConstraintPoly = IConstraintPoly { address: self.constraint_poly.get() };
-        let composition_from_trace_value = constraint_poly_contract.compute(&mut *self, ctx[317..551].to_vec())?;
-        let claimed_composition = PrimeFieldElement0::fadd(ctx[551], PrimeFieldElement0::fmul(ctx[351], ctx[552]));
-        require!(composition_from_trace_value == claimed_composition, "claimedComposition does not match trace");
-        
-        Ok(())
-    }
-
-    fn get_public_input_hash(public_input: &[U256]) -> FixedBytes<32> {
-        let n_pages = public_input[21].to::<usize>();
-        let offset_page_prod = PublicMemoryOffset::get_offset_page_prod(0, n_pages);
-        let mut input_data = Vec::new();
-        for i in 0..offset_page_prod {
-            input_data.extend_from_slice(&public_input[i].to_be_bytes::<32>());
-        }
-
-        keccak(&input_data).into()
+    fn init_verifier_params(
+        &mut self,
+        public_input: &[U256],
+        proof_params: &[U256],
+    ) -> Result<(Vec<U256>, Vec<U256>), Vec<u8>> {
+        let init_verifier = IInitVerifier { address: self.init_verifier.get() };
+        Ok(init_verifier.init_verifier_params(&mut *self, public_input.to_vec(), proof_params.to_vec())?)
     }
 
-    fn get_init_verifier(&self) -> IInitVerifier {
-        IInitVerifier { address: self.init_verifier.get() }
+    fn is_memory_page_fact_valid(&mut self, fact: FixedBytes<32>) -> Result<bool, Vec<u8>> {
+        let memory_page_fact_registry =
+            IMemoryPageFactRegistry { address: self.dependencies.check(self.memory_page_fact_registry.get())? };
+        Ok(memory_page_fact_registry.is_valid(&*self, fact)?)
     }
 
-    fn get_fri_statement_verifier(&self) -> IFriStatementVerifier {
-        IFriStatementVerifier { address: self.fri_statement_verifier.get() }
+    fn compute_constraint_poly(&mut self, calldata: &[U256]) -> Result<U256, Vec<u8>> {
+        let constraint_poly_contract = IConstraintPoly { address: self.constraint_poly.get() };
+        Ok(constraint_poly_contract.compute(&mut *self, calldata.to_vec())?)
     }
-}
-
-impl CpuVerifier {
 
-    pub fn verify_memory_page_facts(ctx: &[U256], public_input: &[U256]) {
-        let n_public_memory_pages = ctx[1276].to::<usize>();
-        for page in 0..n_public_memory_pages {
-            let memory_hash_ptr = ctx[5].to::<usize>() + PublicMemoryOffset::get_offset_page_hash(page);
-            let prod_ptr = ctx[5].to::<usize>() + PublicMemoryOffset::get_offset_page_prod(page, n_public_memory_pages);
-            let page_size_ptr = ctx[5].to::<usize>() + PublicMemoryOffset::get_offset_page_size(page);
-
-            let memory_hash = public_input[memory_hash_ptr];
-            let prod = public_input[prod_ptr];
-            let page_size = public_input[page_size_ptr];
-
-            let mut page_addr = U256::ZERO;
-            if page > 0 {
-                let page_addr_ptr = ctx[5].to::<usize>() + PublicMemoryOffset::get_offset_page_addr(page);
-                page_addr = public_input[page_addr_ptr];
-            }
-
-            let mut page_type = U256::from(1);
-            if page == 0 {
-                page_type = U256::from(0);
+    fn verify_fri(
+        &mut self,
+        proof: ProofWords,
+        ctx: &[U256],
+        fri_step_sizes: &[U256],
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        let fri_statement_verifier =
+            IFriStatementVerifier { address: self.dependencies.check(self.fri_statement_verifier.get())? };
+        // A compact proof is forwarded as its bytes, which the FRI statement verifier reads in place.
+        let ctx = match proof {
+            ProofWords::Words(proof) => {
+                fri_statement_verifier.verify(&mut *self, proof.to_vec(), ctx.to_vec(), fri_step_sizes.to_vec())?
             }
-            let mut hash_buffer = Vec::new();
-            hash_buffer.extend_from_slice(&page_type.to_be_bytes::<32>());
-            hash_buffer.extend_from_slice(&PrimeFieldElement0::K_MODULUS.to_be_bytes::<32>());
-            hash_buffer.extend_from_slice(&page_size.to_be_bytes::<32>());
-            hash_buffer.extend_from_slice(&ctx[352].to_be_bytes::<32>());
-            hash_buffer.extend_from_slice(&ctx[353].to_be_bytes::<32>());
-            hash_buffer.extend_from_slice(&prod.to_be_bytes::<32>());
-            hash_buffer.extend_from_slice(&memory_hash.to_be_bytes::<32>());
-            hash_buffer.extend_from_slice(&page_addr.to_be_bytes::<32>());
-            // let fact_hash_output: FixedBytes<32> = keccak(&hash_buffer).into();
-
-            // Lyubo: Use memory_page_fact_registry from the storage
-            // require!(memory_page_fact_registry.is_valid(fact_hash_output), b"Memory page fact was not registered.");
-        }
-    }
-
-    pub fn compute_public_memory_quotient(ctx: &[U256], public_input: &[U256]) -> Result<U256, Vec<u8>> {
-        let n_values = ctx[1275];
-        let z = ctx[331];
-        let alpha = ctx[332];
-        
-        let public_memory_size = Self::safe_div(ctx[324], U256::from(16))?;
-        require!(n_values < uint!(16777216_U256), "Overflow protection failed.");
-        require!(n_values <= public_memory_size, "Number of values of public memory is too large.");
-
-        let n_public_memory_pages = ctx[1276].to::<usize>();
-        let cumulative_prods_ptr = ctx[5].to::<usize>() + PublicMemoryOffset::get_offset_page_prod(0, n_public_memory_pages);
-        let denominator = Self::compute_public_memory_prod(public_input, cumulative_prods_ptr, n_public_memory_pages, PrimeFieldElement0::K_MODULUS);
-        
-        let padding_addr_ptr = ctx[5].to::<usize>() + 19;
-        let padding_addr = public_input[padding_addr_ptr];
-        let padding_value = public_input[padding_addr_ptr + 1];
-        
-        let hash_first_address_value = PrimeFieldElement0::fadd(padding_addr, PrimeFieldElement0::fmul(padding_value, alpha));
-        let denom_pad = PrimeFieldElement0::fpow(PrimeFieldElement0::fsub(z, hash_first_address_value), public_memory_size - n_values);
-        let denominator = PrimeFieldElement0::fmul(denominator, denom_pad);
-        let numerator = PrimeFieldElement0::fpow(z, public_memory_size);
-        let result = PrimeFieldElement0::fmul(numerator, PrimeFieldElement0::inverse(denominator));
-        Ok(result)
-    }
-
-    pub fn compute_public_memory_prod(public_input: &[U256], cumulative_prods_ptr: usize, n_public_memory_pages: usize, prime: U256) -> U256 {
-        let mut res = U256::from(1);
-        let last_ptr = cumulative_prods_ptr + n_public_memory_pages;
-        for ptr in cumulative_prods_ptr..last_ptr {
-            res = res.mul_mod(public_input[ptr], prime);
-        }
-        res
+            ProofWords::Bytes(proof) => fri_statement_verifier.verify_compact(
+                &mut *self,
+                proof.to_vec().into(),
+                ctx.to_vec(),
+                fri_step_sizes.to_vec(),
+            )?,
+        };
+        Ok(ctx)
     }
 }
 
 #[public]
+#[inherit(Ownable)]
 impl CpuVerifier {
+    #[constructor]
+    pub fn constructor(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable.init_owner(owner)
+    }
 
+    // Can only be called once, by the owner.
     #[inline]
     pub fn init(
         &mut self,
@@ -214,26 +152,276 @@ impl CpuVerifier {
         poseidon_poseidon_partial_round_key1: Address,
         init_verifier: Address,
         fri_statement_verifier: Address,
-    ) {
-        self.constraint_poly.set(constraint_poly);
-        self.pedersen_points_x.set(pedersen_points_x);
-        self.pedersen_points_y.set(pedersen_points_y);
-        self.poseidon_poseidon_full_round_key0.set(poseidon_poseidon_full_round_key0);
-        self.poseidon_poseidon_full_round_key1.set(poseidon_poseidon_full_round_key1);
-        self.poseidon_poseidon_full_round_key2.set(poseidon_poseidon_full_round_key2);
-        self.poseidon_poseidon_partial_round_key0.set(poseidon_poseidon_partial_round_key0);
-        self.poseidon_poseidon_partial_round_key1.set(poseidon_poseidon_partial_round_key1);
-        self.init_verifier.set(init_verifier);
-        self.fri_statement_verifier.set(fri_statement_verifier);
+        memory_page_fact_registry: Address,
+    ) -> Result<(), Vec<u8>> {
+        self.ownable.initialize()?;
+        self.dependencies.set(&mut self.constraint_poly, "constraintPoly", constraint_poly);
+        self.dependencies.set(&mut self.pedersen_points_x, "pedersenPointsX", pedersen_points_x);
+        self.dependencies.set(&mut self.pedersen_points_y, "pedersenPointsY", pedersen_points_y);
+        self.dependencies.set(
+            &mut self.poseidon_poseidon_full_round_key0,
+            "poseidonPoseidonFullRoundKey0",
+            poseidon_poseidon_full_round_key0,
+        );
+        self.dependencies.set(
+            &mut self.poseidon_poseidon_full_round_key1,
+            "poseidonPoseidonFullRoundKey1",
+            poseidon_poseidon_full_round_key1,
+        );
+        self.dependencies.set(
+            &mut self.poseidon_poseidon_full_round_key2,
+            "poseidonPoseidonFullRoundKey2",
+            poseidon_poseidon_full_round_key2,
+        );
+        self.dependencies.set(
+            &mut self.poseidon_poseidon_partial_round_key0,
+            "poseidonPoseidonPartialRoundKey0",
+            poseidon_poseidon_partial_round_key0,
+        );
+        self.dependencies.set(
+            &mut self.poseidon_poseidon_partial_round_key1,
+            "poseidonPoseidonPartialRoundKey1",
+            poseidon_poseidon_partial_round_key1,
+        );
+        self.dependencies.set(&mut self.init_verifier, "initVerifier", init_verifier);
+        self.dependencies.set(&mut self.fri_statement_verifier, "friStatementVerifier", fri_statement_verifier);
+        self.dependencies.set(
+            &mut self.memory_page_fact_registry,
+            "memoryPageFactRegistry",
+            memory_page_fact_registry,
+        );
+        Ok(())
+    }
+
+    // Sets the contracts that evaluate all the Pedersen or all the Poseidon columns in one call,
+    // see pedersen-columns and poseidon-columns. A zero address keeps the column contracts set by
+    // init for that family. Only the owner can call it.
+    pub fn set_batched_periodic_columns(
+        &mut self,
+        pedersen_columns: Address,
+        poseidon_columns: Address,
+    ) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner()?;
+        self.dependencies.set(&mut self.pedersen_columns, "pedersenColumns", pedersen_columns);
+        self.dependencies.set(&mut self.poseidon_columns, "poseidonColumns", poseidon_columns);
+        Ok(())
     }
 
     #[inline]
     pub fn verify_proof_external(
         &mut self,
         proof_params: Vec<U256>,
-        mut proof: Vec<U256>,
+        proof: Vec<U256>,
         public_input: Vec<U256>,
     ) -> Result<Vec<U256>, Vec<u8>> {
-        Ok(self.verify_proof(&proof_params, &mut proof, &public_input)?)
+        self.ownable.when_not_paused()?;
+        StarkVerifier::verify_proof(self, &proof_params, (&proof).into(), &public_input)
+    }
+
+    // Same as verify_proof_external, with the input in the compact encoding of
+    // stark_verifier_core::compact, which takes far less calldata. The proof is read in place.
+    #[inline]
+    pub fn verify_proof_compact(&mut self, input: Bytes) -> Result<Vec<U256>, Vec<u8>> {
+        self.ownable.when_not_paused()?;
+        let input = StarkInput::decode(&input)?;
+        StarkVerifier::verify_proof(self, &input.proof_params, ProofWords::Bytes(input.proof), &input.public_input)
+    }
+
+    // Reverts with DependencyCodeChanged if the code of a dependency is not the one pinned by init.
+    pub fn check_wiring(&self) -> Result<(), Vec<u8>> {
+        self.dependencies.check_all(&self.wiring())
+    }
+
+    // The names, addresses and pinned code hashes of the dependencies, so a deployment can be
+    // compared with the code hashes of a known build.
+    pub fn verifier_config(&self) -> (Vec<String>, Vec<Address>, Vec<FixedBytes<32>>) {
+        self.dependencies.config(&self.wiring())
+    }
+
+    // Lets the GPS statement verifier build the main page for this layout.
+    pub fn get_layout_info(&self) -> (U256, U256) {
+        layout_specific::get_layout_info()
+    }
+}
+
+impl CpuVerifier {
+    fn wiring(&self) -> [(&'static str, Address); 13] {
+        [
+            ("constraintPoly", self.constraint_poly.get()),
+            ("pedersenPointsX", self.pedersen_points_x.get()),
+            ("pedersenPointsY", self.pedersen_points_y.get()),
+            ("poseidonPoseidonFullRoundKey0", self.poseidon_poseidon_full_round_key0.get()),
+            ("poseidonPoseidonFullRoundKey1", self.poseidon_poseidon_full_round_key1.get()),
+            ("poseidonPoseidonFullRoundKey2", self.poseidon_poseidon_full_round_key2.get()),
+            ("poseidonPoseidonPartialRoundKey0", self.poseidon_poseidon_partial_round_key0.get()),
+            ("poseidonPoseidonPartialRoundKey1", self.poseidon_poseidon_partial_round_key1.get()),
+            ("initVerifier", self.init_verifier.get()),
+            ("friStatementVerifier", self.fri_statement_verifier.get()),
+            ("memoryPageFactRegistry", self.memory_page_fact_registry.get()),
+            ("pedersenColumns", self.pedersen_columns.get()),
+            ("poseidonColumns", self.poseidon_columns.get()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::alloy_primitives::keccak256;
+    use utils::require::*;
+    #[cfg(feature = "inline-periodic-columns")]
+    use stylus_sdk::alloy_primitives::uint;
+    use stylus_sdk::testing::*;
+
+    #[motsu::test]
+    fn test_get_layout_info() {
+        let vm = TestVM::default();
+        let contract = CpuVerifier::from(&vm);
+        let (public_memory_offset, selected_builtins) = contract.get_layout_info();
+        assert_eq!(public_memory_offset, U256::from(21));
+        // output, pedersen, range_check, bitwise and poseidon.
+        assert_eq!(selected_builtins, U256::from(0b10010111));
+    }
+
+    // The deployment, with the sender as the owner.
+    fn deploy(vm: &TestVM) -> CpuVerifier {
+        let mut contract = CpuVerifier::from(vm);
+        contract.constructor(vm.msg_sender()).unwrap();
+        contract
+    }
+
+    fn init(contract: &mut CpuVerifier) -> Result<(), Vec<u8>> {
+        let [a, b, c, d, e, f, g, h, i, j, k] = core::array::from_fn(|i| Address::repeat_byte(i as u8 + 1));
+        contract.init(a, b, c, d, e, f, g, h, i, j, k)
+    }
+
+    #[motsu::test]
+    fn test_init_before_owner() {
+        let vm = TestVM::default();
+        let mut contract = deploy(&vm);
+        let owner = vm.msg_sender();
+
+        let attacker = Address::repeat_byte(0x11);
+        vm.set_sender(attacker);
+        let err = init(&mut contract).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::OnlyOwner(OnlyOwner { caller: attacker })));
+        assert_eq!(contract.fri_statement_verifier.get(), Address::ZERO);
+
+        vm.set_sender(owner);
+        init(&mut contract).unwrap();
+        assert_eq!(contract.ownable.owner(), owner);
+
+        let err = CpuVerifier::from(&vm).constructor(Address::ZERO).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::InvalidOwner(InvalidOwner { owner: Address::ZERO })));
+    }
+
+    #[motsu::test]
+    fn test_wiring_is_owner_only() {
+        let vm = TestVM::default();
+        let mut contract = deploy(&vm);
+        init(&mut contract).unwrap();
+        assert_eq!(contract.fri_statement_verifier.get(), Address::repeat_byte(10));
+        let err = init(&mut contract).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::AlreadyInitialized(AlreadyInitialized {})));
+
+        let attacker = Address::repeat_byte(0x11);
+        vm.set_sender(attacker);
+        let err = contract.set_batched_periodic_columns(attacker, attacker).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::OnlyOwner(OnlyOwner { caller: attacker })));
+        assert_eq!(contract.pedersen_columns.get(), Address::ZERO);
+
+        vm.set_sender(contract.ownable.owner());
+        contract.set_batched_periodic_columns(Address::repeat_byte(12), Address::ZERO).unwrap();
+        assert_eq!(contract.pedersen_columns.get(), Address::repeat_byte(12));
+    }
+
+    #[motsu::test]
+    fn test_check_wiring() {
+        let vm = TestVM::default();
+        let fri_statement_verifier = Address::repeat_byte(10);
+        vm.set_code(fri_statement_verifier, vec![1, 2, 3]);
+        let mut contract = deploy(&vm);
+        init(&mut contract).unwrap();
+        contract.check_wiring().unwrap();
+
+        let expected = keccak256([1, 2, 3]);
+        let (names, addresses, code_hashes) = contract.verifier_config();
+        assert_eq!(names.len(), 13);
+        assert_eq!(names[9], "friStatementVerifier");
+        assert_eq!(addresses[9], fri_statement_verifier);
+        assert_eq!(code_hashes[9], expected);
+        assert_eq!(code_hashes[0], FixedBytes::ZERO);
+
+        // E.g. an always-true contract deployed at the address after the wiring.
+        vm.set_code(fri_statement_verifier, vec![4]);
+        let err = contract.check_wiring().unwrap_err();
+        let changed = DependencyCodeChanged { dependency: fri_statement_verifier, expected, actual: keccak256([4]) };
+        assert_eq!(StarkError::decode(&err), Some(StarkError::DependencyCodeChanged(changed.clone())));
+        let err = contract.verify_fri(ProofWords::Words(&[]), &[], &[]).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::DependencyCodeChanged(changed)));
+    }
+
+    #[motsu::test]
+    fn test_paused_verifier_rejects_proofs() {
+        let vm = TestVM::default();
+        let mut contract = deploy(&vm);
+        init(&mut contract).unwrap();
+        contract.ownable.pause().unwrap();
+
+        let err = contract.verify_proof_external(vec![], vec![], vec![]).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::ContractPaused(ContractPaused {})));
+        let err = contract.verify_proof_compact(Bytes::from(vec![])).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::ContractPaused(ContractPaused {})));
+    }
+
+    #[cfg(feature = "inline-periodic-columns")]
+    #[motsu::test]
+    fn test_compute_periodic_column_inline() {
+        let vm = TestVM::default();
+        let mut contract = CpuVerifier::from(&vm);
+        // The inputs and outputs of the Solidity periodic column tests (test/PeriodicColumnts.t.sol).
+        // No column contract is set, so the values can only come from the in-process evaluation.
+        let pedersen_x = uint!(2502371038239847331946845555940821891939660827069539886818086403686260021246_U256);
+        let poseidon_x = uint!(513761785516736576210258345954495650460389361631034617172115002511570125974_U256);
+        let vectors = [
+            (
+                PeriodicColumn::PedersenPointsX,
+                pedersen_x,
+                uint!(2476435194882991550378205418214791165604712474576866766823810310226558062065_U256),
+            ),
+            (
+                PeriodicColumn::PedersenPointsY,
+                pedersen_x,
+                uint!(1444533035788560090889078696321009507857064390212204404518903797387225515076_U256),
+            ),
+            (
+                PeriodicColumn::PoseidonFullRoundKey0,
+                poseidon_x,
+                uint!(1747952454919021766681010400995206390562374609324430906386085649753967957996_U256),
+            ),
+            (
+                PeriodicColumn::PoseidonFullRoundKey1,
+                poseidon_x,
+                uint!(1664257228653772301912891197477956780973260593455413394763471271235501957228_U256),
+            ),
+            (
+                PeriodicColumn::PoseidonFullRoundKey2,
+                poseidon_x,
+                uint!(1938976483485279484363264204509611131731729867572976629648616677903267220493_U256),
+            ),
+            (
+                PeriodicColumn::PoseidonPartialRoundKey0,
+                poseidon_x,
+                uint!(1499007735260395255086346814066654016187033964386904667040298584658325794077_U256),
+            ),
+            (
+                PeriodicColumn::PoseidonPartialRoundKey1,
+                poseidon_x,
+                uint!(2486570557154671379335084513491649861794821253711847039152551529444239535533_U256),
+            ),
+        ];
+        for (column, x, expected) in vectors {
+            assert_eq!(contract.compute_periodic_column(column, x).unwrap(), expected, "{column:?}");
+        }
     }
 }
@@ -0,0 +1,64 @@
+//! The address book the deployer writes after every step, and resumes from.
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use alloy_primitives::{Address, B256};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AddressBook {
+    pub chain_id: u64,
+    pub contracts: BTreeMap<String, Deployment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Deployment {
+    pub address: Address,
+    #[serde(rename = "crate")]
+    pub crate_dir: String,
+    pub features: Vec<String>,
+    #[serde(default)]
+    pub constructor_args: Vec<String>,
+    // The calls sent to the contract, in the manifest order.
+    pub calls: Vec<CallRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallRecord {
+    pub signature: String,
+    // The resolved arguments the call was sent with.
+    pub args: Vec<String>,
+    pub tx: B256,
+    // False while the transaction is pending. A resumed run waits for it instead of sending the
+    // call again, since the wiring calls can only succeed once.
+    pub done: bool,
+}
+
+impl AddressBook {
+    // An empty address book if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<AddressBook, String> {
+        match fs::read_to_string(path) {
+            Ok(input) => {
+                serde_json::from_str(&input).map_err(|e| format!("{}: {e}", path.display()))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(AddressBook::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    // Writes to a temporary file first, so an interrupted run never leaves a truncated book.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+        }
+        let output = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, output + "\n").map_err(|e| format!("{}: {e}", tmp.display()))?;
+        fs::rename(&tmp, path).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn addresses(&self) -> BTreeMap<String, Address> {
+        self.contracts.iter().map(|(name, deployment)| (name.clone(), deployment.address)).collect()
+    }
+}
@@ -0,0 +1,280 @@
+//! Deploys and wires the Stylus contracts as described by a deployment manifest.
+//!
+//! The deployment is resumable: the address book records every deployed contract and every call
+//! sent to it, and a new run skips what is already done. A contract is deployed again if there is
+//! no code at its address any more (e.g. the dev node was restarted), and so is every contract
+//! wired to it, since the wiring calls can only succeed once.
+#[path = "address-book.rs"]
+pub mod address_book;
+#[path = "manifest.rs"]
+pub mod manifest;
+
+use alloy_primitives::{Address, B256};
+
+use crate::address_book::CallRecord;
+
+// What to do with a call of the manifest given what the address book recorded for it.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CallAction {
+    Skip,
+    // The transaction was sent but the run stopped before its receipt.
+    Wait(B256),
+    Send,
+    // The call was sent with other arguments, e.g. the address of a contract that has been
+    // deployed again since. It cannot be sent twice, so the contract has to be deployed again.
+    Redeploy,
+}
+
+pub fn call_action(record: Option<&CallRecord>, signature: &str, args: &[String]) -> CallAction {
+    match record {
+        None => CallAction::Send,
+        Some(record) if record.signature != signature || record.args != args => {
+            CallAction::Redeploy
+        }
+        Some(record) if record.done => CallAction::Skip,
+        Some(record) => CallAction::Wait(record.tx),
+    }
+}
+
+// The address in the output of `cargo stylus deploy`.
+pub fn parse_deployed_address(output: &str) -> Option<Address> {
+    let line =
+        output.lines().find(|line| line.to_lowercase().contains("deployed code at address"))?;
+    let start = line.find("0x")?;
+    let hex: String = line[start + 2..].chars().take_while(char::is_ascii_hexdigit).collect();
+    if hex.len() != 40 {
+        return None;
+    }
+    hex.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use super::*;
+    use crate::address_book::{AddressBook, Deployment};
+    use crate::manifest::Manifest;
+
+    fn manifest() -> Manifest {
+        Manifest::parse(include_str!("../../deploy.toml")).unwrap()
+    }
+
+    fn position(order: &[String], name: &str) -> usize {
+        order.iter().position(|contract| contract == name).unwrap()
+    }
+
+    #[test]
+    fn test_deployment_order() {
+        let manifest = manifest();
+        let order = manifest.deployment_order().unwrap();
+        // The edges of the dependency graph in the README.
+        for (contract, dependency) in [
+            ("gps-sv", "mpfr"),
+            ("gps-sv", "cpu-verifier"),
+            ("cpu-verifier", "constraint-poly"),
+            ("cpu-verifier", "pedersen-columns"),
+            ("cpu-verifier", "verifier-init"),
+            ("cpu-verifier", "fri-statement-verifier"),
+            ("constraint-poly", "constraint-poly-preparer"),
+            ("constraint-poly", "constraint-poly-finalizer"),
+            ("fri-statement-verifier", "oods"),
+            ("fri-statement-verifier", "fri-statement"),
+            ("fri-statement-verifier", "merkle-statement"),
+        ] {
+            assert!(
+                position(&order, dependency) < position(&order, contract),
+                "{contract} before {dependency}"
+            );
+        }
+        // The native statement contracts and batched periodic columns by default.
+        assert!(!order.contains(&"mock-provider".to_string()));
+        assert!(!order.contains(&"pedersen-hp-x-c".to_string()));
+    }
+
+    #[test]
+    fn test_readme_diagram() {
+        // The mermaid graph of the README, with its nodes labelled `ID[contract<br/>...]`.
+        let readme = include_str!("../../../README.md");
+        let graph = readme.split("```mermaid").nth(1).unwrap().split("```").next().unwrap();
+        let mut names = BTreeMap::new();
+        let mut edges = Vec::new();
+        for line in graph.lines().map(str::trim) {
+            let Some((from, to)) = line.split_once("-->").or_else(|| line.split_once("-.->"))
+            else {
+                continue;
+            };
+            let mut node = |node: &str| {
+                let node = node.trim();
+                match node.split_once('[') {
+                    Some((id, label)) => {
+                        let name = label.split("<br/>").next().unwrap();
+                        names.insert(id.to_string(), name.to_string());
+                        id.to_string()
+                    }
+                    None => node.to_string(),
+                }
+            };
+            edges.push((node(from), node(to)));
+        }
+        let edges: BTreeSet<(String, String)> = edges
+            .into_iter()
+            .map(|(from, to)| (names[&from].clone(), names[&to].clone()))
+            .collect();
+        assert_eq!(edges, manifest().edges());
+    }
+
+    #[test]
+    fn test_options() {
+        let mut manifest = manifest();
+        manifest.set_option("use_mock_statements=true").unwrap();
+        manifest.set_option("batched_periodic_columns=false").unwrap();
+        let order = manifest.deployment_order().unwrap();
+        assert!(!order.contains(&"fri-statement".to_string()));
+        assert!(position(&order, "mock-provider") < position(&order, "fri-statement-verifier"));
+        assert!(position(&order, "pedersen-hp-x-c") < position(&order, "cpu-verifier"));
+        assert!(!order.contains(&"pedersen-columns".to_string()));
+
+        manifest.set_option("inline_periodic_columns=true").unwrap();
+        let order = manifest.deployment_order().unwrap();
+        assert!(!order
+            .iter()
+            .any(|name| name.starts_with("pedersen") || name.starts_with("poseidon")));
+        let cpu_verifier = &manifest.contracts["cpu-verifier"];
+        assert_eq!(manifest.features(cpu_verifier).unwrap(), ["inline-periodic-columns"]);
+        assert_eq!(manifest.calls(cpu_verifier).unwrap().len(), 1);
+
+        assert!(manifest.set_option("unknown=1").is_err());
+    }
+
+    #[test]
+    fn test_resolve_args() {
+        let mut manifest = manifest();
+        manifest.set_option("use_mock_statements=true").unwrap();
+        let addresses: BTreeMap<String, Address> = [
+            ("mpfr".to_string(), Address::repeat_byte(1)),
+            ("cpu-verifier".to_string(), Address::repeat_byte(2)),
+            ("mock-provider".to_string(), Address::repeat_byte(3)),
+        ]
+        .into();
+        let gps_sv = &manifest.contracts["gps-sv"];
+        let args: Vec<String> = gps_sv.calls[0]
+            .args
+            .iter()
+            .map(|arg| manifest.resolve_arg(arg, &addresses).unwrap())
+            .collect();
+        assert_eq!(
+            args,
+            [
+                format!("{:?}", Address::repeat_byte(1)),
+                format!("[{:?}]", Address::repeat_byte(2)),
+                "604800".to_string(),
+            ]
+        );
+
+        // The mock stands in for the statement contracts, and the column contracts of the other
+        // periodic columns mode are the zero address.
+        let statement = toml::Value::String("@mock-provider|@fri-statement".to_string());
+        assert_eq!(
+            manifest.resolve_arg(&statement, &addresses).unwrap(),
+            format!("{:?}", Address::repeat_byte(3))
+        );
+        let column = toml::Value::String("@pedersen-hp-x-c".to_string());
+        assert_eq!(
+            manifest.resolve_arg(&column, &addresses).unwrap(),
+            format!("{:?}", Address::ZERO)
+        );
+        let not_deployed = toml::Value::String("@oods".to_string());
+        assert!(manifest.resolve_arg(&not_deployed, &addresses).is_err());
+
+        // The owner set by the constructor of the wiring contracts.
+        let cpu_verifier = &manifest.contracts["cpu-verifier"];
+        assert!(manifest.resolve_arg(&cpu_verifier.constructor_args[0], &addresses).is_err());
+        let mut addresses = addresses;
+        addresses.insert(manifest::DEPLOYER.to_string(), Address::repeat_byte(4));
+        assert_eq!(
+            manifest.resolve_arg(&cpu_verifier.constructor_args[0], &addresses).unwrap(),
+            format!("{:?}", Address::repeat_byte(4))
+        );
+    }
+
+    #[test]
+    fn test_invalid_manifests() {
+        let unknown_reference = r#"
+            rpc_url = "http://127.0.0.1:8547"
+            address_book = "book.json"
+            [contracts.a]
+            calls = [{ signature = "init(address)", args = ["@b"] }]
+        "#;
+        assert!(Manifest::parse(unknown_reference).unwrap_err().contains("unknown contract b"));
+
+        let cycle = r#"
+            rpc_url = "http://127.0.0.1:8547"
+            address_book = "book.json"
+            [contracts.a]
+            calls = [{ signature = "init(address)", args = ["@b"] }]
+            [contracts.b]
+            calls = [{ signature = "init(address)", args = ["@a"] }]
+        "#;
+        assert_eq!(
+            Manifest::parse(cycle).unwrap().deployment_order().unwrap_err(),
+            "dependency cycle between a, b"
+        );
+    }
+
+    #[test]
+    fn test_call_action() {
+        let args = vec![format!("{:?}", Address::repeat_byte(1))];
+        let mut record = CallRecord {
+            signature: "init(address)".to_string(),
+            args: args.clone(),
+            tx: B256::repeat_byte(9),
+            done: false,
+        };
+        assert_eq!(call_action(None, "init(address)", &args), CallAction::Send);
+        assert_eq!(
+            call_action(Some(&record), "init(address)", &args),
+            CallAction::Wait(B256::repeat_byte(9))
+        );
+        record.done = true;
+        assert_eq!(call_action(Some(&record), "init(address)", &args), CallAction::Skip);
+        let rewired = vec![format!("{:?}", Address::repeat_byte(2))];
+        assert_eq!(call_action(Some(&record), "init(address)", &rewired), CallAction::Redeploy);
+    }
+
+    #[test]
+    fn test_parse_deployed_address() {
+        let output = "stripped custom section from user wasm to remove any sensitive data\n\
+            contract size: 19.2 KB\n\
+            deployed code at address: 0xa6e41ffd769491a42a6e5ce453259b93983a22ef\n\
+            deployment tx hash: 0x307b1d712840327349d561dea948d957362d5d807a1dfa87413023159cbb23f2\n";
+        assert_eq!(
+            parse_deployed_address(output),
+            Some("0xa6e41ffd769491a42a6e5ce453259b93983a22ef".parse().unwrap())
+        );
+        assert_eq!(parse_deployed_address("error: insufficient funds"), None);
+    }
+
+    #[test]
+    fn test_address_book_roundtrip() {
+        let path =
+            std::env::temp_dir().join(format!("deployer-test-{}/book.json", std::process::id()));
+        assert_eq!(AddressBook::load(&path).unwrap(), AddressBook::default());
+
+        let mut book = AddressBook { chain_id: 412346, ..Default::default() };
+        book.contracts.insert(
+            "mpfr".to_string(),
+            Deployment {
+                address: Address::repeat_byte(1),
+                crate_dir: "mpfr".to_string(),
+                features: vec![],
+                constructor_args: vec![],
+                calls: vec![],
+            },
+        );
+        book.save(&path).unwrap();
+        assert_eq!(AddressBook::load(&path).unwrap(), book);
+        assert_eq!(book.addresses()["mpfr"], Address::repeat_byte(1));
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,388 @@
+//! Deploys the Stylus contracts to a node and wires them.
+//!
+//!     deployer [--manifest FILE] [--address-book FILE] [--set OPTION=VALUE]...
+//!
+//! The contracts, the calls that wire them and the options are described by the manifest
+//! (default ../deploy.toml). The contracts are deployed with `cargo stylus deploy` in dependency
+//! order, and the deployer's key is read from the environment variable the manifest names
+//! (PRIVATE_KEY by default). `cargo stylus deploy` gets the key in a temporary file that only the
+//! user can read, so it never shows up in the arguments of a process.
+//!
+//! Options:
+//!     --address-book FILE   where the addresses and the sent calls are recorded, instead of the
+//!                           address_book of the manifest. A run resumes from it.
+//!     --set OPTION=VALUE    overrides an option of the manifest, e.g. use_mock_statements=true.
+//!
+//! Exits with 0 once every contract is deployed, wired and passes its check, 1 if a step fails
+//! and 2 on invalid arguments.
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, ExitCode};
+use std::{env, fs};
+
+use alloy_dyn_abi::{DynSolValue, JsonAbiExt, Specifier};
+use alloy_json_abi::Function;
+use alloy_network::{EthereumWallet, TransactionBuilder};
+use alloy_primitives::{Address, Bytes, B256};
+use alloy_provider::{DynProvider, PendingTransactionBuilder, Provider, ProviderBuilder};
+use alloy_rpc_types_eth::TransactionRequest;
+use alloy_signer_local::PrivateKeySigner;
+use deployer::address_book::{AddressBook, CallRecord, Deployment};
+use deployer::manifest::{Manifest, DEPLOYER};
+use deployer::{call_action, parse_deployed_address, CallAction};
+
+const DEFAULT_MANIFEST: &str = "../deploy.toml";
+
+struct Args {
+    manifest: String,
+    address_book: Option<String>,
+    options: Vec<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args =
+        Args { manifest: DEFAULT_MANIFEST.to_string(), address_book: None, options: Vec::new() };
+    let mut argv = env::args().skip(1);
+    while let Some(flag) = argv.next() {
+        let value = argv.next().ok_or(format!("missing value for {flag}"))?;
+        match flag.as_str() {
+            "--manifest" => args.manifest = value,
+            "--address-book" => args.address_book = Some(value),
+            "--set" => args.options.push(value),
+            _ => return Err(format!("unknown argument {flag}")),
+        }
+    }
+    Ok(args)
+}
+
+struct Deployer {
+    manifest: Manifest,
+    // The directory of the manifest, which the crate and address book paths are relative to.
+    dir: PathBuf,
+    book_path: PathBuf,
+    book: AddressBook,
+    private_key: String,
+    // The address of the deploying account, which signs the calls.
+    address: Address,
+    provider: DynProvider,
+}
+
+// The private key in a file that only the user can read, for `cargo stylus deploy
+// --private-key-path`. The file is removed when dropped.
+struct KeyFile(PathBuf);
+
+impl KeyFile {
+    fn create(private_key: &str) -> Result<KeyFile, String> {
+        let path = env::temp_dir().join(format!("deployer-key-{}", process::id()));
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let key_file = KeyFile(path);
+        let mut file =
+            options.open(&key_file.0).map_err(|e| format!("{}: {e}", key_file.0.display()))?;
+        file.write_all(private_key.as_bytes())
+            .map_err(|e| format!("{}: {e}", key_file.0.display()))?;
+        Ok(key_file)
+    }
+}
+
+impl Drop for KeyFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+impl Deployer {
+    async fn connect(
+        manifest: Manifest,
+        dir: PathBuf,
+        book_path: PathBuf,
+    ) -> Result<Deployer, String> {
+        let private_key = env::var(&manifest.private_key_env).map_err(|_| {
+            format!("set the deployer's private key in {}", manifest.private_key_env)
+        })?;
+        let signer: PrivateKeySigner =
+            private_key.parse().map_err(|e| format!("invalid private key: {e}"))?;
+        let address = signer.address();
+        let provider = ProviderBuilder::new()
+            .wallet(EthereumWallet::from(signer))
+            .on_builtin(&manifest.rpc_url)
+            .await
+            .map_err(|e| format!("{}: {e}", manifest.rpc_url))?
+            .erased();
+        let chain_id = provider
+            .get_chain_id()
+            .await
+            .map_err(|e| format!("node is not responding on {}: {e}", manifest.rpc_url))?;
+        println!("✅ Node is up on {} (chain id {chain_id})", manifest.rpc_url);
+
+        let mut book = AddressBook::load(&book_path)?;
+        if !book.contracts.is_empty() && book.chain_id != chain_id {
+            return Err(format!(
+                "{} was written for chain id {}",
+                book_path.display(),
+                book.chain_id
+            ));
+        }
+        book.chain_id = chain_id;
+        Ok(Deployer { manifest, dir, book_path, book, private_key, address, provider })
+    }
+
+    async fn run(&mut self) -> Result<(), String> {
+        let order = self.manifest.deployment_order()?;
+        println!("Deployment order: {}", order.join(", "));
+        for name in &order {
+            self.deploy(name).await?;
+        }
+        for name in &order {
+            self.check(name).await?;
+        }
+        println!("Address book: {}", self.book_path.display());
+        for name in &order {
+            println!("  {name:<28} {:?}", self.book.contracts[name].address);
+        }
+        Ok(())
+    }
+
+    // Deploys the contract unless the address book has a deployment that is still current, then
+    // sends the calls that were not sent yet.
+    async fn deploy(&mut self, name: &str) -> Result<(), String> {
+        let contract = &self.manifest.contracts[name];
+        let crate_dir = contract.crate_dir.clone().unwrap_or_else(|| name.to_string());
+        let no_verify = contract.no_verify;
+        let features = self.manifest.features(contract)?;
+        let mut addresses = self.book.addresses();
+        addresses.insert(DEPLOYER.to_string(), self.address);
+        let constructor_args: Result<Vec<String>, String> = contract
+            .constructor_args
+            .iter()
+            .map(|arg| self.manifest.resolve_arg(arg, &addresses))
+            .collect();
+        let constructor_args = constructor_args?;
+        let mut calls = Vec::new();
+        for call in self.manifest.calls(contract)? {
+            let args: Result<Vec<String>, String> =
+                call.args.iter().map(|arg| self.manifest.resolve_arg(arg, &addresses)).collect();
+            calls.push((call.signature.clone(), args?));
+        }
+
+        let current = match self.book.contracts.get(name) {
+            Some(deployment)
+                if deployment.crate_dir == crate_dir
+                    && deployment.features == features
+                    && deployment.constructor_args == constructor_args =>
+            {
+                let code = self
+                    .provider
+                    .get_code_at(deployment.address)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                !code.is_empty()
+                    && deployment.calls.len() <= calls.len()
+                    && calls.iter().enumerate().all(|(i, (signature, args))| {
+                        call_action(deployment.calls.get(i), signature, args)
+                            != CallAction::Redeploy
+                    })
+            }
+            _ => false,
+        };
+        if current {
+            println!("✅ '{name}' contract already at {:?}", self.book.contracts[name].address);
+        } else {
+            let address =
+                self.deploy_crate(name, &crate_dir, no_verify, &features, &constructor_args)?;
+            println!("✅ '{name}' contract at {address:?}");
+            let deployment =
+                Deployment { address, crate_dir, features, constructor_args, calls: Vec::new() };
+            self.book.contracts.insert(name.to_string(), deployment);
+            self.book.save(&self.book_path)?;
+        }
+
+        for (i, (signature, args)) in calls.into_iter().enumerate() {
+            match call_action(self.book.contracts[name].calls.get(i), &signature, &args) {
+                CallAction::Skip => println!("✅ {signature} already called on '{name}'"),
+                CallAction::Wait(tx) => {
+                    if self.wait(tx).await? {
+                        self.book.contracts.get_mut(name).unwrap().calls[i].done = true;
+                        self.book.save(&self.book_path)?;
+                        println!("✅ {signature} called on '{name}' in {tx:?}");
+                    } else {
+                        self.send(name, i, signature, args).await?;
+                    }
+                }
+                CallAction::Send => self.send(name, i, signature, args).await?,
+                CallAction::Redeploy => unreachable!("'{name}' was deployed again"),
+            }
+        }
+        Ok(())
+    }
+
+    fn deploy_crate(
+        &self,
+        name: &str,
+        crate_dir: &str,
+        no_verify: bool,
+        features: &[String],
+        constructor_args: &[String],
+    ) -> Result<Address, String> {
+        let dir = self.dir.join(crate_dir);
+        println!("Deploying '{name}' contract from {}...", dir.display());
+        let key_file = KeyFile::create(&self.private_key)?;
+        let mut command = Command::new("cargo");
+        command
+            .current_dir(&dir)
+            .args(["stylus", "deploy"])
+            .arg("--private-key-path")
+            .arg(&key_file.0)
+            .arg(format!("--endpoint={}", self.manifest.rpc_url));
+        if no_verify {
+            command.arg("--no-verify");
+        }
+        if !features.is_empty() {
+            command.arg(format!("--features={}", features.join(",")));
+        }
+        if !constructor_args.is_empty() {
+            command.arg("--constructor-args").args(constructor_args);
+        }
+        let output = command.output().map_err(|e| format!("cargo stylus deploy: {e}"));
+        drop(key_file);
+        let output = output?;
+        let output = String::from_utf8_lossy(&output.stdout).into_owned()
+            + &String::from_utf8_lossy(&output.stderr);
+        parse_deployed_address(&output).ok_or_else(|| {
+            let lines: Vec<&str> = output.lines().collect();
+            let tail = lines[lines.len().saturating_sub(20)..].join("\n");
+            format!("deployment failed for '{name}' contract, last 20 lines of output:\n{tail}")
+        })
+    }
+
+    // Sends the i-th call of the contract. The transaction is recorded before its receipt, so an
+    // interrupted run waits for it instead of sending the call twice.
+    async fn send(
+        &mut self,
+        name: &str,
+        i: usize,
+        signature: String,
+        args: Vec<String>,
+    ) -> Result<(), String> {
+        let address = self.book.contracts[name].address;
+        println!("Calling {signature} on '{name}'...");
+        let data = encode_call(&signature, &args)?;
+        let pending = self
+            .provider
+            .send_transaction(TransactionRequest::default().with_to(address).with_input(data))
+            .await
+            .map_err(|e| format!("{signature} on '{name}': {e}"))?;
+        let tx = *pending.tx_hash();
+
+        let calls = &mut self.book.contracts.get_mut(name).unwrap().calls;
+        calls.truncate(i);
+        calls.push(CallRecord { signature: signature.clone(), args, tx, done: false });
+        self.book.save(&self.book_path)?;
+
+        let receipt = pending.get_receipt().await.map_err(|e| format!("{tx:?}: {e}"))?;
+        if !receipt.status() {
+            return Err(format!("{signature} reverted on '{name}' in {tx:?}"));
+        }
+        self.book.contracts.get_mut(name).unwrap().calls[i].done = true;
+        self.book.save(&self.book_path)?;
+        println!("✅ {signature} called on '{name}' in {tx:?}");
+        Ok(())
+    }
+
+    // Whether a transaction sent by an interrupted run succeeded, waiting for it if it is still
+    // pending. False if it failed or the node does not know it, so the call has to be sent again.
+    async fn wait(&self, tx: B256) -> Result<bool, String> {
+        let provider = &self.provider;
+        let receipt = match provider.get_transaction_receipt(tx).await.map_err(|e| e.to_string())? {
+            Some(receipt) => Some(receipt),
+            None if provider
+                .get_transaction_by_hash(tx)
+                .await
+                .map_err(|e| e.to_string())?
+                .is_some() =>
+            {
+                println!("Waiting for {tx:?}...");
+                let pending = PendingTransactionBuilder::new(provider.root().clone(), tx);
+                Some(pending.get_receipt().await.map_err(|e| e.to_string())?)
+            }
+            None => None,
+        };
+        Ok(receipt.is_some_and(|receipt| receipt.status()))
+    }
+
+    async fn check(&self, name: &str) -> Result<(), String> {
+        let Some(check) = &self.manifest.contracts[name].check else {
+            return Ok(());
+        };
+        let address = self.book.contracts[name].address;
+        let tx =
+            TransactionRequest::default().with_to(address).with_input(encode_call(check, &[])?);
+        self.provider.call(&tx).await.map_err(|e| format!("{check} failed on '{name}': {e}"))?;
+        println!("✅ {check} passed on '{name}'");
+        Ok(())
+    }
+}
+
+fn encode_call(signature: &str, args: &[String]) -> Result<Bytes, String> {
+    let function = Function::parse(&format!("function {signature}"))
+        .map_err(|e| format!("invalid signature {signature}: {e}"))?;
+    if function.inputs.len() != args.len() {
+        return Err(format!(
+            "{signature} takes {} arguments, got {}",
+            function.inputs.len(),
+            args.len()
+        ));
+    }
+    let values: Result<Vec<DynSolValue>, String> = function
+        .inputs
+        .iter()
+        .zip(args)
+        .map(|(param, arg)| {
+            param
+                .resolve()
+                .and_then(|ty| ty.coerce_str(arg))
+                .map_err(|e| format!("{signature}: {arg}: {e}"))
+        })
+        .collect();
+    let data = function.abi_encode_input(&values?).map_err(|e| e.to_string())?;
+    Ok(data.into())
+}
+
+fn load(args: Args) -> Result<(Manifest, PathBuf, PathBuf), String> {
+    let input =
+        fs::read_to_string(&args.manifest).map_err(|e| format!("{}: {e}", args.manifest))?;
+    let mut manifest = Manifest::parse(&input).map_err(|e| format!("{}: {e}", args.manifest))?;
+    for option in &args.options {
+        manifest.set_option(option)?;
+    }
+    let dir = Path::new(&args.manifest).parent().unwrap_or(Path::new("")).to_path_buf();
+    let book_path = match args.address_book {
+        Some(path) => PathBuf::from(path),
+        None => dir.join(&manifest.address_book),
+    };
+    Ok((manifest, dir, book_path))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let (manifest, dir, book_path) = match parse_args().and_then(load) {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            eprintln!("deployer: {err}");
+            return ExitCode::from(2);
+        }
+    };
+    let result = match Deployer::connect(manifest, dir, book_path).await {
+        Ok(mut deployer) => deployer.run().await,
+        Err(err) => Err(err),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("❌ {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
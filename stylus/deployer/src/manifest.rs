@@ -0,0 +1,285 @@
+//! The deployment manifest, see `stylus/deploy.toml`.
+//!
+//! Every contract lists its constructor arguments and the calls that wire it once it is deployed.
+//! An argument of the form `@name` is the address of the contract `name`, `@a|@b` the address of
+//! the first of them that is deployed, `@deployer` the address of the deploying account and
+//! `$option` the value of an option. The references are the edges of the dependency graph: a
+//! contract is deployed after every contract it references, so the calls can pin their code
+//! hashes.
+use std::collections::{BTreeMap, BTreeSet};
+
+use alloy_primitives::Address;
+use serde::Deserialize;
+use toml::Value;
+
+// The reference to the account that deploys the contracts, e.g. as the owner.
+pub const DEPLOYER: &str = "deployer";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    pub rpc_url: String,
+    // The environment variable with the private key of the deployer. Keys are never read from the
+    // manifest.
+    #[serde(default = "default_private_key_env")]
+    pub private_key_env: String,
+    // Relative to the manifest.
+    pub address_book: String,
+    // Values for `when` conditions and `$option` arguments, overridden with --set.
+    #[serde(default)]
+    pub options: BTreeMap<String, Value>,
+    pub contracts: BTreeMap<String, Contract>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Contract {
+    // The directory of the crate, relative to the manifest. Defaults to the contract name.
+    #[serde(rename = "crate")]
+    pub crate_dir: Option<String>,
+    // Skips the `cargo stylus check` of the deployment, for the contracts it rejects.
+    #[serde(default)]
+    pub no_verify: bool,
+    #[serde(default)]
+    pub features: Vec<Feature>,
+    // Passed to the constructor, which runs in the deployment transaction.
+    #[serde(default)]
+    pub constructor_args: Vec<Value>,
+    // The contract is only deployed if all the conditions hold. A condition is a boolean option,
+    // negated with a leading `!`.
+    #[serde(default)]
+    pub when: Vec<String>,
+    #[serde(default)]
+    pub calls: Vec<Call>,
+    // A view that must not revert once the contract is wired, e.g. `checkWiring()`.
+    pub check: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Feature {
+    Always(String),
+    When { name: String, when: Vec<String> },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Call {
+    // E.g. `init(address,address[],uint64)`.
+    pub signature: String,
+    #[serde(default)]
+    pub args: Vec<Value>,
+    #[serde(default)]
+    pub when: Vec<String>,
+}
+
+fn default_private_key_env() -> String {
+    "PRIVATE_KEY".to_string()
+}
+
+impl Manifest {
+    pub fn parse(input: &str) -> Result<Manifest, String> {
+        let manifest: Manifest = toml::from_str(input).map_err(|e| e.to_string())?;
+        if manifest.contracts.contains_key(DEPLOYER) {
+            return Err(format!("{DEPLOYER} is reserved for the deploying account"));
+        }
+        for (name, contract) in &manifest.contracts {
+            let constructor = ("constructor", &contract.constructor_args);
+            let calls = contract.calls.iter().map(|call| (call.signature.as_str(), &call.args));
+            for (signature, args) in [constructor].into_iter().chain(calls) {
+                for reference in args.iter().flat_map(references) {
+                    if !manifest.contracts.contains_key(&reference) {
+                        return Err(format!(
+                            "{name}: {signature} references unknown contract {reference}"
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(manifest)
+    }
+
+    // Overrides an option from a `KEY=VALUE` argument. The value is parsed as TOML, and kept as a
+    // string if it is not valid TOML.
+    pub fn set_option(&mut self, assignment: &str) -> Result<(), String> {
+        let (key, value) =
+            assignment.split_once('=').ok_or(format!("expected KEY=VALUE, got {assignment}"))?;
+        if !self.options.contains_key(key) {
+            return Err(format!("unknown option {key}"));
+        }
+        let value = toml::from_str::<BTreeMap<String, Value>>(&format!("v = {value}"))
+            .ok()
+            .and_then(|mut table| table.remove("v"))
+            .unwrap_or_else(|| Value::String(value.to_string()));
+        self.options.insert(key.to_string(), value);
+        Ok(())
+    }
+
+    pub fn holds(&self, conditions: &[String]) -> Result<bool, String> {
+        for condition in conditions {
+            let (negated, option) = match condition.strip_prefix('!') {
+                Some(option) => (true, option),
+                None => (false, condition.as_str()),
+            };
+            let value = match self.options.get(option) {
+                Some(Value::Boolean(value)) => *value,
+                Some(_) => {
+                    return Err(format!(
+                        "option {option} of condition {condition} is not a boolean"
+                    ))
+                }
+                None => return Err(format!("unknown option {option} in condition {condition}")),
+            };
+            if value == negated {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    pub fn is_enabled(&self, name: &str) -> Result<bool, String> {
+        match self.contracts.get(name) {
+            Some(contract) => self.holds(&contract.when),
+            None => Err(format!("unknown contract {name}")),
+        }
+    }
+
+    // The enabled features of the contract, in the manifest order.
+    pub fn features(&self, contract: &Contract) -> Result<Vec<String>, String> {
+        let mut features = Vec::new();
+        for feature in &contract.features {
+            match feature {
+                Feature::Always(name) => features.push(name.clone()),
+                Feature::When { name, when } => {
+                    if self.holds(when)? {
+                        features.push(name.clone());
+                    }
+                }
+            }
+        }
+        Ok(features)
+    }
+
+    // The enabled calls of the contract, in the manifest order.
+    pub fn calls<'a>(&self, contract: &'a Contract) -> Result<Vec<&'a Call>, String> {
+        let mut calls = Vec::new();
+        for call in &contract.calls {
+            if self.holds(&call.when)? {
+                calls.push(call);
+            }
+        }
+        Ok(calls)
+    }
+
+    // The enabled contracts, each after the contracts its calls reference. Ties are broken by name,
+    // so the order is the same on every run.
+    pub fn deployment_order(&self) -> Result<Vec<String>, String> {
+        let mut dependencies: BTreeMap<&str, BTreeSet<String>> = BTreeMap::new();
+        for (name, contract) in &self.contracts {
+            if !self.holds(&contract.when)? {
+                continue;
+            }
+            let mut edges = BTreeSet::new();
+            let calls = self.calls(contract)?;
+            let args =
+                contract.constructor_args.iter().chain(calls.iter().flat_map(|call| &call.args));
+            for reference in args.flat_map(references) {
+                if self.is_enabled(&reference)? && reference != *name {
+                    edges.insert(reference);
+                }
+            }
+            dependencies.insert(name, edges);
+        }
+
+        let mut order: Vec<String> = Vec::new();
+        while !dependencies.is_empty() {
+            let ready: Vec<&str> = dependencies
+                .iter()
+                .filter(|(_, edges)| edges.iter().all(|edge| order.contains(edge)))
+                .map(|(&name, _)| name)
+                .collect();
+            if ready.is_empty() {
+                let cycle: Vec<&str> = dependencies.keys().copied().collect();
+                return Err(format!("dependency cycle between {}", cycle.join(", ")));
+            }
+            for name in ready {
+                dependencies.remove(name);
+                order.push(name.to_string());
+            }
+        }
+        Ok(order)
+    }
+
+    // Every (contract, reference) edge of the dependency graph, whatever the options, as drawn in
+    // the README.
+    pub fn edges(&self) -> BTreeSet<(String, String)> {
+        let mut edges = BTreeSet::new();
+        for (name, contract) in &self.contracts {
+            let args = contract
+                .constructor_args
+                .iter()
+                .chain(contract.calls.iter().flat_map(|call| &call.args));
+            for reference in args.flat_map(references) {
+                if reference != *name {
+                    edges.insert((name.clone(), reference));
+                }
+            }
+        }
+        edges
+    }
+
+    // The argument as a string for the ABI tokenizer, with the references replaced by the
+    // addresses of the deployed contracts and the options by their values. A reference to a
+    // contract that is not enabled is the zero address. `addresses` also holds the address of the
+    // deploying account, under DEPLOYER.
+    pub fn resolve_arg(
+        &self,
+        arg: &Value,
+        addresses: &BTreeMap<String, Address>,
+    ) -> Result<String, String> {
+        match arg {
+            Value::String(value) if value.starts_with('@') => {
+                for reference in value.split('|') {
+                    let name = reference
+                        .trim()
+                        .strip_prefix('@')
+                        .ok_or(format!("invalid reference {value}"))?;
+                    if name == DEPLOYER || self.is_enabled(name)? {
+                        let address =
+                            addresses.get(name).ok_or(format!("{name} is not deployed yet"))?;
+                        return Ok(format!("{address:?}"));
+                    }
+                }
+                Ok(format!("{:?}", Address::ZERO))
+            }
+            Value::String(value) if value.starts_with('$') => {
+                let option =
+                    self.options.get(&value[1..]).ok_or(format!("unknown option {value}"))?;
+                self.resolve_arg(option, addresses)
+            }
+            Value::String(value) => Ok(value.clone()),
+            Value::Integer(value) => Ok(value.to_string()),
+            Value::Boolean(value) => Ok(value.to_string()),
+            Value::Array(values) => {
+                let values: Result<Vec<String>, String> =
+                    values.iter().map(|value| self.resolve_arg(value, addresses)).collect();
+                Ok(format!("[{}]", values?.join(",")))
+            }
+            _ => Err(format!("unsupported argument {arg}")),
+        }
+    }
+}
+
+// The contracts an argument references.
+pub fn references(arg: &Value) -> Vec<String> {
+    match arg {
+        Value::String(value) if value.starts_with('@') => value
+            .split('|')
+            .filter_map(|reference| reference.trim().strip_prefix('@'))
+            .filter(|&name| name != DEPLOYER)
+            .map(str::to_string)
+            .collect(),
+        Value::Array(values) => values.iter().flat_map(references).collect(),
+        _ => Vec::new(),
+    }
+}
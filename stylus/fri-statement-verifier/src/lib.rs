@@ -2,19 +2,20 @@
 
 #[macro_use]
 extern crate alloc;
-use alloc::vec::Vec;
-use utils::{prime_field_element0::PrimeFieldElement0, require};
+use alloc::{string::String, vec::Vec};
 
 #[path = "interfaces.rs"]
 pub mod interfaces;
 use crate::interfaces::{ICpuOods, IFriStatementVerifier, IMerkleStatementVerifier};
 
 use alloy_sol_types::sol;
+use stark_verifier_core::{proof::ProofWords, FriVerifier};
 use stylus_sdk::{
-    alloy_primitives::{uint, Address, FixedBytes, U256},
-    crypto::keccak,
+    abi::Bytes,
+    alloy_primitives::{Address, FixedBytes, U256},
     prelude::*,
 };
+use utils::{dependencies::Dependencies, ownable::Ownable};
 
 sol! {
     event FriVerified(
@@ -25,324 +26,208 @@ sol! {
 sol_storage! {
     #[entrypoint]
     pub struct FriStatementVerifier {
+        #[borrow]
+        Ownable ownable;
+        Dependencies dependencies;
         address oods;
         address fri_statement;
         address merkle_statement;
     }
 }
 
-// const COMMITMENT_MASK: U256 = uint!(0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF000000000000000000000000_U256);
-
 #[public]
+#[inherit(Ownable)]
 impl FriStatementVerifier {
+    #[constructor]
+    fn constructor(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable.init_owner(owner)
+    }
+
+    // Can only be called once, by the owner.
     #[inline]
-    fn init(&mut self, oods: Address, fri_statement: Address, merkle_statement: Address) {
-        self.oods.set(oods);
-        self.fri_statement.set(fri_statement);
-        self.merkle_statement.set(merkle_statement);
+    fn init(&mut self, oods: Address, fri_statement: Address, merkle_statement: Address) -> Result<(), Vec<u8>> {
+        self.ownable.initialize()?;
+        self.dependencies.set(&mut self.oods, "oods", oods);
+        self.dependencies.set(&mut self.fri_statement, "friStatement", fri_statement);
+        self.dependencies.set(&mut self.merkle_statement, "merkleStatement", merkle_statement);
+        Ok(())
     }
 
     #[inline]
     fn verify(
         &mut self,
-        mut proof: Vec<U256>,
+        proof: Vec<U256>,
         mut ctx: Vec<U256>,
         fri_step_sizes: Vec<U256>,
     ) -> Result<Vec<U256>, Vec<u8>> {
-        Self::adjust_query_indices_and_prepare_eval_points(&mut ctx);
-
-        let val1: FixedBytes<32> = FixedBytes(ctx[6].to_be_bytes());
-        let val2: FixedBytes<32> = FixedBytes(ctx[7].to_be_bytes());
-        let val3: FixedBytes<32> = FixedBytes(ctx[8].to_be_bytes());
-        self.read_query_responses_and_decommit(&mut proof, &mut ctx, 12, 9, 602, val1)?;
-        self.read_query_responses_and_decommit(&mut proof, &mut ctx, 12, 3, 611, val2)?;
-        self.read_query_responses_and_decommit(&mut proof, &mut ctx, 2, 2, 1178, val3)?;
-
-        let oods_contract: ICpuOods = ICpuOods {
-            address: self.oods.get(),
-        };
-        let oods_result: Vec<U256> = oods_contract.compute(&mut *self, ctx.to_vec())?;
-        for i in 0..oods_result.len() {
-            ctx[109 + i] = oods_result[i];
-        }
-
-        self.fri_verify_layers(&mut ctx, &proof, &fri_step_sizes)?;
+        self.ownable.when_not_paused()?;
+        FriVerifier::verify(self, (&proof).into(), &mut ctx, &fri_step_sizes)?;
         Ok(ctx)
     }
-}
-
-impl FriStatementVerifier {
-    fn adjust_query_indices_and_prepare_eval_points(ctx: &mut [U256]) {
-        let n_unique_queries = ctx[9].to::<usize>();
-        let fri_queue = 109;
-        let fri_queue_end = fri_queue + n_unique_queries * 3;
-
-        let mut eval_points_ptr = 553;
-        let log_eval_domain_size = ctx[2].to::<usize>();
-        let eval_domain_size = ctx[0];
-        let eval_domain_generator = ctx[4];
 
-        let mut i = fri_queue;
-        while i < fri_queue_end {
-            let query_idx = ctx[i];
-            let adjusted_query_idx = query_idx + eval_domain_size;
-            ctx[i] = adjusted_query_idx;
-            ctx[eval_points_ptr] = PrimeFieldElement0::expmod(
-                eval_domain_generator,
-                PrimeFieldElement0::bit_reverse(query_idx, log_eval_domain_size),
-                PrimeFieldElement0::K_MODULUS,
-            );
-            eval_points_ptr += 1;
-            i += 3;
-        }
+    // Same as verify, with the bytes of a compact proof (see stark_verifier_core::compact), which
+    // are read in place.
+    #[inline]
+    fn verify_compact(
+        &mut self,
+        proof: Bytes,
+        mut ctx: Vec<U256>,
+        fri_step_sizes: Vec<U256>,
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        self.ownable.when_not_paused()?;
+        FriVerifier::verify(self, ProofWords::Bytes(&proof), &mut ctx, &fri_step_sizes)?;
+        Ok(ctx)
     }
 
-    fn read_query_responses_and_decommit(
-        &self,
-        proof: &mut [U256],
-        ctx: &mut [U256],
-        n_total_columns: usize,
-        n_columns: usize,
-        mut proof_data_ptr: usize,
-        merkle_root: FixedBytes<32>,
-    ) -> Result<(), Vec<u8>> {
-        require!(n_columns <= n_total_columns, "Too many columns.");
-
-        let n_unique_queries = ctx[9].to::<usize>();
-        let channel_ptr = 10;
-        let fri_queue = 109;
-        let fri_queue_end = fri_queue + n_unique_queries * 3;
-        let merkle_queue_ptr = 13;
-        let row_size = n_columns * 32;
-        let proof_data_skip_bytes = n_total_columns - n_columns;
-
-        let mut proof_ptr = ctx[channel_ptr].to::<usize>();
-        let mut merkle_ptr = merkle_queue_ptr;
-
-        let mut i = fri_queue;
-        while i < fri_queue_end {
-            let mut j = proof_ptr;
-            let mut input_data = Vec::new();
-            while j < proof_ptr + row_size {
-                ctx[proof_data_ptr] = Self::read_ptr(proof, j, 8);
-                input_data.extend_from_slice(&ctx[proof_data_ptr].to_be_bytes::<32>());
-                proof_data_ptr += 1;
-                j += 32;
-            }
-
-            let merkle_leaf_hash: U256 = keccak(&input_data).into();
-            let mut merkle_leaf = merkle_leaf_hash
-                & uint!(0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF000000000000000000000000_U256);
-
-            if row_size == 32 {
-                merkle_leaf = Self::read_ptr(proof, proof_ptr, 8);
-            }
-
-            ctx[merkle_ptr] = ctx[i];
-            ctx[merkle_ptr + 1] = merkle_leaf;
-            merkle_ptr += 2;
-
-            i += 3;
-            proof_ptr += row_size;
-            proof_data_ptr += proof_data_skip_bytes;
-        }
-
-        ctx[channel_ptr] = U256::from(proof_ptr);
-        self.verify_merkle(ctx, merkle_queue_ptr, merkle_root, n_unique_queries)?;
-        Ok(())
+    // Reverts with DependencyCodeChanged if the code of a dependency is not the one pinned by init.
+    fn check_wiring(&self) -> Result<(), Vec<u8>> {
+        self.dependencies.check_all(&self.wiring())
     }
 
-    fn read_ptr(proof: &[U256], ptr: usize, offset: usize) -> U256 {
-        let element_index = ptr / 32;
-
-        if ptr % 32 == 0 {
-            proof[element_index]
-        } else {
-            let bit_shift = offset * 8;
-            let element1 = proof[element_index] << bit_shift;
-            let element2 = proof[element_index + 1] >> (256 - bit_shift);
-            element1 | element2
-        }
+    // The names, addresses and pinned code hashes of the dependencies.
+    fn verifier_config(&self) -> (Vec<String>, Vec<Address>, Vec<FixedBytes<32>>) {
+        self.dependencies.config(&self.wiring())
     }
+}
 
-    fn read_bytes_from_ptr(proof: &[U256], ctx: &mut [U256], channel_ptr: usize) -> U256 {
-        let proof_ptr = ctx[channel_ptr];
-        let val = Self::read_ptr(proof, proof_ptr.to::<usize>(), 8);
-        ctx[channel_ptr] = proof_ptr + U256::from(32);
-
-        let mut input_data = Vec::new();
-        input_data.extend_from_slice(&(ctx[channel_ptr + 1] + U256::from(1)).to_be_bytes::<32>());
-        input_data.extend_from_slice(&val.to_be_bytes::<32>());
-        ctx[channel_ptr + 1] = uint!(keccak(&input_data).into());
-        ctx[channel_ptr + 2] = U256::ZERO;
-
-        val
+impl FriStatementVerifier {
+    fn wiring(&self) -> [(&'static str, Address); 3] {
+        [
+            ("oods", self.oods.get()),
+            ("friStatement", self.fri_statement.get()),
+            ("merkleStatement", self.merkle_statement.get()),
+        ]
     }
+}
 
-    fn fri_verify_layers(
-        &self,
-        ctx: &mut [U256],
-        proof: &[U256],
-        fri_step_sizes: &[U256],
-    ) -> Result<(), Vec<u8>> {
-        let n_queries = ctx[9].to::<usize>();
-        for i in 0..n_queries {
-            ctx[109 + 3 * i + 1] =
-                PrimeFieldElement0::fmul(ctx[109 + 3 * i + 1], PrimeFieldElement0::K_MONTGOMERY_R);
-        }
-
-        let mut input_data = Vec::new();
-        for i in 109..n_queries * 3 + 109 {
-            input_data.extend_from_slice(&ctx[i].to_be_bytes::<32>());
-        }
-        let mut input_layer_hash: U256 = uint!(keccak(&input_data).into());
+impl FriVerifier for FriStatementVerifier {
+    fn compute_oods(&mut self, ctx: &[U256]) -> Result<Vec<U256>, Vec<u8>> {
+        let oods_contract: ICpuOods = ICpuOods { address: self.oods.get() };
+        Ok(oods_contract.compute(&mut *self, ctx.to_vec())?)
+    }
 
-        let n_fri_inner_layers = fri_step_sizes.len() - 1;
-        let mut fri_step = 1;
-        let mut sum_of_step_sizes = fri_step_sizes[1];
-        let fri_statement_contract: IFriStatementVerifier = IFriStatementVerifier {
-            address: self.fri_statement.get(),
+    fn is_merkle_statement_valid(&mut self, statement: FixedBytes<32>) -> Result<bool, Vec<u8>> {
+        let merkle_statement_contract = IMerkleStatementVerifier {
+            address: self.dependencies.check(self.merkle_statement.get())?,
         };
-        while fri_step < n_fri_inner_layers {
-            let mut data_to_hash = Vec::new();
-            let output_layer_hash = Self::read_bytes_from_ptr(proof, ctx, 10);
-            data_to_hash.extend_from_slice(&ctx[295 + fri_step].to_be_bytes::<32>());
-            data_to_hash.extend_from_slice(&fri_step_sizes[fri_step].to_be_bytes::<32>());
-            data_to_hash.extend_from_slice(&input_layer_hash.to_be_bytes::<32>());
-            data_to_hash.extend_from_slice(&output_layer_hash.to_be_bytes::<32>());
-            data_to_hash.extend_from_slice(&ctx[305 + fri_step - 1].to_be_bytes::<32>());
-
-            let hash: FixedBytes<32> = keccak(&data_to_hash).into();
-            require!(
-                fri_statement_contract.is_valid(self, hash)?,
-                "INVALIDATED_FRI_STATEMENT"
-            );
-
-            input_layer_hash = output_layer_hash;
-            fri_step += 1;
-            sum_of_step_sizes += fri_step_sizes[fri_step];
-        }
-
-        let mut data_to_hash = Vec::new();
-        data_to_hash.extend_from_slice(&ctx[295 + fri_step].to_be_bytes::<32>());
-        data_to_hash.extend_from_slice(&fri_step_sizes[fri_step].to_be_bytes::<32>());
-        data_to_hash.extend_from_slice(&input_layer_hash.to_be_bytes::<32>());
-        data_to_hash.extend_from_slice(
-            &Self::compute_last_layer_hash(proof, ctx, n_queries, sum_of_step_sizes)?
-                .to_be_bytes::<32>(),
-        );
-        data_to_hash.extend_from_slice(&ctx[305 + fri_step - 1].to_be_bytes::<32>());
-
-        let hash: FixedBytes<32> = keccak(&data_to_hash).into();
-        require!(
-            fri_statement_contract.is_valid(self, hash)?,
-            "INVALIDATED_FRI_STATEMENT"
-        );
-
-        Ok(())
+        Ok(merkle_statement_contract.is_valid(&*self, statement)?)
     }
 
-    fn compute_last_layer_hash(
-        proof: &[U256],
-        ctx: &mut [U256],
-        n_points: usize,
-        sum_of_step_sizes: U256,
-    ) -> Result<U256, Vec<u8>> {
-        let fri_last_layer_deg_bound = ctx[315];
-        let group_order_minus_one = fri_last_layer_deg_bound * ctx[1] - U256::from(1);
-        let exponent = U256::from(1) << sum_of_step_sizes;
-        let mut cur_point_index = 0;
-        let mut prev_query = U256::ZERO;
-        let coefs_start = ctx[316].to::<usize>();
-
-        for i in 0..n_points {
-            let query = ctx[109 + 3 * i] >> sum_of_step_sizes;
-            if query == prev_query {
-                continue;
-            }
-
-            ctx[109 + 3 * cur_point_index] = query;
-            prev_query = query;
-
-            let mut point = PrimeFieldElement0::fpow(ctx[109 + 3 * i + 2], exponent);
-            ctx[109 + 3 * cur_point_index + 2] = point;
-
-            point = PrimeFieldElement0::fpow(point, group_order_minus_one);
-            ctx[109 + 3 * cur_point_index + 1] = Self::horner_eval(
-                proof,
-                coefs_start,
-                point,
-                fri_last_layer_deg_bound.to::<usize>(),
-            )?;
+    fn is_fri_statement_valid(&mut self, statement: FixedBytes<32>) -> Result<bool, Vec<u8>> {
+        let fri_statement_contract = IFriStatementVerifier {
+            address: self.dependencies.check(self.fri_statement.get())?,
+        };
+        Ok(fri_statement_contract.is_valid(&*self, statement)?)
+    }
+}
 
-            cur_point_index += 1;
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::{alloy_primitives::keccak256, testing::*};
+    use utils::require::*;
+
+    // The deployment, with the sender as the owner.
+    fn deploy(vm: &TestVM) -> FriStatementVerifier {
+        let mut contract = FriStatementVerifier::from(vm);
+        contract.constructor(vm.msg_sender()).unwrap();
+        contract
+    }
 
-        let mut data_to_hash = Vec::new();
-        for i in 109..cur_point_index * 3 + 109 {
-            data_to_hash.extend_from_slice(&ctx[i].to_be_bytes::<32>());
-        }
-        Ok(uint!(keccak(&data_to_hash).into()))
+    #[motsu::test]
+    fn test_init_before_owner() {
+        let vm = TestVM::default();
+        let mut contract = deploy(&vm);
+        let owner = vm.msg_sender();
+
+        // Whoever sees the deployment cannot wire the contract before the owner does.
+        let always_true = Address::repeat_byte(0x11);
+        vm.set_sender(always_true);
+        let err = contract.init(Address::repeat_byte(1), always_true, always_true).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::OnlyOwner(OnlyOwner { caller: always_true })));
+        assert_eq!(contract.ownable.owner(), owner);
+
+        vm.set_sender(owner);
+        contract.init(Address::repeat_byte(1), Address::repeat_byte(2), Address::repeat_byte(3)).unwrap();
+        assert_eq!(contract.fri_statement.get(), Address::repeat_byte(2));
     }
 
-    fn horner_eval(
-        proof: &[U256],
-        coefs_start: usize,
-        point: U256,
-        n_coefs: usize,
-    ) -> Result<U256, Vec<u8>> {
-        let mut result = U256::ZERO;
-        let prime = PrimeFieldElement0::K_MODULUS;
+    #[motsu::test]
+    fn test_init_once() {
+        let vm = TestVM::default();
+        let mut contract = deploy(&vm);
+        let (oods, fri_statement, merkle_statement) =
+            (Address::repeat_byte(1), Address::repeat_byte(2), Address::repeat_byte(3));
+        contract.init(oods, fri_statement, merkle_statement).unwrap();
+        let owner = contract.ownable.owner();
+
+        // Not even the owner can rewire the statement contracts after init.
+        let always_true = Address::repeat_byte(0x11);
+        vm.set_sender(always_true);
+        let err = contract.init(oods, always_true, always_true).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::OnlyOwner(OnlyOwner { caller: always_true })));
+        vm.set_sender(owner);
+        let err = contract.init(oods, always_true, always_true).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::AlreadyInitialized(AlreadyInitialized {})));
+        assert_eq!(contract.fri_statement.get(), fri_statement);
+        assert_eq!(contract.merkle_statement.get(), merkle_statement);
+    }
 
-        require!(
-            n_coefs % 8 == 0,
-            "Number of polynomial coefficients must be divisible by 8"
+    #[motsu::test]
+    fn test_verifier_config() {
+        let vm = TestVM::default();
+        let (oods, mock_provider) = (Address::repeat_byte(1), Address::repeat_byte(2));
+        vm.set_code(oods, vec![1]);
+        vm.set_code(mock_provider, vec![2]);
+        let mut contract = deploy(&vm);
+        contract.init(oods, mock_provider, mock_provider).unwrap();
+
+        // The always-true mock shows up as the same code hash for both statement contracts.
+        let (names, addresses, code_hashes) = contract.verifier_config();
+        assert_eq!(names, ["oods", "friStatement", "merkleStatement"]);
+        assert_eq!(addresses, [oods, mock_provider, mock_provider]);
+        assert_eq!(code_hashes, [keccak256([1]), keccak256([2]), keccak256([2])]);
+        contract.check_wiring().unwrap();
+
+        vm.set_code(oods, vec![3]);
+        let err = contract.check_wiring().unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::DependencyCodeChanged(DependencyCodeChanged {
+                dependency: oods,
+                expected: keccak256([1]),
+                actual: keccak256([3]),
+            }))
         );
-        require!(
-            n_coefs < 4096,
-            "No more than 4096 coefficients are supported"
-        );
-
-        let mut coefs_ptr = coefs_start + n_coefs;
-        while coefs_ptr > coefs_start {
-            coefs_ptr -= 8;
-            result = proof[coefs_ptr + 7] + result.mul_mod(point, prime);
-            result = proof[coefs_ptr + 6] + result.mul_mod(point, prime);
-            result = proof[coefs_ptr + 5] + result.mul_mod(point, prime);
-            result = proof[coefs_ptr + 4] + result.mul_mod(point, prime);
-            result = proof[coefs_ptr + 3] + result.mul_mod(point, prime);
-            result = proof[coefs_ptr + 2] + result.mul_mod(point, prime);
-            result = proof[coefs_ptr + 1] + result.mul_mod(point, prime);
-            result = proof[coefs_ptr] + result.mul_mod(point, prime);
-        }
-
-        Ok(result % prime)
     }
 
-    fn verify_merkle(
-        &self,
-        ctx: &[U256],
-        queue_ptr: usize,
-        root: FixedBytes<32>,
-        n: usize,
-    ) -> Result<FixedBytes<32>, Vec<u8>> {
-        require!(n <= 128, "TOO_MANY_MERKLE_QUERIES");
-
-        let que_end_ptr = queue_ptr + n * 2;
-        let mut input_data = Vec::new();
-        for i in queue_ptr..que_end_ptr {
-            input_data.extend_from_slice(&ctx[i].to_be_bytes::<32>());
-        }
-        input_data.extend_from_slice(&root.as_slice());
-
-        let statement: FixedBytes<32> = keccak(&input_data).into();
-        let merkle_statement_contract: IMerkleStatementVerifier = IMerkleStatementVerifier {
-            address: self.merkle_statement.get(),
-        };
-        require!(
-            merkle_statement_contract.is_valid(self, statement)?,
-            "INVALIDATED_MERKLE_STATEMENT"
+    #[motsu::test]
+    fn test_pause() {
+        let vm = TestVM::default();
+        let mut contract = deploy(&vm);
+        contract.init(Address::ZERO, Address::ZERO, Address::ZERO).unwrap();
+
+        vm.set_sender(Address::repeat_byte(0x11));
+        let err = contract.ownable.pause().unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::OnlyOwner(OnlyOwner { caller: Address::repeat_byte(0x11) }))
         );
-        Ok(root)
+
+        vm.set_sender(contract.ownable.owner());
+        contract.ownable.pause().unwrap();
+        assert!(contract.ownable.paused());
+        let err = contract.verify(vec![], vec![], vec![]).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::ContractPaused(ContractPaused {})));
+        let err = contract.verify_compact(vec![].into(), vec![], vec![]).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::ContractPaused(ContractPaused {})));
+
+        let new_owner = Address::repeat_byte(0x22);
+        contract.ownable.transfer_ownership(new_owner).unwrap();
+        let err = contract.ownable.unpause().unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::OnlyOwner(OnlyOwner { caller: vm.msg_sender() })));
+        vm.set_sender(new_owner);
+        contract.ownable.unpause().unwrap();
+        assert!(!contract.ownable.paused());
     }
 }
@@ -0,0 +1,236 @@
+use alloc::vec::Vec;
+use stylus_sdk::{
+    alloy_primitives::{uint, U256},
+    crypto::keccak,
+};
+use utils::{
+    felt252::{Felt252, K_MODULUS},
+    merkle_verifier::MerkleVerifier,
+    require,
+    require::*,
+};
+
+use crate::fri_transform::FriTransform;
+
+// The main component of FRI is the FRI step which takes
+// the i-th layer evaluations on a coset c*<g> and produces a single evaluation in layer i+1.
+// To this end the FriLayer holds the following data:
+// group:          holds the group <g> in bit reversed order.
+// half_inv_group: holds the group <g^-1>/<-1> in bit reversed order.
+//                 (We only need half of the inverse group)
+// Note that due to the bit reversed order, a prefix of size 2^k of either group
+// or half_inv_group has the same structure (but for a smaller group).
+pub struct FriLayer {
+    group: [U256; Self::MAX_COSET_SIZE],
+    half_inv_group: [U256; Self::MAX_COSET_SIZE / 2],
+}
+
+impl FriLayer {
+    pub const MAX_COSET_SIZE: usize = 1 << FriTransform::FRI_MAX_STEP_SIZE;
+    // Generator of the group of size MAX_COSET_SIZE: GENERATOR_VAL**((K_MODULUS - 1)/MAX_COSET_SIZE).
+    pub const FRI_GROUP_GEN: U256 =
+        uint!(0x5ec467b88826aba4537602d514425f3b0bdf467bbf302458337c45f6021e539_U256);
+
+    // The FRI queue is an array of triplets (query index, FRI value, FRI inversed point).
+    //    'query index' is an adjust query index,
+    //       see adjust_query_indices_and_prepare_eval_points for detail.
+    //    'FRI value' is the expected committed value at query index.
+    //    'FRI inversed point' is evaluation point corresponding to query index i.e.
+    //       inverse(
+    //          fpow(layerGenerator, bitReverse(query index - (1 << logLayerSize), logLayerSize)).
+    pub const FRI_QUEUE_SLOT_SIZE: usize = 3;
+
+    // Initializes the FRI group and half inv group.
+    pub fn new() -> Self {
+        let mut group = [U256::ZERO; Self::MAX_COSET_SIZE];
+        let mut half_inv_group = [U256::ZERO; Self::MAX_COSET_SIZE / 2];
+
+        // FRI_GROUP_GEN is the coset generator.
+        // Raising it to the (MAX_COSET_SIZE - 1) power gives us the inverse.
+        let gen_fri_group = Felt252::new(Self::FRI_GROUP_GEN);
+        let gen_fri_group_inv = gen_fri_group.pow(U256::from(Self::MAX_COSET_SIZE - 1));
+
+        let mut last_val = Felt252::ONE;
+        let mut last_val_inv = Felt252::ONE;
+        half_inv_group[0] = last_val_inv.value();
+        group[0] = last_val.value();
+        group[1] = (-last_val).value();
+
+        // To compute [1, -1 (== g^n/2), g^n/4, -g^n/4, ...]
+        // we compute half the elements and derive the rest using negation.
+        for i in 1..Self::MAX_COSET_SIZE / 2 {
+            last_val *= gen_fri_group;
+            last_val_inv *= gen_fri_group_inv;
+            let idx = Self::bit_reverse(i, FriTransform::FRI_MAX_STEP_SIZE - 1);
+
+            half_inv_group[idx] = last_val_inv.value();
+            group[2 * idx] = last_val.value();
+            group[2 * idx + 1] = (-last_val).value();
+        }
+
+        Self {
+            group,
+            half_inv_group,
+        }
+    }
+
+    // Returns the bit reversal of num assuming it has the given number of bits.
+    // For example, if we have number_of_bits = 6 and num = (0b)1101 == (0b)001101,
+    // the function will return (0b)101100.
+    fn bit_reverse(num: usize, number_of_bits: usize) -> usize {
+        let mut n = num;
+        let mut r = 0;
+        for _ in 0..number_of_bits {
+            r = (r << 1) | (n & 1);
+            n >>= 1;
+        }
+        r
+    }
+
+    // Gathers the coset_size elements that belong to the coset of the element at the head of
+    // the FRI queue. The elements are written to evaluations_on_coset.
+    // The coset elements are read either from the FRI queue or from the proof
+    // depending on whether the required element is in the queue or not.
+    // Returns
+    //   new_fri_queue_head - The updated FRI queue head i.e.
+    //     fri_queue_head + FRI_QUEUE_SLOT_SIZE * (# elements that were taken from the queue).
+    //   coset_idx - the start index of the coset that was gathered.
+    //   coset_offset - the x_inv field element that corresponds to coset_idx.
+    fn gather_coset_inputs(
+        &self,
+        proof: &[U256],
+        proof_ptr: &mut usize,
+        fri_queue: &[U256],
+        fri_queue_head: usize,
+        evaluations_on_coset: &mut [U256],
+        coset_size: usize,
+    ) -> Result<(usize, U256, U256), Vec<u8>> {
+        let mut fri_queue_head = fri_queue_head;
+        let mut queue_item_idx = fri_queue[fri_queue_head];
+        // The coset index is represented by the most significant bits of the queue item index.
+        let coset_idx = queue_item_idx & !U256::from(coset_size - 1);
+
+        // Get the algebraic coset offset:
+        // I.e. given c*g^(-k) compute c, where
+        //      g is the generator of the coset group.
+        //      k is bitReverse(offsetWithinCoset, log2(coset_size)).
+        //
+        // To do this we multiply the algebraic coset offset at the top of the queue (c*g^(-k))
+        // by the group element that corresponds to the index inside the coset (g^k).
+        let offset_within_coset = (queue_item_idx - coset_idx).to::<usize>();
+        let coset_offset = (Felt252::new(fri_queue[fri_queue_head + 2])
+            * Felt252::new(self.group[offset_within_coset]))
+        .value();
+
+        for (i, evaluation) in evaluations_on_coset.iter_mut().take(coset_size).enumerate() {
+            let field_element = if coset_idx + U256::from(i) == queue_item_idx {
+                // Take element from the queue rather than from the proof.
+                let field_element = fri_queue[fri_queue_head + 1];
+                // Reading the next index here is safe due to the delimiter after the queries.
+                fri_queue_head += Self::FRI_QUEUE_SLOT_SIZE;
+                queue_item_idx = fri_queue[fri_queue_head];
+                field_element
+            } else {
+                require!(*proof_ptr < proof.len(), FriDecommitmentTooShort {});
+                let field_element = proof[*proof_ptr];
+                *proof_ptr += 1;
+                field_element
+            };
+            // Note that we apply the modulo operation to convert the field elements we read
+            // from the proof to canonical representation (in the range [0, K_MODULUS - 1]).
+            *evaluation = field_element % K_MODULUS;
+        }
+
+        Ok((fri_queue_head, coset_idx, coset_offset))
+    }
+
+    // Computes the FRI step with eta = log2(fri_coset_size) for all the live queries.
+    // The inputs for the current layer are read from the FRI queue and the inputs
+    // for the next layer are written to the same queue (overwriting the input).
+    // See fri_verify_layers for the description for the FRI queue.
+    // The function returns the number of live queries remaining after computing the FRI step.
+    // The number of live queries decreases whenever multiple query points in the same
+    // coset are reduced to a single query in the next FRI layer.
+    // As the function computes the next layer it also collects that data from
+    // the previous layer for Merkle verification into merkle_queue.
+    #[allow(clippy::too_many_arguments)]
+    pub fn compute_next_layer(
+        &self,
+        proof: &[U256],
+        proof_ptr: &mut usize,
+        fri_queue: &mut [U256],
+        merkle_queue: &mut Vec<U256>,
+        n_queries: usize,
+        fri_eval_point: U256,
+        fri_coset_size: usize,
+    ) -> Result<usize, Vec<u8>> {
+        require!(
+            (1 << FriTransform::FRI_MIN_STEP_SIZE..=Self::MAX_COSET_SIZE).contains(&fri_coset_size),
+            InvalidFriStepSize { friStepSize: U256::from(fri_coset_size.trailing_zeros()) }
+        );
+        let mut evaluations_on_coset = [U256::ZERO; Self::MAX_COSET_SIZE];
+
+        // The inputs are read from the FRI queue and the result is written to the same queue.
+        // The inputs are never overwritten since gather_coset_inputs reads at least one element
+        // and transform_coset writes exactly one element.
+        let mut input_ptr = 0;
+        let input_end = Self::FRI_QUEUE_SLOT_SIZE * n_queries;
+        let mut output_ptr = 0;
+        while input_ptr < input_end {
+            let (new_input_ptr, coset_idx, coset_offset) = self.gather_coset_inputs(
+                proof,
+                proof_ptr,
+                fri_queue,
+                input_ptr,
+                &mut evaluations_on_coset,
+                fri_coset_size,
+            )?;
+            input_ptr = new_input_ptr;
+
+            // Compute the index of the coset evaluations in the Merkle queue.
+            let index = coset_idx / U256::from(fri_coset_size);
+            // Add (index, keccak256(evaluations_on_coset)) to the Merkle queue.
+            let mut input_data = Vec::with_capacity(fri_coset_size * 32);
+            for evaluation in &evaluations_on_coset[..fri_coset_size] {
+                input_data.extend_from_slice(&evaluation.to_be_bytes::<32>());
+            }
+            merkle_queue.push(index);
+            merkle_queue.push(
+                U256::from_be_bytes::<32>(keccak(&input_data).into())
+                    & MerkleVerifier::COMMITMENT_MASK,
+            );
+
+            let (fri_value, fri_inversed_point) = FriTransform::transform_coset(
+                &self.half_inv_group,
+                &evaluations_on_coset,
+                coset_offset,
+                fri_eval_point,
+                fri_coset_size,
+            )?;
+
+            // Add (index, fri_value, fri_inversed_point) to the FRI queue.
+            // Note that the index in the Merkle queue is also the index in the next FRI layer.
+            fri_queue[output_ptr] = index;
+            fri_queue[output_ptr + 1] = fri_value;
+            fri_queue[output_ptr + 2] = fri_inversed_point;
+            output_ptr += Self::FRI_QUEUE_SLOT_SIZE;
+        }
+
+        // Return the current number of live queries.
+        Ok(output_ptr / Self::FRI_QUEUE_SLOT_SIZE)
+    }
+
+    pub fn group(&self) -> &[U256] {
+        &self.group
+    }
+
+    pub fn half_inv_group(&self) -> &[U256] {
+        &self.half_inv_group
+    }
+}
+
+impl Default for FriLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,67 @@
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::U256;
+use utils::{felt252::Felt252, require, require::InvalidFriStepSize};
+
+// The FRI transform for a coset of size 2 (x, -x) takes the inputs
+// x, f(x), f(-x) and evalPoint
+// and returns
+// (f(x) + f(-x) + evalPoint*(f(x) - f(-x))/x) / 2.
+// The implementation here modifies this transformation slightly:
+// 1. Since dividing by 2 does not affect the degree, it is omitted here (and in the prover).
+// 2. The division by x is replaced by multiplication by x^-1, x^-1 is passed as input to the
+//    transform and (x^-1)^2 is returned as it will be needed in the next layer.
+// To apply the transformation on a larger coset the transformation above is used multiple times
+// with the evaluation points: evalPoint, evalPoint^2, evalPoint^4, ...
+pub struct FriTransform {}
+
+impl FriTransform {
+    // The supported step sizes are 2, 3 and 4.
+    pub const FRI_MIN_STEP_SIZE: usize = 2;
+    pub const FRI_MAX_STEP_SIZE: usize = 4;
+
+    // Performs a FRI transform for the coset of size fri_coset_size.
+    // Assumes the evaluations on the coset are given in bit reversed order, see
+    // FriLayer::gather_coset_inputs for more detail.
+    pub fn transform_coset(
+        fri_half_inv_group: &[U256],
+        evaluations_on_coset: &[U256],
+        coset_offset: U256,
+        fri_eval_point: U256,
+        fri_coset_size: usize,
+    ) -> Result<(U256, U256), Vec<u8>> {
+        require!(
+            matches!(fri_coset_size, 4 | 8 | 16),
+            InvalidFriStepSize { friStepSize: U256::from(fri_coset_size.trailing_zeros()) }
+        );
+
+        // The first layer pairs (f(x), f(-x)) where x^-1 = coset_offset * half_inv_group[i].
+        // Every following layer works on the squares of the previous layer's x^-1, which in bit
+        // reversed order are the squares of the factors of the even pairs.
+        let coset_offset = Felt252::new(coset_offset);
+        let fri_eval_point_div_by_x = Felt252::new(fri_eval_point) * coset_offset;
+        let mut factors: Vec<Felt252> = fri_half_inv_group[..fri_coset_size / 2]
+            .iter()
+            .map(|inv| fri_eval_point_div_by_x * Felt252::new(*inv))
+            .collect();
+        let mut values: Vec<Felt252> = evaluations_on_coset[..fri_coset_size]
+            .iter()
+            .map(|value| Felt252::new(*value))
+            .collect();
+
+        let mut layer_size = fri_coset_size;
+        while layer_size > 1 {
+            for i in 0..layer_size / 2 {
+                let f_x = values[2 * i];
+                let f_minus_x = values[2 * i + 1];
+                values[i] = f_x + f_minus_x + factors[i] * (f_x - f_minus_x);
+            }
+            layer_size /= 2;
+            for i in 0..layer_size / 2 {
+                factors[i] = factors[2 * i].square();
+            }
+        }
+
+        let next_x_inv = coset_offset.pow(U256::from(fri_coset_size));
+        Ok((values[0].value(), next_x_inv.value()))
+    }
+}
@@ -0,0 +1,358 @@
+//!
+//! FRI Statement Contract
+//! Stylus port of FriStatementContract.sol, FriLayer.sol and FriTransform.sol. Computes a single
+//! FRI layer, verifies its Merkle commitment and registers the resulting fact so it can be looked
+//! up by the FRI statement verifier.
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
+
+#[macro_use]
+extern crate alloc;
+use alloc::vec::Vec;
+use utils::{
+    fact_registry::FactRegistry, felt252::K_MODULUS, merkle_verifier::MerkleVerifier, require,
+    require::*,
+};
+
+#[path = "fri-layer.rs"]
+pub mod fri_layer;
+#[path = "fri-transform.rs"]
+pub mod fri_transform;
+use crate::{fri_layer::FriLayer, fri_transform::FriTransform};
+
+use stylus_sdk::{
+    alloy_primitives::{FixedBytes, U256},
+    crypto::keccak,
+    prelude::*,
+};
+
+sol_storage! {
+    #[entrypoint]
+    pub struct FriStatementContract {
+        #[borrow]
+        FactRegistry fact_registry;
+    }
+}
+
+#[public]
+#[inherit(FactRegistry)]
+impl FriStatementContract {
+    // Compute a single FRI layer of size fri_step_size at evaluation_point starting from input
+    // fri_queue, and the extra witnesses in the "proof" channel. Also check that the input and
+    // witnesses belong to a Merkle tree with root expected_root, again using witnesses from "proof".
+    // After verification, register the FRI fact hash, which is:
+    // keccak256(
+    //     evaluation_point,
+    //     fri_step_size,
+    //     keccak256(fri_queue_input),
+    //     keccak256(fri_queue_output),  // The FRI queue after processing the FRI layer
+    //     expected_root
+    // )
+    #[inline]
+    #[selector(name = "verifyFRI")]
+    pub fn verify_fri(
+        &mut self,
+        proof: Vec<U256>,
+        fri_queue: Vec<U256>,
+        evaluation_point: U256,
+        fri_step_size: U256,
+        expected_root: U256,
+    ) -> Result<(), Vec<u8>> {
+        require!(
+            fri_step_size <= U256::from(FriTransform::FRI_MAX_STEP_SIZE),
+            InvalidFriStepSize { friStepSize: fri_step_size }
+        );
+
+        // Verify evaluation point within valid range.
+        require!(evaluation_point < K_MODULUS, InvalidEvalPoint { evalPoint: evaluation_point });
+
+        // Validate the FRI queue.
+        let mut fri_queue = fri_queue;
+        Self::validate_fri_queue(&mut fri_queue)?;
+
+        let mut n_queries = fri_queue.len() / FriLayer::FRI_QUEUE_SLOT_SIZE;
+        let input_hash = Self::hash_fri_queue(&fri_queue, n_queries);
+
+        let fri_layer = FriLayer::new();
+        let mut merkle_queue = Vec::with_capacity(2 * n_queries);
+        let mut proof_ptr = 0;
+        n_queries = fri_layer.compute_next_layer(
+            &proof,
+            &mut proof_ptr,
+            &mut fri_queue,
+            &mut merkle_queue,
+            n_queries,
+            evaluation_point,
+            1 << fri_step_size.to::<usize>(),
+        )?;
+
+        MerkleVerifier::verify_merkle(
+            &proof,
+            &mut proof_ptr,
+            &mut merkle_queue,
+            expected_root,
+            n_queries,
+        )?;
+
+        let output_hash = Self::hash_fri_queue(&fri_queue, n_queries);
+        let mut data_to_hash = Vec::with_capacity(5 * 32);
+        data_to_hash.extend_from_slice(&evaluation_point.to_be_bytes::<32>());
+        data_to_hash.extend_from_slice(&fri_step_size.to_be_bytes::<32>());
+        data_to_hash.extend_from_slice(&input_hash.to_be_bytes::<32>());
+        data_to_hash.extend_from_slice(&output_hash.to_be_bytes::<32>());
+        data_to_hash.extend_from_slice(&expected_root.to_be_bytes::<32>());
+
+        let fact_hash: FixedBytes<32> = keccak(&data_to_hash);
+        self.fact_registry.register_fact(fact_hash);
+        Ok(())
+    }
+}
+
+impl FriStatementContract {
+    // Validates the entries of the FRI queue.
+    //
+    // The fri_queue should have 3*n_queries + 1 elements, beginning with n_queries triplets
+    // of the form (query_index, FRI_value, FRI_inverse_point), and ending with a single buffer
+    // cell set to 0, which is accessed and read during the computation of the FRI layer.
+    //
+    // Queries need to be in the range [2**height .. 2**(height+1)-1] and strictly incrementing.
+    // The FRI values and inverses need to be smaller than K_MODULUS.
+    fn validate_fri_queue(fri_queue: &mut [U256]) -> Result<(), Vec<u8>> {
+        // The FRI queue must be composed of triplets plus one delimiter cell, with at least one
+        // query to process.
+        require!(fri_queue.len() % 3 == 1 && fri_queue.len() >= 4, InvalidFriQueue {});
+
+        // Force delimiter cell to 0, this is cheaper then asserting it.
+        let delimiter = fri_queue.len() - 1;
+        fri_queue[delimiter] = U256::ZERO;
+
+        // We need to check that Qi+1 > Qi for each i,
+        // Given that the queries are sorted the height range requirement can be validated by
+        // checking that (Q1 ^ Qn) < Q1.
+        // This check affirms that all queries are within the same logarithmic step.
+        let n_queries = fri_queue.len() / 3;
+        let mut prev_query = U256::ZERO;
+        for i in 0..n_queries {
+            // Verify that queries are strictly incrementing.
+            require!(fri_queue[3 * i] > prev_query, InvalidFriQuery { query: U256::from(i) });
+            // Verify FRI value and inverse are within valid range.
+            require!(
+                fri_queue[3 * i + 1] < K_MODULUS && fri_queue[3 * i + 2] < K_MODULUS,
+                InvalidFriQuery { query: U256::from(i) }
+            );
+            prev_query = fri_queue[3 * i];
+        }
+
+        // Verify all queries are on the same logarithmic step.
+        require!(
+            (fri_queue[0] ^ fri_queue[3 * n_queries - 3]) < fri_queue[0],
+            InvalidQueriesRange {}
+        );
+        Ok(())
+    }
+
+    fn hash_fri_queue(fri_queue: &[U256], n_queries: usize) -> U256 {
+        let mut input_data = Vec::with_capacity(n_queries * FriLayer::FRI_QUEUE_SLOT_SIZE * 32);
+        for val in &fri_queue[..n_queries * FriLayer::FRI_QUEUE_SLOT_SIZE] {
+            input_data.extend_from_slice(&val.to_be_bytes::<32>());
+        }
+        U256::from_be_bytes::<32>(keccak(&input_data).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::testing::*;
+    use utils::prime_field_element0::PrimeFieldElement0;
+
+    const EVAL_POINT: U256 = U256::from_limbs([0x1234, 0x5678, 0, 0]);
+
+    fn horner(coefs: &[U256], x: U256) -> U256 {
+        coefs.iter().rev().fold(U256::ZERO, |acc, c| {
+            PrimeFieldElement0::fadd(PrimeFieldElement0::fmul(acc, x), *c)
+        })
+    }
+
+    fn poly(degree: usize) -> Vec<U256> {
+        (0..degree).map(|i| U256::from(7 * i * i + 3)).collect()
+    }
+
+    // Evaluations of the polynomial on the coset c*<g> in bit reversed order, i.e. the
+    // evaluation at index k is p(c * group[k]).
+    fn coset_evaluations(coefs: &[U256], c: U256, coset_size: usize) -> Vec<U256> {
+        let fri_layer = FriLayer::new();
+        fri_layer.group()[..coset_size]
+            .iter()
+            .map(|g| horner(coefs, PrimeFieldElement0::fmul(c, *g)))
+            .collect()
+    }
+
+    fn x_inv(c: U256, offset_within_coset: usize) -> U256 {
+        let fri_layer = FriLayer::new();
+        PrimeFieldElement0::inverse(PrimeFieldElement0::fmul(
+            c,
+            fri_layer.group()[offset_within_coset],
+        ))
+    }
+
+    fn leaf_hash(evaluations: &[U256]) -> U256 {
+        let mut input_data = Vec::new();
+        for val in evaluations {
+            input_data.extend_from_slice(&val.to_be_bytes::<32>());
+        }
+        U256::from_be_bytes::<32>(keccak(&input_data).into()) & MerkleVerifier::COMMITMENT_MASK
+    }
+
+    #[motsu::test]
+    fn test_transform_coset_folds_low_degree_polynomial() {
+        // Folding a coset of size 2^k of a polynomial p with deg(p) < 2^k gives 2^k * p(evalPoint).
+        let fri_layer = FriLayer::new();
+        let c = U256::from(3);
+        let coset_offset = PrimeFieldElement0::inverse(c);
+        for step_size in 2..=4 {
+            let coset_size = 1 << step_size;
+            let coefs = poly(coset_size);
+            let evaluations = coset_evaluations(&coefs, c, coset_size);
+            let (value, next_x_inv) = FriTransform::transform_coset(
+                fri_layer.half_inv_group(),
+                &evaluations,
+                coset_offset,
+                EVAL_POINT,
+                coset_size,
+            )
+            .unwrap();
+
+            assert_eq!(
+                value,
+                PrimeFieldElement0::fmul(U256::from(coset_size), horner(&coefs, EVAL_POINT))
+            );
+            assert_eq!(
+                next_x_inv,
+                PrimeFieldElement0::fpow(coset_offset, U256::from(coset_size))
+            );
+        }
+    }
+
+    #[motsu::test]
+    fn test_verify_fri_registers_fact() {
+        let vm = TestVM::default();
+        let mut contract = FriStatementContract::from(&vm);
+
+        // Step size 2 over a layer of 8 elements: two cosets of size 4 (query indices 8..15),
+        // committed in a Merkle tree of height 1 (leaf indices 2 and 3).
+        let coefs = poly(4);
+        let (c2, c3) = (U256::from(5), U256::from(11));
+        let coset2 = coset_evaluations(&coefs, c2, 4);
+        let coset3 = coset_evaluations(&coefs, c3, 4);
+        let root = MerkleVerifier::hash_node(leaf_hash(&coset2), leaf_hash(&coset3));
+
+        let fri_queue = vec![
+            U256::from(9), coset2[1], x_inv(c2, 1),
+            U256::from(10), coset2[2], x_inv(c2, 2),
+            U256::from(13), coset3[1], x_inv(c3, 1),
+            U256::ZERO,
+        ];
+        let proof = vec![coset2[0], coset2[3], coset3[0], coset3[2], coset3[3]];
+
+        if let Err(e) = contract.verify_fri(
+            proof,
+            fri_queue.clone(),
+            EVAL_POINT,
+            U256::from(2),
+            root,
+        ) {
+            panic!("Error: {:?}", StarkError::decode(&e));
+        }
+
+        let folded = PrimeFieldElement0::fmul(U256::from(4), horner(&coefs, EVAL_POINT));
+        let next_layer = vec![
+            U256::from(2), folded,
+            PrimeFieldElement0::fpow(PrimeFieldElement0::inverse(c2), U256::from(4)),
+            U256::from(3), folded,
+            PrimeFieldElement0::fpow(PrimeFieldElement0::inverse(c3), U256::from(4)),
+        ];
+        let mut data_to_hash = Vec::new();
+        data_to_hash.extend_from_slice(&EVAL_POINT.to_be_bytes::<32>());
+        data_to_hash.extend_from_slice(&U256::from(2).to_be_bytes::<32>());
+        data_to_hash.extend_from_slice(
+            &FriStatementContract::hash_fri_queue(&fri_queue, 3).to_be_bytes::<32>(),
+        );
+        data_to_hash.extend_from_slice(
+            &FriStatementContract::hash_fri_queue(&next_layer, 2).to_be_bytes::<32>(),
+        );
+        data_to_hash.extend_from_slice(&root.to_be_bytes::<32>());
+
+        assert!(contract.fact_registry.is_valid(keccak(&data_to_hash)));
+    }
+
+    #[motsu::test]
+    fn test_verify_fri_rejects_wrong_root() {
+        let vm = TestVM::default();
+        let mut contract = FriStatementContract::from(&vm);
+
+        let coefs = poly(4);
+        let c = U256::from(5);
+        let coset = coset_evaluations(&coefs, c, 4);
+        let fri_queue = vec![U256::from(5), coset[1], x_inv(c, 1), U256::ZERO];
+        let proof = vec![coset[0], coset[2], coset[3], U256::from(1) << 96];
+
+        let err = contract
+            .verify_fri(proof, fri_queue, EVAL_POINT, U256::from(2), U256::from(1) << 96)
+            .unwrap_err();
+        assert!(matches!(StarkError::decode(&err), Some(StarkError::InvalidMerkleProof(_))));
+        assert!(!contract.fact_registry.has_registered_fact());
+    }
+
+    #[motsu::test]
+    fn test_verify_fri_rejects_invalid_input() {
+        let vm = TestVM::default();
+        let mut contract = FriStatementContract::from(&vm);
+        let check = |res: Result<(), Vec<u8>>, err: StarkError| {
+            assert_eq!(StarkError::decode(&res.unwrap_err()), Some(err));
+        };
+
+        let queue = vec![U256::from(5), U256::from(1), U256::from(1), U256::ZERO];
+        check(
+            contract.verify_fri(vec![], queue.clone(), EVAL_POINT, U256::from(5), U256::ZERO),
+            StarkError::InvalidFriStepSize(InvalidFriStepSize { friStepSize: U256::from(5) }),
+        );
+        check(
+            contract.verify_fri(
+                vec![],
+                queue.clone(),
+                K_MODULUS,
+                U256::from(2),
+                U256::ZERO,
+            ),
+            StarkError::InvalidEvalPoint(InvalidEvalPoint { evalPoint: K_MODULUS }),
+        );
+        check(
+            contract.verify_fri(vec![], queue[..3].to_vec(), EVAL_POINT, U256::from(2), U256::ZERO),
+            StarkError::InvalidFriQueue(InvalidFriQueue {}),
+        );
+        check(
+            contract.verify_fri(vec![], queue.clone(), EVAL_POINT, U256::from(1), U256::ZERO),
+            StarkError::InvalidFriStepSize(InvalidFriStepSize { friStepSize: U256::from(1) }),
+        );
+
+        let unsorted = vec![
+            U256::from(6), U256::from(1), U256::from(1),
+            U256::from(5), U256::from(1), U256::from(1),
+            U256::ZERO,
+        ];
+        check(
+            contract.verify_fri(vec![], unsorted, EVAL_POINT, U256::from(2), U256::ZERO),
+            StarkError::InvalidFriQuery(InvalidFriQuery { query: U256::from(1) }),
+        );
+
+        let out_of_range = vec![
+            U256::from(5), U256::from(1), U256::from(1),
+            U256::from(9), U256::from(1), U256::from(1),
+            U256::ZERO,
+        ];
+        check(
+            contract.verify_fri(vec![], out_of_range, EVAL_POINT, U256::from(2), U256::ZERO),
+            StarkError::InvalidQueriesRange(InvalidQueriesRange {}),
+        );
+    }
+}
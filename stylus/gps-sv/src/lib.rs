@@ -5,75 +5,149 @@
 #![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
 extern crate alloc;
 
-mod bootloader;
-mod consts;
 use alloc::{vec, vec::Vec};
-use bootloader::{
-    BootloaderCompiledProgram, APPLICATION_BOOTLOADER_PROGRAM_HASH, SIMPLE_BOOTLOADER_PROGRAM_HASH,
-};
-use consts::{page_info::*, public_input_offsets};
+use stark_verifier_core::compact::GpsInput;
+use stark_verifier_core::gps::{self, consts::public_input_offsets, K_MODULUS};
+use utils::{dependencies::Dependencies, fact_registry::FactRegistry, ownable::Ownable, require, require::*};
 
 /// Import items from the SDK. The prelude contains common traits and macros.
+use alloy_sol_types::{sol, SolCall};
 use stylus_sdk::{
-    alloy_primitives::{uint, Address, FixedBytes, I256, U256},
-    call::{self, Call, MethodError},
+    abi::Bytes,
+    alloy_primitives::{Address, U256, U64},
+    stylus_core::calls::{self, context::Call},
     // console,
-    crypto::keccak,
     prelude::*,
     storage::*,
 };
 
-macro_rules! require {
-    ($cond:expr, $msg:expr) => {
-        if !$cond {
-            return Err($msg.as_bytes().to_vec());
-        }
-    };
+sol! {
+    // Emitted for each registered fact, links the program output fact to the hashes of the
+    // memory pages it was computed from.
+    event LogMemoryPagesHashes(bytes32 programOutputFact, bytes32[] pagesHashes);
+    // Proofs can be verified with the verifier from activationTime on. The delay after addVerifier
+    // lets fact consumers react to a verifier they do not trust before it registers any fact.
+    event VerifierAdded(uint256 indexed cairoVerifierId, address verifier, uint256 activationTime);
+    event VerifierDisabled(uint256 indexed cairoVerifierId, address verifier);
 }
 
-sol_interface! {
+// Called through the host (self.vm()), which the tests mock with TestVM::mock_call.
+sol! {
     interface IMemoryPageFactRegistry {
         function registerRegularMemoryPage(uint256[] memory memory_pairs, uint256 z, uint256 alpha, uint256 prime) external returns (bytes32, bytes32, uint256);
     }
     interface ICairoVerifierContract {
-        function verifyProofExternal(uint256[] calldata proofParams, uint256[] calldata proof, uint256[] calldata publicInput) external returns (uint256[]);
+        function verifyProofExternal(uint256[] calldata proof_params, uint256[] calldata proof, uint256[] calldata public_input) external returns (uint256[]);
+        function verifyProofCompact(bytes calldata input) external returns (uint256[]);
         function getLayoutInfo() external view returns (uint256 publicMemoryOffset, uint256 selectedBuiltins);
     }
 }
 
+// A CPU verifier registered under the index of its entry, the cairo verifier id of the proofs.
+#[storage]
+pub struct CairoVerifier {
+    address: StorageAddress,
+    // The block timestamp from which proofs can be verified with it.
+    activation_time: StorageU64,
+    disabled: StorageBool,
+}
+
 #[storage]
 #[entrypoint]
 pub struct GpsStatementVerifier {
-    initialized: StorageBool,
+    #[borrow]
+    ownable: Ownable,
+    dependencies: Dependencies,
     memory_page_fact_registry: StorageAddress,
-    verifiers: StorageVec<StorageAddress>,
-    verified_facts: StorageMap<FixedBytes<32>, StorageBool>,
-    any_fact_registered: StorageBool,
+    verifiers: StorageVec<CairoVerifier>,
+    // The delay in seconds between addVerifier and the activation of the verifier.
+    verifier_timelock: StorageU64,
+    // Exposes isValid and hasRegisteredFact, so GpsStatementVerifier can be queried as a
+    // fact registry.
+    #[borrow]
+    fact_registry: FactRegistry,
 }
 
-impl BootloaderCompiledProgram for GpsStatementVerifier {}
-
 /// Declare that `GpsStatementVerifier` is a contract with the following external methods.
 #[public]
+#[inherit(FactRegistry, Ownable)]
 impl GpsStatementVerifier {
+    #[constructor]
+    pub fn constructor(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable.init_owner(owner)
+    }
+
+    // Can only be called once, by the owner. The verifiers are active right away,
+    // the ones added later with addVerifier after verifier_timelock seconds.
     pub fn init(
         &mut self,
         memory_page_fact_registry: Address,
         verifiers: Vec<Address>,
+        verifier_timelock: u64,
     ) -> Result<(), Vec<u8>> {
-        require!(!self.initialized.get(), "already initialized");
-
-        self.memory_page_fact_registry
-            .set(memory_page_fact_registry);
+        self.ownable.initialize()?;
+        self.dependencies.set(
+            &mut self.memory_page_fact_registry,
+            "memoryPageFactRegistry",
+            memory_page_fact_registry,
+        );
+        self.verifier_timelock.set(U64::from(verifier_timelock));
 
-        for addr in verifiers {
-            self.verifiers.push(addr);
+        let now = self.vm().block_timestamp();
+        for verifier in verifiers {
+            self.push_verifier(verifier, now);
         }
+        Ok(())
+    }
+
+    // Registers a verifier, e.g. for a new layout, under the next cairo verifier id. Returns the id.
+    pub fn add_verifier(&mut self, verifier: Address) -> Result<U256, Vec<u8>> {
+        self.ownable.only_owner()?;
+        let activation_time = self
+            .vm()
+            .block_timestamp()
+            .saturating_add(self.verifier_timelock.get().to());
+        Ok(self.push_verifier(verifier, activation_time))
+    }
 
-        self.initialized.set(true);
+    // Rejects the proofs of the verifier from now on. Ids are never reused.
+    pub fn disable_verifier(&mut self, cairo_verifier_id: U256) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner()?;
+        let unknown_verifier = UnknownCairoVerifier { cairoVerifierId: cairo_verifier_id };
+        require!(cairo_verifier_id < U256::from(self.verifiers.len()), unknown_verifier);
+        let mut entry = self.verifiers.setter(cairo_verifier_id.to::<usize>()).ok_or(unknown_verifier)?;
+        require!(
+            !entry.disabled.get(),
+            CairoVerifierDisabled { cairoVerifierId: cairo_verifier_id }
+        );
+        entry.disabled.set(true);
+        let verifier = entry.address.get();
+        log(
+            self.vm(),
+            VerifierDisabled { cairoVerifierId: cairo_verifier_id, verifier },
+        );
         Ok(())
     }
 
+    // The registered verifiers by cairo verifier id: their addresses, activation times and
+    // whether they are disabled.
+    pub fn get_verifiers(&self) -> (Vec<Address>, Vec<u64>, Vec<bool>) {
+        let mut addresses = Vec::new();
+        let mut activation_times = Vec::new();
+        let mut disabled = Vec::new();
+        for i in 0..self.verifiers.len() {
+            let Some(entry) = self.verifiers.get(i) else { break };
+            addresses.push(entry.address.get());
+            activation_times.push(entry.activation_time.get().to());
+            disabled.push(entry.disabled.get());
+        }
+        (addresses, activation_times, disabled)
+    }
+
+    pub fn get_verifier_timelock(&self) -> u64 {
+        self.verifier_timelock.get().to()
+    }
+
     pub fn verify_proof_and_register(
         &mut self,
         proof_params: Vec<U256>,
@@ -82,469 +156,510 @@ impl GpsStatementVerifier {
         cairo_aux_input: Vec<U256>,
         verifier_id: U256,
     ) -> Result<(), Vec<u8>> {
-        // fail if it has not been initialized
+        let (verifier_contract, public_memory_pages) =
+            self.register_main_page(&task_metadata, &cairo_aux_input, verifier_id)?;
+        let cairo_public_input = &cairo_aux_input[..cairo_aux_input.len() - 2];
 
-        require!(self.initialized.get(), "not initialized");
-
-        let verifier_id_usize: usize = match verifier_id.try_into() {
-            Ok(val) => val,
-            Err(_) => return Err("Verifier Id does not fit in usize".as_bytes().to_vec()),
+        let verify_proof = ICairoVerifierContract::verifyProofExternalCall {
+            proof_params,
+            proof,
+            public_input: cairo_public_input.to_vec(),
         };
+        self.call_contract(verifier_contract, &verify_proof)?;
 
-        require!(
-            verifier_id_usize < self.verifiers.len(),
-            "cairoVerifierId is out of range."
-        );
-        let cairo_public_input: &[U256] = &cairo_aux_input[..cairo_aux_input.len() - 2];
-
-        let verifier_address = match self.verifiers.get(verifier_id_usize) {
-            Some(verifier) => verifier,
-            None => return Err("Verifier not found".as_bytes().to_vec()),
-        };
+        self.register_gps_facts(
+            &task_metadata,
+            public_memory_pages,
+            cairo_aux_input[public_input_offsets::OFFSET_OUTPUT_BEGIN_ADDR],
+        )?;
 
-        let verifier_contract = ICairoVerifierContract::new(verifier_address);
+        Ok(())
+    }
 
-        let mut selected_builtins = uint!(151_U256);
+    // Same as verify_proof_and_register, with the input in the compact encoding of
+    // stark_verifier_core::compact. The sections of the STARK input are forwarded to the CPU
+    // verifier as they are.
+    pub fn verify_proof_and_register_compact(&mut self, input: Bytes) -> Result<(), Vec<u8>> {
+        let input = GpsInput::decode(&input)?;
+        let (verifier_contract, public_memory_pages) = self.register_main_page(
+            &input.task_metadata,
+            &input.cairo_aux_input,
+            input.cairo_verifier_id,
+        )?;
+        let verify_proof = ICairoVerifierContract::verifyProofCompactCall { input: input.stark_input().into() };
+        self.call_contract(verifier_contract, &verify_proof)?;
 
-        let public_memory_offset_usize: usize = 21;
+        self.register_gps_facts(
+            &input.task_metadata,
+            public_memory_pages,
+            input.cairo_aux_input[public_input_offsets::OFFSET_OUTPUT_BEGIN_ADDR],
+        )?;
 
-        require!(
-            cairo_public_input.len() > public_memory_offset_usize,
-            "Invalid cairoAuxInput length."
+        Ok(())
+    }
+}
+impl GpsStatementVerifier {
+    fn push_verifier(&mut self, verifier: Address, activation_time: u64) -> U256 {
+        let cairo_verifier_id = U256::from(self.verifiers.len());
+        let mut entry = self.verifiers.grow();
+        entry.address.set(verifier);
+        entry.activation_time.set(U64::from(activation_time));
+        log(
+            self.vm(),
+            VerifierAdded {
+                cairoVerifierId: cairo_verifier_id,
+                verifier,
+                activationTime: U256::from(activation_time),
+            },
         );
+        cairo_verifier_id
+    }
 
-        let public_memory_pages: &[U256] = &cairo_public_input[public_memory_offset_usize..];
-        let n_pages: usize = public_memory_pages[0]
-            .try_into()
-            .map_err(|_| "Invalid nPages.")?;
-        require!(n_pages < 10000, "Invalid nPages.");
+    // Registers the main page of the public memory and checks it against the public input. Returns
+    // the verifier of the proof and the public memory pages.
+    fn register_main_page<'a>(
+        &mut self,
+        task_metadata: &[U256],
+        cairo_aux_input: &'a [U256],
+        verifier_id: U256,
+    ) -> Result<(Address, &'a [U256]), Vec<u8>> {
+        self.ownable.only_initialized()?;
+        self.ownable.when_not_paused()?;
+
+        let unknown_verifier = UnknownCairoVerifier { cairoVerifierId: verifier_id };
+        require!(verifier_id < U256::from(self.verifiers.len()), unknown_verifier);
+        let verifier_id_usize: usize = verifier_id.to();
+        // The aux input ends with the interaction elements z and alpha.
+        require!(cairo_aux_input.len() >= 2, InvalidCairoAuxInput {});
+        let cairo_public_input: &[U256] = &cairo_aux_input[..cairo_aux_input.len() - 2];
 
+        let (verifier_address, activation_time, disabled) = match self.verifiers.get(verifier_id_usize) {
+            Some(entry) => (entry.address.get(), entry.activation_time.get().to::<u64>(), entry.disabled.get()),
+            None => return Err(unknown_verifier.into()),
+        };
+        require!(!disabled, CairoVerifierDisabled { cairoVerifierId: verifier_id });
         require!(
-            public_memory_pages.len() == n_pages * (PAGE_INFO_SIZE + 1),
-            "Invalid publicMemoryPages length."
+            self.vm().block_timestamp() >= activation_time,
+            CairoVerifierNotActive { cairoVerifierId: verifier_id, activationTime: U256::from(activation_time) }
         );
 
-        let (public_memory_length, memory_hash, product) =
-            match Self::register_public_memory_main_page(
-                &mut *self,
-                &task_metadata,
-                &cairo_aux_input,
-                &mut selected_builtins,
-            ) {
-                Ok(val) => val,
-                Err(e) => return Err(e),
-            };
+        // The layout of the public input and the builtins of the bootloader depend on the verifier.
+        let layout_info =
+            self.static_call_contract(verifier_address, &ICairoVerifierContract::getLayoutInfoCall {})?;
+        let (public_memory_offset, mut selected_builtins) =
+            (layout_info.publicMemoryOffset, layout_info.selectedBuiltins);
+        let public_memory_pages =
+            gps::get_public_memory_pages(cairo_public_input, public_memory_offset)?;
+        let n_pages: usize = public_memory_pages[0].to();
+
+        let (public_memory_length, memory_hash, product) = Self::register_public_memory_main_page(
+            &mut *self,
+            task_metadata,
+            cairo_aux_input,
+            &mut selected_builtins,
+        )?;
         // console!("public_memory_length: {}", public_memory_length);
         // console!("memory_hash: {}", memory_hash);
         // console!("product: {}", product);
-        require!(
-            public_memory_pages[PAGE_INFO_SIZE_OFFSET] == public_memory_length,
-            "Invalid size for memory page 0."
-        );
-        // require!(
-        //     public_memory_pages[PAGE_INFO_HASH_OFFSET] == memory_hash,
-        //     "Invalid hash for memory page 0."
-        // );
-        // require!(
-        //     public_memory_pages[n_pages * PAGE_INFO_SIZE] == product,
-        //     "Invalid cumulative product for memory page 0."
-        // );
-
-        let _result = verifier_contract.verify_proof_external(
-            &mut *self,
-            proof_params,
-            proof,
-            cairo_public_input.to_vec(),
-        )?;
-
-        self.register_gps_facts(
-            &task_metadata,
-            &public_memory_pages,
-            cairo_aux_input[public_input_offsets::OFFSET_OUTPUT_BEGIN_ADDR],
+        gps::verify_main_page_info(
+            public_memory_pages,
+            n_pages,
+            public_memory_length,
+            memory_hash,
+            product,
         )?;
 
-        Ok(())
-    }
-
-    // pub fn has_registered_fact(&self) -> bool {
-    //     self.any_fact_registered.get()
-    // }
-
-    // fn is_valid(&self, fact: FixedBytes<32>) -> bool {
-    //     self.fact_check(fact)
-    // }
-}
-fn construct_node(
-    node_stack: &mut [U256],
-    node_stack_len: usize,
-    n_nodes: usize,
-) -> Result<usize, Vec<u8>> {
-    require!(
-        n_nodes <= node_stack_len,
-        "Invalid value of n_nodes in tree structure."
-    );
-
-    // End-offset of the right-most child = end of the parent.
-    let new_node_end =
-        node_stack[(node_stack_len - 1) * NODE_STACK_ITEM_SIZE + NODE_STACK_OFFSET_END];
-
-    let new_stack_len = node_stack_len - n_nodes;
-
-    // ---  build byte-buffer to hash  ----------------------------------------------------------
-    let mut buf = Vec::with_capacity(n_nodes * NODE_STACK_ITEM_SIZE * 32);
-    for i in 0..n_nodes {
-        let base = (new_stack_len + i) * NODE_STACK_ITEM_SIZE;
-        buf.extend_from_slice(&node_stack[base + NODE_STACK_OFFSET_HASH].to_be_bytes::<32>());
-        buf.extend_from_slice(&node_stack[base + NODE_STACK_OFFSET_END].to_be_bytes::<32>());
+        Ok((verifier_address, public_memory_pages))
     }
 
-    let new_node_hash = U256::from_be_bytes::<32>(keccak(&buf).into()) + U256::ONE;
-    // -----------------------------------------------------------------------------------------
-
-    // Over-write the first child slot with the parent.
-    let parent_base = new_stack_len * NODE_STACK_ITEM_SIZE;
-    node_stack[parent_base + NODE_STACK_OFFSET_HASH] = new_node_hash;
-    node_stack[parent_base + NODE_STACK_OFFSET_END] = new_node_end;
-
-    Ok(new_stack_len + 1) // new length after pushing parent
-}
-
-impl GpsStatementVerifier {
     fn register_gps_facts(
         &mut self,
         task_metadata: &[U256],
         public_memory_pages: &[U256],
         output_start_address: U256,
     ) -> Result<(), Vec<u8>> {
-        let total_num_pages: usize = public_memory_pages[0]
-            .try_into()
-            .map_err(|_| "Invalid total number of pages.".as_bytes().to_vec())?;
-        let n_tasks: usize = task_metadata[0]
-            .try_into()
-            .map_err(|_| "Invalid number of tasks.".as_bytes().to_vec())?;
-
-        // node_stack capacity bounded by total_num_pages * NODE_STACK_ITEM_SIZE.
-        let mut node_stack: Vec<U256> = vec![U256::ZERO; total_num_pages * NODE_STACK_ITEM_SIZE];
-        let mut cur_addr = output_start_address + U256::from(6);
-        let mut cur_page = FIRST_CONTINUOUS_PAGE_INDEX;
-        let mut task_metadata_offset = METADATA_TASKS_OFFSET;
-        let mut page_info_index: usize = PAGE_INFO_SIZE;
-        for task in 0..n_tasks {
-            let mut cur_offset = U256::ZERO;
-            let first_page_of_task = cur_page;
-
-            let n_tree_pairs: usize = task_metadata
-                [task_metadata_offset + METADATA_OFFSET_TASK_N_TREE_PAIRS]
-                .try_into()
-                .map_err(|_| "Invalid number of tree pairs.".as_bytes().to_vec())?;
-            let mut node_stack_len: usize = 0;
-
-            for tree_pair in 0..n_tree_pairs {
-                let n_pages: usize = task_metadata[task_metadata_offset
-                    + METADATA_TASK_HEADER_SIZE
-                    + 2 * tree_pair
-                    + METADATA_OFFSET_TREE_PAIR_N_PAGES]
-                    .try_into()
-                    .map_err(|_| "Invalid number of pages.".as_bytes().to_vec())?;
-
-                require!(
-                    n_pages < 1usize << 20,
-                    "Invalid value of n_pages in tree structure."
-                );
-                for page in 0..n_pages {
-                    // Push page to node stack
-                    {
-                        let page_addr = public_memory_pages[page_info_index];
-
-                        let page_size =
-                            public_memory_pages[page_info_index + PAGE_INFO_SIZE_OFFSET];
-                        let page_hash =
-                            public_memory_pages[page_info_index + PAGE_INFO_HASH_OFFSET];
-
-                        require!(page_size < MAX_PAGE_SIZE, "Invalid page size.");
-                        require!(page_addr == cur_addr, "Invalid page address.");
-
-                        let base = NODE_STACK_ITEM_SIZE * node_stack_len;
-                        node_stack[base + NODE_STACK_OFFSET_END] = page_size + cur_offset;
-                        node_stack[base + NODE_STACK_OFFSET_HASH] = page_hash;
-
-                        // TODO: handle pageHashesLogData
-                        cur_page += 1;
-                        node_stack_len += 1;
-                        cur_addr += page_size;
-                        cur_offset += page_size;
-                        page_info_index += PAGE_INFO_SIZE;
-                    }
-                }
-
-                let n_nodes: usize = task_metadata[task_metadata_offset
-                    + METADATA_TASK_HEADER_SIZE
-                    + 2 * tree_pair
-                    + METADATA_OFFSET_TREE_PAIR_N_NODES]
-                    .try_into()
-                    .map_err(|_| "Invalid number of nodes.".as_bytes().to_vec())?;
-                if n_nodes != 0 {
-                    node_stack_len = construct_node(&mut node_stack, node_stack_len, n_nodes)?;
-                }
-            }
-
-            require!(
-                node_stack_len == 1,
-                "Node stack must contain exactly one item."
-            );
-            let program_hash =
-                task_metadata[task_metadata_offset + METADATA_OFFSET_TASK_PROGRAM_HASH];
-
-            require!(
-                node_stack[NODE_STACK_OFFSET_END] + U256::from(2)
-                    == task_metadata[task_metadata_offset + METADATA_OFFSET_TASK_OUTPUT_SIZE],
-                "The sum of the page sizes does not match output size."
-            );
-
-            let program_output_fact = node_stack[NODE_STACK_OFFSET_HASH];
-            // let encoded_data = ;
-            let fact = keccak(
-                &[
-                    program_hash.to_be_bytes::<32>(),
-                    program_output_fact.to_be_bytes::<32>(),
-                ]
-                .concat(),
+        let facts =
+            gps::compute_gps_facts(task_metadata, public_memory_pages, output_start_address)?;
+        for gps_fact in facts {
+            log(
+                self.vm(),
+                LogMemoryPagesHashes {
+                    programOutputFact: gps_fact.program_output_fact,
+                    pagesHashes: gps_fact.pages_hashes,
+                },
             );
 
-            // Update taskMetadataOffset.
-            task_metadata_offset += METADATA_TASK_HEADER_SIZE + 2 * n_tree_pairs;
-
-            self.register_fact(fact.as_slice());
-
-            // Move curAddr to the output of the next task (skipping the size and hash fields).
-            cur_addr += U256::from(2);
+            self.fact_registry.register_fact(gps_fact.fact);
         }
         Ok(())
     }
 
-    fn fact_check(&self, fact: FixedBytes<32>) -> bool {
-        self.verified_facts.get(fact)
-    }
-
-    fn register_fact(&mut self, fact: &[u8]) {
-        self.verified_facts
-            .insert(FixedBytes::from_slice(fact), true);
-        if !self.any_fact_registered.get() {
-            self.any_fact_registered.set(true);
-        }
-    }
-
     fn register_public_memory_main_page(
         &mut self,
         task_metadata: &[U256],
         aux_input: &[U256],
         selected_builtins: &mut U256,
     ) -> Result<(U256, U256, U256), Vec<u8>> {
-        let n_tasks: usize = task_metadata[0].try_into().unwrap();
-        require!(n_tasks < 2usize.pow(30), "Invalid number of tasks.");
-
-        // let bootloader_program_size = Self::BOOTLOADER_PROGRAM.len();
-        let public_memory_length = Self::BOOTLOADER_PROGRAM.len()
-            + 2
-            + N_MAIN_ARGS
-            + N_MAIN_RETURN_VALUES
-            + 3
-            + 1
-            + 2 * n_tasks;
-
-        let mut public_memory: Vec<U256> = vec![U256::ZERO; 2 * public_memory_length];
-        let mut offset = 0;
-
-        for i in 0..Self::BOOTLOADER_PROGRAM.len() {
-            public_memory[offset] = U256::from(i + public_input_offsets::INITIAL_PC);
-            public_memory[offset + 1] = Self::BOOTLOADER_PROGRAM[i];
-            offset += 2;
-        }
-
-        {
-            let initial_fp = aux_input[public_input_offsets::OFFSET_EXECUTION_BEGIN_ADDR];
-            require!(
-                initial_fp.gt(&U256::from(2)),
-                "Invalid execution begin address."
-            );
-
-            public_memory[offset + 0] = initial_fp - U256::from(2);
-            public_memory[offset + 1] = initial_fp;
-            // Make sure [initial_fp - 1] = 0.
-            public_memory[offset + 2] = initial_fp - U256::ONE;
-            public_memory[offset + 3] = U256::ZERO;
-            offset += 4;
-
-            let return_values_address =
-                aux_input[public_input_offsets::OFFSET_EXECUTION_STOP_PTR] - U256::from(N_BUILTINS);
-            let mut builtin_segment_info_offset = public_input_offsets::OFFSET_OUTPUT_BEGIN_ADDR;
-
-            for i in 0..N_BUILTINS {
-                // Write argument address.
-                public_memory[offset] = initial_fp + U256::from(i);
-                let return_value_offset = offset + 2 * N_BUILTINS;
-
-                // Write return value address.
-                public_memory[return_value_offset] = return_values_address + U256::from(i);
-
-                // Write values.
-                if selected_builtins.bit(0) {
-                    // Set the argument to the builtin start pointer.
-                    public_memory[offset + 1] = aux_input[builtin_segment_info_offset];
-                    // Set the return value to the builtin stop pointer.
-                    public_memory[return_value_offset + 1] =
-                        aux_input[builtin_segment_info_offset + 1];
-                    builtin_segment_info_offset += 2;
-                } else {
-                    // Builtin is not present in layout, set the argument value and return value to 0.
-                    public_memory[offset + 1] = U256::ZERO;
-                    public_memory[return_value_offset + 1] = U256::ZERO;
-                }
-                offset += 2;
-                *selected_builtins = *selected_builtins >> 1;
-            }
-            require!(
-                *selected_builtins == U256::ZERO,
-                "SELECTED_BUILTINS_VECTOR_IS_TOO_LONG"
-            );
-
-            // Skip the return values which were already written.
-            offset += 2 * N_BUILTINS;
-        }
-
-        // Program output.
-        {
-            let mut output_address = aux_input[public_input_offsets::OFFSET_OUTPUT_BEGIN_ADDR];
-            // Force that memory[outputAddress] and memory[outputAddress + 1] contain the
-            // bootloader config (which is 2 words size).
-            public_memory[offset + 0] = output_address;
-
-            public_memory[offset + 1] = SIMPLE_BOOTLOADER_PROGRAM_HASH;
-            public_memory[offset + 2] = output_address + U256::ONE;
-            public_memory[offset + 3] = APPLICATION_BOOTLOADER_PROGRAM_HASH;
-            // Force that memory[outputAddress + 3] = nTasks.
-            public_memory[offset + 4] = output_address + U256::from(2);
-            public_memory[offset + 5] = HASHED_SUPPORTED_VERIFIERS;
-            public_memory[offset + 6] = output_address + U256::from(3);
-            public_memory[offset + 7] = U256::from(n_tasks);
-
-            offset += 8;
-            output_address += U256::from(4);
-
-            let mut task_metadata_slice = &task_metadata[METADATA_TASKS_OFFSET..];
-            for _task in 0..n_tasks {
-                let output_size = task_metadata_slice[METADATA_OFFSET_TASK_OUTPUT_SIZE];
-                require!(
-                    U256::from(2) <= output_size && output_size < U256::from(1u64 << 30),
-                    "Invalid task output size."
-                );
-                let program_hash = task_metadata_slice[METADATA_OFFSET_TASK_PROGRAM_HASH];
-                let n_tree_pairs: usize = task_metadata_slice[METADATA_OFFSET_TASK_N_TREE_PAIRS]
-                    .try_into()
-                    .unwrap();
-                require!(
-                    1 <= n_tree_pairs && n_tree_pairs < 2usize.pow(20),
-                    "Invalid number of pairs in the Merkle tree structure."
-                );
-                // Force that memory[outputAddress] = outputSize.
-                public_memory[offset + 0] = output_address;
-                public_memory[offset + 1] = output_size;
-                // Force that memory[outputAddress + 1] = programHash.
-                public_memory[offset + 2] = output_address + U256::from(1);
-                public_memory[offset + 3] = program_hash;
-                offset += 4;
-                output_address += output_size;
-                let start_index = METADATA_TASK_HEADER_SIZE + 2 * n_tree_pairs;
-                task_metadata_slice = &task_metadata_slice[start_index..];
-            }
-            require!(
-                task_metadata_slice.len() == 0,
-                "Invalid length of taskMetadata."
-            );
-
-            require!(
-                aux_input[public_input_offsets::OFFSET_OUTPUT_STOP_PTR] == output_address,
-                "Inconsistent program output length."
-            );
-        }
-
-        require!(
-            public_memory.len() == offset,
-            "Not all Cairo public inputs were written."
-        );
+        let public_memory =
+            gps::build_public_memory_main_page(task_metadata, aux_input, selected_builtins)?;
+        let public_memory_length = public_memory.len() / 2;
 
         let z = aux_input[aux_input.len() - 2];
         let alpha = aux_input[aux_input.len() - 1];
 
-        let (_fact, memory_hash_bytes, product) =
-            IMemoryPageFactRegistry::new(self.memory_page_fact_registry.get())
-                .register_regular_memory_page(&mut *self, public_memory, z, alpha, K_MODULUS)?;
-
-        let memory_hash = U256::from_be_bytes::<32>(memory_hash_bytes.into());
+        let memory_page_fact_registry = self.dependencies.check(self.memory_page_fact_registry.get())?;
+        let register_page = IMemoryPageFactRegistry::registerRegularMemoryPageCall {
+            memory_pairs: public_memory,
+            z,
+            alpha,
+            prime: K_MODULUS,
+        };
+        let registered = self.call_contract(memory_page_fact_registry, &register_page)?;
+        let (memory_hash, product) = (U256::from_be_bytes::<32>(registered._1.into()), registered._2);
         // console!("memory_hash: {}", memory_hash);
         // console!("product: {}", product);
 
         Ok((U256::from(public_memory_length), memory_hash, product))
     }
-}
-
-const N_BUILTINS: usize = 11;
-const N_MAIN_ARGS: usize = N_BUILTINS;
-const N_MAIN_RETURN_VALUES: usize = N_BUILTINS;
-
-const METADATA_TASKS_OFFSET: usize = 1;
-const METADATA_OFFSET_TASK_OUTPUT_SIZE: usize = 0;
-const METADATA_OFFSET_TASK_PROGRAM_HASH: usize = 1;
-const METADATA_OFFSET_TASK_N_TREE_PAIRS: usize = 2;
-const METADATA_TASK_HEADER_SIZE: usize = 3;
-
-const METADATA_OFFSET_TREE_PAIR_N_PAGES: usize = 0;
-const METADATA_OFFSET_TREE_PAIR_N_NODES: usize = 1;
 
-/// Node‑stack bookkeeping.
-const NODE_STACK_OFFSET_HASH: usize = 0;
-const NODE_STACK_OFFSET_END: usize = 1;
-// The size of each node in the node stack.
-const NODE_STACK_ITEM_SIZE: usize = 2;
-
-const FIRST_CONTINUOUS_PAGE_INDEX: usize = 1;
+    fn call_contract<C: SolCall>(&mut self, to: Address, call: &C) -> Result<C::Return, Vec<u8>> {
+        let returned = self.vm().call(&Call::new(), to, &call.abi_encode())?;
+        Ok(C::abi_decode_returns(&returned, true).map_err(calls::errors::Error::from)?)
+    }
 
-const HASHED_SUPPORTED_VERIFIERS: U256 =
-    uint!(988080400528720010398639244351885480706475299330001427790099377094461351470_U256);
-const K_MODULUS: U256 =
-    uint!(0x800000000000011000000000000000000000000000000000000000000000001_U256);
+    fn static_call_contract<C: SolCall>(&self, to: Address, call: &C) -> Result<C::Return, Vec<u8>> {
+        let returned = self.vm().static_call(&Call::new(), to, &call.abi_encode())?;
+        Ok(C::abi_decode_returns(&returned, true).map_err(calls::errors::Error::from)?)
+    }
+}
 
 #[cfg(test)]
 mod test {
     use core::assert_ne;
 
     use super::*;
-    use stylus_sdk::testing::*;
+    use stark_verifier_core::compact::StarkInput;
+    use stark_verifier_core::gps::{
+        consts::page_info::*, METADATA_OFFSET_TASK_N_TREE_PAIRS,
+        METADATA_OFFSET_TASK_PROGRAM_HASH, METADATA_TASKS_OFFSET, METADATA_TASK_HEADER_SIZE,
+    };
+    use stylus_sdk::{
+        alloy_primitives::{uint, FixedBytes},
+        crypto::keccak,
+        testing::*,
+    };
     #[motsu::test]
     fn test_register_gps_facts() {
         let vm = TestVM::default();
         let mut gpsVerifier: GpsStatementVerifier = GpsStatementVerifier::from(&vm);
+        assert!(!gpsVerifier.fact_registry.has_registered_fact());
         if let Err(e) = gpsVerifier.register_gps_facts(
             &TASK_META_DATA,
             &PUBLIC_MEMORY_PAGES,
             OUTPUT_START_ADDRESS,
         ) {
-            let str_err = String::from_utf8(e).unwrap();
-            panic!("Error: {:?}", str_err);
+            panic!("Error: {:?}", StarkError::decode(&e));
         }
+        assert!(gpsVerifier.fact_registry.has_registered_fact());
+    }
+
+    #[motsu::test]
+    fn test_register_gps_facts_logs_pages_hashes() {
+        use alloy_sol_types::SolEvent;
+
+        let vm = TestVM::default();
+        let mut gps_verifier = GpsStatementVerifier::from(&vm);
+        if let Err(e) = gps_verifier.register_gps_facts(
+            &TASK_META_DATA,
+            &PUBLIC_MEMORY_PAGES,
+            OUTPUT_START_ADDRESS,
+        ) {
+            panic!("Error: {:?}", StarkError::decode(&e));
+        }
+
+        let logs = vm.get_emitted_logs();
+        let n_tasks: usize = TASK_META_DATA[0].to();
+        assert_eq!(logs.len(), n_tasks);
+
+        // Every page but the main page belongs to exactly one task, in order.
+        let mut logged_hashes = Vec::new();
+        let mut task_metadata_offset = METADATA_TASKS_OFFSET;
+        for (topics, data) in logs {
+            assert_eq!(topics, vec![LogMemoryPagesHashes::SIGNATURE_HASH]);
+            let (program_output_fact, pages_hashes) =
+                LogMemoryPagesHashes::abi_decode_data(&data, true).unwrap();
+            assert!(!pages_hashes.is_empty());
+            logged_hashes.extend(pages_hashes);
+
+            // The logged output fact is the one the registered fact was computed from.
+            let program_hash =
+                TASK_META_DATA[task_metadata_offset + METADATA_OFFSET_TASK_PROGRAM_HASH];
+            let fact = keccak([program_hash.to_be_bytes::<32>(), program_output_fact.0].concat());
+            assert!(gps_verifier.fact_registry.is_valid(fact));
+
+            let n_tree_pairs: usize =
+                TASK_META_DATA[task_metadata_offset + METADATA_OFFSET_TASK_N_TREE_PAIRS].to();
+            task_metadata_offset += METADATA_TASK_HEADER_SIZE + 2 * n_tree_pairs;
+        }
+        let n_pages: usize = PUBLIC_MEMORY_PAGES[0].to();
+        let expected_hashes: Vec<FixedBytes<32>> = (1..n_pages)
+            .map(|page| {
+                FixedBytes(
+                    PUBLIC_MEMORY_PAGES[page * PAGE_INFO_SIZE + PAGE_INFO_HASH_OFFSET]
+                        .to_be_bytes(),
+                )
+            })
+            .collect();
+        assert_eq!(logged_hashes, expected_hashes);
+    }
+
+    const VERIFIER_TIMELOCK: u64 = 7 * 24 * 60 * 60;
+
+    // The deployment, with the sender as the owner.
+    fn deploy(vm: &TestVM) -> GpsStatementVerifier {
+        let mut gps_verifier = GpsStatementVerifier::from(vm);
+        gps_verifier.constructor(vm.msg_sender()).unwrap();
+        gps_verifier
+    }
+
+    #[motsu::test]
+    fn test_init_before_owner() {
+        let vm = TestVM::default();
+        let mut gps_verifier = deploy(&vm);
+        let owner = vm.msg_sender();
+
+        // E.g. a front-runner registering their own always-true verifier.
+        let attacker = Address::repeat_byte(0x11);
+        vm.set_sender(attacker);
+        let err = gps_verifier.init(attacker, vec![attacker], 0).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::OnlyOwner(OnlyOwner { caller: attacker })));
+        assert!(gps_verifier.get_verifiers().0.is_empty());
+
+        vm.set_sender(owner);
+        gps_verifier.init(Address::repeat_byte(1), vec![Address::repeat_byte(2)], 0).unwrap();
+        assert_eq!(gps_verifier.get_verifiers().0, vec![Address::repeat_byte(2)]);
+    }
+
+    #[motsu::test]
+    fn test_init_logs_wiring() {
+        use alloy_sol_types::SolEvent;
+        use utils::{dependencies::DependencySet, ownable::OwnershipTransferred};
+
+        let vm = TestVM::default();
+        vm.set_block_timestamp(1_000);
+        let mut gps_verifier = deploy(&vm);
+        let verifiers = vec![Address::repeat_byte(2), Address::repeat_byte(3)];
+        gps_verifier.init(Address::repeat_byte(1), verifiers.clone(), VERIFIER_TIMELOCK).unwrap();
+        let err = gps_verifier.init(Address::repeat_byte(1), verifiers, 0).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::AlreadyInitialized(AlreadyInitialized {})));
+
+        let logs = vm.get_emitted_logs();
+        assert_eq!(logs.len(), 4);
+        assert_eq!(logs[0].0[0], OwnershipTransferred::SIGNATURE_HASH);
+        assert_eq!(logs[0].0[2], gps_verifier.ownable.owner().into_word());
+        assert_eq!(
+            logs[1].0,
+            vec![DependencySet::SIGNATURE_HASH, Address::ZERO.into_word(), Address::repeat_byte(1).into_word()]
+        );
+        assert_eq!(DependencySet::abi_decode_data(&logs[1].1, true).unwrap().0, "memoryPageFactRegistry");
+        // The verifiers of init are active right away.
+        for (id, (topics, data)) in logs[2..].iter().enumerate() {
+            assert_eq!(topics, &vec![VerifierAdded::SIGNATURE_HASH, U256::from(id).into()]);
+            let (verifier, activation_time) = VerifierAdded::abi_decode_data(data, true).unwrap();
+            assert_eq!(verifier, Address::repeat_byte(id as u8 + 2));
+            assert_eq!(activation_time, U256::from(1_000));
+        }
+        assert_eq!(gps_verifier.get_verifier_timelock(), VERIFIER_TIMELOCK);
+    }
+
+    #[motsu::test]
+    fn test_verifier_registry() {
+        let vm = TestVM::default();
+        vm.set_block_timestamp(1_000);
+        let mut gps_verifier = deploy(&vm);
+        gps_verifier
+            .init(Address::repeat_byte(1), vec![Address::repeat_byte(2)], VERIFIER_TIMELOCK)
+            .unwrap();
+        let owner = gps_verifier.ownable.owner();
+
+        let attacker = Address::repeat_byte(0x11);
+        vm.set_sender(attacker);
+        let err = gps_verifier.add_verifier(attacker).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::OnlyOwner(OnlyOwner { caller: attacker })));
+        let err = gps_verifier.disable_verifier(U256::ZERO).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::OnlyOwner(OnlyOwner { caller: attacker })));
+
+        vm.set_sender(owner);
+        let id = gps_verifier.add_verifier(Address::repeat_byte(3)).unwrap();
+        assert_eq!(id, U256::from(1));
+        let activation_time = 1_000 + VERIFIER_TIMELOCK;
+        assert_eq!(
+            gps_verifier.get_verifiers(),
+            (vec![Address::repeat_byte(2), Address::repeat_byte(3)], vec![1_000, activation_time], vec![false, false])
+        );
+
+        // The new verifier is rejected until the timelock has passed.
+        vm.set_block_timestamp(activation_time - 1);
+        let err = gps_verifier.register_main_page(&TASK_META_DATA, &AUX_INPUT, id).err().unwrap();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::CairoVerifierNotActive(CairoVerifierNotActive {
+                cairoVerifierId: id,
+                activationTime: U256::from(activation_time),
+            }))
+        );
+
+        gps_verifier.disable_verifier(U256::ZERO).unwrap();
+        let err = gps_verifier.register_main_page(&TASK_META_DATA, &AUX_INPUT, U256::ZERO).err().unwrap();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::CairoVerifierDisabled(CairoVerifierDisabled { cairoVerifierId: U256::ZERO }))
+        );
+        let err = gps_verifier.disable_verifier(U256::ZERO).unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::CairoVerifierDisabled(CairoVerifierDisabled { cairoVerifierId: U256::ZERO }))
+        );
+        let err = gps_verifier.disable_verifier(U256::from(2)).unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::UnknownCairoVerifier(UnknownCairoVerifier { cairoVerifierId: U256::from(2) }))
+        );
+        assert_eq!(gps_verifier.get_verifiers().2, vec![true, false]);
+    }
+
+    #[motsu::test]
+    fn test_verify_proof_and_register_compact_rejects_bad_input() {
+        let vm = TestVM::default();
+        let mut gps_verifier = deploy(&vm);
+        gps_verifier
+            .init(Address::repeat_byte(1), vec![Address::repeat_byte(2)], 0)
+            .unwrap();
+
+        let err = gps_verifier
+            .verify_proof_and_register_compact(vec![2, 0].into())
+            .unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::InvalidCompactInput(InvalidCompactInput { offset: U256::ZERO }))
+        );
+
+        let (cairo_public_input, interaction_elements) = AUX_INPUT.split_at(AUX_INPUT.len() - 2);
+        let stark_input = StarkInput { proof_params: vec![], proof: &[], public_input: cairo_public_input.to_vec() };
+        let input = GpsInput::encode(&TASK_META_DATA, 1, &stark_input, interaction_elements);
+        let err = gps_verifier
+            .verify_proof_and_register_compact(input.into())
+            .unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::UnknownCairoVerifier(UnknownCairoVerifier {
+                cairoVerifierId: U256::from(1)
+            }))
+        );
+    }
+
+    // The offset of the public memory pages in the public input of the test vectors, and the
+    // builtins of their layout.
+    const PUBLIC_MEMORY_OFFSET: usize = 21;
+    const SELECTED_BUILTINS: U256 = uint!(151_U256);
+
+    // Mocks the CPU verifier and the memory page fact registry for verify_proof_and_register with
+    // the given aux input. The registry returns the hash and product of the actual main page.
+    fn mock_dependencies(vm: &TestVM, mpfr: Address, verifier: Address, aux_input: &[U256]) -> (U256, U256) {
+        use alloy_sol_types::SolValue;
+        use ICairoVerifierContract::{getLayoutInfoCall, verifyProofExternalCall};
+        use IMemoryPageFactRegistry::registerRegularMemoryPageCall;
+
+        vm.mock_static_call(
+            verifier,
+            getLayoutInfoCall {}.abi_encode(),
+            Ok((U256::from(PUBLIC_MEMORY_OFFSET), SELECTED_BUILTINS).abi_encode_params()),
+        );
+
+        let memory_pairs =
+            gps::build_public_memory_main_page(&TASK_META_DATA, aux_input, &mut SELECTED_BUILTINS.clone()).unwrap();
+        let (z, alpha) = (aux_input[aux_input.len() - 2], aux_input[aux_input.len() - 1]);
+        let (fact, memory_hash, product) = gps::compute_regular_memory_page_fact(&memory_pairs, z, alpha, K_MODULUS);
+        let call = registerRegularMemoryPageCall { memory_pairs, z, alpha, prime: K_MODULUS };
+        vm.mock_call(
+            mpfr,
+            call.abi_encode(),
+            Ok((fact, FixedBytes::<32>::from(memory_hash), product).abi_encode_params()),
+        );
+
+        let call = verifyProofExternalCall {
+            proof_params: vec![],
+            proof: vec![],
+            public_input: aux_input[..aux_input.len() - 2].to_vec(),
+        };
+        vm.mock_call(verifier, call.abi_encode(), Ok((Vec::<U256>::new(),).abi_encode_params()));
+        (memory_hash, product)
+    }
+
+    #[motsu::test]
+    fn test_verify_proof_and_register_rejects_mismatched_main_page() {
+        use alloy_sol_types::SolEvent;
+
+        let vm = TestVM::default();
+        let mut gps_verifier = deploy(&vm);
+        let (mpfr, verifier) = (Address::repeat_byte(1), Address::repeat_byte(2));
+        gps_verifier.init(mpfr, vec![verifier], 0).unwrap();
+
+        // The public input claims another hash for the main page than the registered one.
+        let hash_index = PUBLIC_MEMORY_OFFSET + PAGE_INFO_HASH_OFFSET;
+        let mut aux_input = AUX_INPUT.to_vec();
+        aux_input[hash_index] += U256::ONE;
+        let (memory_hash, _) = mock_dependencies(&vm, mpfr, verifier, &aux_input);
+        let err = gps_verifier
+            .verify_proof_and_register(vec![], vec![], TASK_META_DATA.to_vec(), aux_input.clone(), U256::ZERO)
+            .unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::InvalidMainPageHash(InvalidMainPageHash {
+                expected: memory_hash,
+                actual: aux_input[hash_index],
+            }))
+        );
+
+        // Same for the product of the main page, the first word after the page infos.
+        let product_index = PUBLIC_MEMORY_OFFSET + AUX_INPUT[PUBLIC_MEMORY_OFFSET].to::<usize>() * PAGE_INFO_SIZE;
+        let mut aux_input = AUX_INPUT.to_vec();
+        aux_input[product_index] += U256::ONE;
+        let (_, product) = mock_dependencies(&vm, mpfr, verifier, &aux_input);
+        let err = gps_verifier
+            .verify_proof_and_register(vec![], vec![], TASK_META_DATA.to_vec(), aux_input.clone(), U256::ZERO)
+            .unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::InvalidMainPageProduct(InvalidMainPageProduct {
+                expected: product,
+                actual: aux_input[product_index],
+            }))
+        );
+
+        // No fact is registered.
+        assert!(vm.get_emitted_logs().iter().all(|(topics, _)| topics[0] != LogMemoryPagesHashes::SIGNATURE_HASH));
+
+        // The untouched input gets through the main page check to the proof verification.
+        mock_dependencies(&vm, mpfr, verifier, &AUX_INPUT);
+        gps_verifier
+            .verify_proof_and_register(vec![], vec![], TASK_META_DATA.to_vec(), AUX_INPUT.to_vec(), U256::ZERO)
+            .unwrap();
+        assert!(vm.get_emitted_logs().iter().any(|(topics, _)| topics[0] == LogMemoryPagesHashes::SIGNATURE_HASH));
     }
 
     #[motsu::test]
     fn test_register_public_memory_main_page() {
         let vm = TestVM::default();
-        let mut gpsVerifier: GpsStatementVerifier = GpsStatementVerifier::from(&vm);
-        if let Err(e) = gpsVerifier.register_public_memory_main_page(
+        let mut gps_verifier: GpsStatementVerifier = GpsStatementVerifier::from(&vm);
+        let (memory_hash, product) = mock_dependencies(&vm, Address::ZERO, Address::repeat_byte(2), &AUX_INPUT);
+        match gps_verifier.register_public_memory_main_page(
             &TASK_META_DATA,
             &AUX_INPUT,
             &mut uint!(151_U256),
         ) {
-            let str_err = String::from_utf8(e).unwrap();
-            panic!("Error: {:?}", str_err);
+            Ok((_, hash, prod)) => assert_eq!((hash, prod), (memory_hash, product)),
+            Err(e) => panic!("Error: {:?}", StarkError::decode(&e)),
         }
     }
 
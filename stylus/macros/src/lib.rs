@@ -1,21 +1,159 @@
-extern crate alloc;
-use alloy_sol_types::sol;
-use stylus_sdk:: prelude::*;
+//! Generates the evaluator of a periodic column from its Solidity contract.
+//!
+//! The Solidity column contracts generated by StarkWare evaluate the column polynomial with
+//! Horner's method in blocks of up to 7 coefficients:
+//!
+//! ```text
+//! result := add(a_k, mulmod(add(a_k+1, ... mulmod(result, x, PRIME) ...), x, PRIME))
+//! ```
+//!
+//! Within a block the coefficients are listed from the lowest degree to the highest, and the
+//! blocks go from the highest degrees down to the constant term.
+use std::{env, fs, path::PathBuf};
 
-sol! {
-    error GenericError();
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, LitStr};
+
+// Expands to the coefficients of the column, `PRIME` and `compute(x)`, e.g.
+// `macros::periodic_column!("../../evm-verifier/.../PoseidonPoseidonFullRoundKey0Column.sol");`.
+// The path is relative to the manifest of the crate that uses the macro.
+//
+// COEFFS is in one canonical order, from the highest degree down to the constant term, and
+// compute evaluates it with Horner's method like the Solidity contract.
+#[proc_macro]
+pub fn periodic_column(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let full_path = PathBuf::from(manifest_dir).join(path.value());
+    let source = match fs::read_to_string(&full_path) {
+        Ok(source) => source,
+        Err(err) => return error(path.span(), format!("cannot read {}: {err}", full_path.display())),
+    };
+    let column = match parse_column(&source) {
+        Ok(column) => column,
+        Err(err) => return error(path.span(), format!("{}: {err}", full_path.display())),
+    };
+
+    let n_coeffs = column.coeffs.len();
+    let prime = u256_tokens(&column.prime);
+    let coeffs = column.coeffs.iter().map(|coeff| u256_tokens(coeff));
+    let full_path = full_path.to_string_lossy().into_owned();
+    quote! {
+        // Rebuilds the evaluator when the Solidity contract changes.
+        const _: &[u8] = include_bytes!(#full_path);
+
+        pub const PRIME: ::stylus_sdk::alloy_primitives::U256 = #prime;
+
+        // From the highest degree down to the constant term.
+        pub const COEFFS: [::stylus_sdk::alloy_primitives::U256; #n_coeffs] = [#(#coeffs),*];
+
+        pub fn compute(x: ::stylus_sdk::alloy_primitives::U256) -> ::stylus_sdk::alloy_primitives::U256 {
+            COEFFS
+                .iter()
+                .fold(::stylus_sdk::alloy_primitives::U256::ZERO, |acc, &coeff| {
+                    acc.mul_mod(x, PRIME).wrapping_add(coeff)
+                })
+                % PRIME
+        }
+    }
+    .into()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Column {
+    // The hex digits of the prime and of the coefficients, without the 0x prefix.
+    prime: String,
+    coeffs: Vec<String>,
+}
+
+fn parse_column(source: &str) -> Result<Column, String> {
+    let prime = source
+        .split_once("PRIME = ")
+        .and_then(|(_, rest)| hex_literals(rest.split(';').next().unwrap_or_default()).into_iter().next())
+        .ok_or("no PRIME constant")?;
+
+    let mut blocks = source.split("result := add(");
+    blocks.next();
+    let mut coeffs = Vec::new();
+    for block in blocks {
+        // Only the coefficients are hex literals, from the lowest degree of the block up.
+        coeffs.extend(hex_literals(block).into_iter().rev());
+    }
+    if coeffs.is_empty() {
+        return Err("no `result := add(` block".to_string());
+    }
+    if let Some(coeff) = coeffs.iter().chain([&prime]).find(|coeff| coeff.len() > 64) {
+        return Err(format!("0x{coeff} does not fit in 256 bits"));
+    }
+    Ok(Column { prime, coeffs })
+}
+
+fn hex_literals(source: &str) -> Vec<String> {
+    source
+        .split("0x")
+        .skip(1)
+        .map(|rest| rest.chars().take_while(char::is_ascii_hexdigit).collect::<String>())
+        .filter(|digits| !digits.is_empty())
+        .collect()
 }
 
-#[derive(SolidityError)]
-pub enum StarkError {
-    GenericError(GenericError),
+// A const U256 expression for the given hex digits.
+fn u256_tokens(digits: &str) -> proc_macro2::TokenStream {
+    let padded = format!("{digits:0>64}");
+    let limbs = (0..4).rev().map(|i| u64::from_str_radix(&padded[16 * i..16 * (i + 1)], 16).unwrap());
+    quote!(::stylus_sdk::alloy_primitives::U256::from_limbs([#(#limbs),*]))
 }
 
-#[macro_export]
-macro_rules! require {
-    ($cond:expr, $msg:expr) => {
-        if !$cond {
-            return Err(format!($msg).as_bytes().to_vec());
+fn error(span: Span, message: String) -> TokenStream {
+    syn::Error::new(span, message).to_compile_error().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "
+        uint256 PRIME = 0x800000000000011000000000000000000000000000000000000000000000001;
+        assembly {
+            result := add(
+                0x3,
+                mulmod(add(0x4, mulmod(add(0x5, mulmod(result, x, PRIME)), x, PRIME)), x, PRIME)
+            )
+
+            result := add(
+                0x1,
+                mulmod(add(0x2, mulmod(result, x, PRIME)), x, PRIME)
+            )
+
+            result := add(
+                0x0,
+                mulmod(result, x, PRIME)
+            )
         }
-    };
+        return result % PRIME;
+    ";
+
+    #[test]
+    fn test_parse_column() {
+        let column = parse_column(SOURCE).unwrap();
+        assert_eq!(column.prime, "800000000000011000000000000000000000000000000000000000000000001");
+        assert_eq!(column.coeffs, ["5", "4", "3", "2", "1", "0"]);
+    }
+
+    #[test]
+    fn test_parse_column_rejects_invalid_source() {
+        assert!(parse_column("contract C {}").is_err());
+        assert!(parse_column("uint256 PRIME = 0x7; return x;").is_err());
+    }
+
+    #[test]
+    fn test_u256_tokens() {
+        let tokens = u256_tokens("800000000000011000000000000000000000000000000000000000000000001");
+        assert_eq!(
+            tokens.to_string().replace(' ', ""),
+            "::stylus_sdk::alloy_primitives::U256::from_limbs([1u64,0u64,0u64,576460752303423505u64])"
+        );
+    }
 }
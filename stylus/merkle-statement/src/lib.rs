@@ -0,0 +1,186 @@
+//!
+//! Merkle Statement Contract
+//! Stylus port of MerkleStatementContract.sol. Verifies Merkle decommitments and registers
+//! the resulting facts so they can be looked up by the FRI statement verifier.
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
+
+#[macro_use]
+extern crate alloc;
+use alloc::vec::Vec;
+use utils::{fact_registry::FactRegistry, merkle_verifier::MerkleVerifier, require, require::*};
+
+use stylus_sdk::{
+    alloy_primitives::{FixedBytes, U256},
+    crypto::keccak,
+    prelude::*,
+};
+
+sol_storage! {
+    #[entrypoint]
+    pub struct MerkleStatementContract {
+        #[borrow]
+        FactRegistry fact_registry;
+    }
+}
+
+#[public]
+#[inherit(FactRegistry)]
+impl MerkleStatementContract {
+    // Receives an initial Merkle queue (indices of leaves in the Merkle tree together with their
+    // values) and a Merkle view (the values of all the nodes required to validate the queue).
+    // On success registers the Merkle fact, which is the hash of the queue together with the
+    // resulting root.
+    #[inline]
+    pub fn verify_merkle(
+        &mut self,
+        merkle_view: Vec<U256>,
+        initial_merkle_queue: Vec<U256>,
+        height: U256,
+        expected_root: U256,
+    ) -> Result<(), Vec<u8>> {
+        // Ensure 'height' is bounded as a sanity check (the bound is somewhat arbitrary).
+        require!(height < U256::from(200), InvalidMerkleHeight { height });
+        require!(
+            initial_merkle_queue.len() <= MerkleVerifier::MAX_N_MERKLE_VERIFIER_QUERIES * 2,
+            TooManyMerkleQueries { nQueries: U256::from(initial_merkle_queue.len() / 2) }
+        );
+        require!(initial_merkle_queue.len() % 2 == 0, InvalidMerkleQueue {});
+
+        let n_queries = initial_merkle_queue.len() / 2;
+        require!(
+            Self::validate_merkle_indices(&initial_merkle_queue, height.to::<usize>()),
+            InvalidMerkleIndices {}
+        );
+
+        // verify_merkle destroys the queue, the initial one is kept for the fact hash.
+        let mut merkle_queue = initial_merkle_queue.clone();
+        let mut proof_ptr = 0;
+        let res_root = MerkleVerifier::verify_merkle(
+            &merkle_view,
+            &mut proof_ptr,
+            &mut merkle_queue,
+            expected_root,
+            n_queries,
+        )?;
+
+        let fact_hash = Self::compute_fact_hash(&initial_merkle_queue, res_root);
+        self.fact_registry.register_fact(fact_hash);
+        Ok(())
+    }
+}
+
+impl MerkleStatementContract {
+    // The indices need to be in the range [2**height..2**(height+1)-1] and strictly incrementing.
+    fn validate_merkle_indices(merkle_queue: &[U256], height: usize) -> bool {
+        let mut idx_lower_limit = U256::from(1) << height;
+        let mut bad_input = false;
+        for pair in merkle_queue.chunks(2) {
+            bad_input |= pair[0] < idx_lower_limit;
+            // The next idx must be at least cur_idx + 1.
+            idx_lower_limit = pair[0].saturating_add(U256::from(1));
+        }
+
+        // Fail if last_idx >= 2**(height+1), i.e. idx_lower_limit > 2**(height+1).
+        bad_input |= idx_lower_limit > U256::from(1) << (height + 1);
+        !bad_input
+    }
+
+    // The fact layout matches what FriStatementVerifier::verify_merkle looks up:
+    // keccak(idx_0, hash_0, ..., idx_n-1, hash_n-1, root).
+    fn compute_fact_hash(merkle_queue: &[U256], root: U256) -> FixedBytes<32> {
+        let mut input_data = Vec::with_capacity((merkle_queue.len() + 1) * 32);
+        for val in merkle_queue {
+            input_data.extend_from_slice(&val.to_be_bytes::<32>());
+        }
+        input_data.extend_from_slice(&root.to_be_bytes::<32>());
+        keccak(&input_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::testing::*;
+
+    // Builds a tree of height 2 over the given leaves and returns all nodes, indexed as in the
+    // Merkle queue (root at 1, leaves at 4..7).
+    fn build_tree(leaves: [U256; 4]) -> Vec<U256> {
+        let mut nodes = vec![U256::ZERO; 8];
+        nodes[4..8].copy_from_slice(&leaves);
+        for i in (1..4).rev() {
+            nodes[i] = MerkleVerifier::hash_node(nodes[2 * i], nodes[2 * i + 1]);
+        }
+        nodes
+    }
+
+    fn leaves() -> [U256; 4] {
+        [
+            U256::from(11) << 96,
+            U256::from(22) << 96,
+            U256::from(33) << 96,
+            U256::from(44) << 96,
+        ]
+    }
+
+    #[motsu::test]
+    fn test_verify_merkle_registers_fact() {
+        let vm = TestVM::default();
+        let mut contract = MerkleStatementContract::from(&vm);
+        let nodes = build_tree(leaves());
+
+        let queue = vec![U256::from(4), nodes[4], U256::from(6), nodes[6]];
+        let merkle_view = vec![nodes[5], nodes[7]];
+        if let Err(e) = contract.verify_merkle(merkle_view, queue.clone(), U256::from(2), nodes[1]) {
+            panic!("Error: {:?}", StarkError::decode(&e));
+        }
+
+        let fact = MerkleStatementContract::compute_fact_hash(&queue, nodes[1]);
+        assert!(contract.fact_registry.is_valid(fact));
+        assert!(contract.fact_registry.has_registered_fact());
+    }
+
+    #[motsu::test]
+    fn test_verify_merkle_takes_siblings_from_queue() {
+        let vm = TestVM::default();
+        let mut contract = MerkleStatementContract::from(&vm);
+        let nodes = build_tree(leaves());
+
+        let queue = vec![
+            U256::from(4), nodes[4],
+            U256::from(5), nodes[5],
+            U256::from(7), nodes[7],
+        ];
+        let merkle_view = vec![nodes[6]];
+        assert!(contract
+            .verify_merkle(merkle_view, queue, U256::from(2), nodes[1])
+            .is_ok());
+    }
+
+    #[motsu::test]
+    fn test_verify_merkle_rejects_wrong_root() {
+        let vm = TestVM::default();
+        let mut contract = MerkleStatementContract::from(&vm);
+        let nodes = build_tree(leaves());
+
+        let queue = vec![U256::from(4), nodes[4]];
+        let merkle_view = vec![nodes[5], nodes[2]];
+        let err = contract
+            .verify_merkle(merkle_view, queue, U256::from(2), nodes[1])
+            .unwrap_err();
+        assert!(matches!(StarkError::decode(&err), Some(StarkError::InvalidMerkleProof(_))));
+        assert!(!contract.fact_registry.has_registered_fact());
+    }
+
+    #[motsu::test]
+    fn test_verify_merkle_rejects_invalid_indices() {
+        let vm = TestVM::default();
+        let mut contract = MerkleStatementContract::from(&vm);
+        let nodes = build_tree(leaves());
+
+        let queue = vec![U256::from(6), nodes[6], U256::from(4), nodes[4]];
+        let err = contract
+            .verify_merkle(vec![], queue, U256::from(2), nodes[1])
+            .unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::InvalidMerkleIndices(InvalidMerkleIndices {})));
+    }
+}
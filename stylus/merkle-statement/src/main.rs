@@ -0,0 +1,10 @@
+#![cfg_attr(not(feature = "export-abi"), no_main)]
+
+#[cfg(not(feature = "export-abi"))]
+#[no_mangle]
+pub extern "C" fn main() {}
+
+#[cfg(feature = "export-abi")]
+fn main() {
+    merkle_statement::print_abi("MIT-OR-APACHE-2.0", "pragma solidity ^0.8.23;");
+}
@@ -10,6 +10,7 @@
 extern crate alloc;
 
 use alloc::vec::Vec;
+use alloy_sol_types::sol;
 /// Import items from the SDK. The prelude contains common traits and macros.
 use stylus_sdk::{
     alloy_primitives::{FixedBytes, U256},
@@ -17,11 +18,38 @@ use stylus_sdk::{
     prelude::*,
 };
 
+// This contract can't use utils::require::StarkError: it is built against stylus-sdk 0.8.4, because
+// its tests deploy it with motsu 0.8, which pins that SDK, while utils is on 0.9, and depending on
+// utils would link a second stylus-sdk into the contract. So it declares the errors it reverts
+// with itself, with the same signatures as in StarkError so they decode the same way. Once motsu
+// supports 0.9 this set should go in favour of StarkError.
+sol! {
+    #[derive(Debug, PartialEq, Eq)]
+    error TooManyMemoryValues(uint256 nValues);
+    #[derive(Debug, PartialEq, Eq)]
+    error InvalidMemoryPairs();
+    #[derive(Debug, PartialEq, Eq)]
+    error InvalidInteractionElement(uint256 value);
+    #[derive(Debug, PartialEq, Eq)]
+    error PrimeTooBig(uint256 prime);
+    #[derive(Debug, PartialEq, Eq)]
+    error InvalidStartAddr(uint256 startAddr);
+}
+
+#[derive(SolidityError, Debug, PartialEq, Eq)]
+pub enum MemoryPageError {
+    TooManyMemoryValues(TooManyMemoryValues),
+    InvalidMemoryPairs(InvalidMemoryPairs),
+    InvalidInteractionElement(InvalidInteractionElement),
+    PrimeTooBig(PrimeTooBig),
+    InvalidStartAddr(InvalidStartAddr),
+}
+
 #[macro_export]
 macro_rules! require {
-    ($cond:expr, $msg:expr) => {
+    ($cond:expr, $err:expr) => {
         if !$cond {
-            return Err(format!($msg).as_bytes().to_vec());
+            return Err(MemoryPageError::from($err).into());
         }
     };
 }
@@ -92,17 +120,46 @@ impl MemoryPageFactRegistry {
     ) -> Result<(FixedBytes<32>, FixedBytes<32>, U256), Vec<u8>> {
         require!(
             memory_pairs.len() < 2usize.pow(20),
-            "Too many memory values."
+            TooManyMemoryValues { nValues: U256::from(memory_pairs.len()) }
+        );
+        require!(memory_pairs.len() % 2 == 0, InvalidMemoryPairs {});
+        require!(z < prime, InvalidInteractionElement { value: z });
+        require!(alpha < prime, InvalidInteractionElement { value: alpha });
+
+        let (fact_hash, memory_hash, prod) =
+            Self::compute_fact_hash(&memory_pairs, z, alpha, prime);
+        self.fact_registry.register_fact(fact_hash);
+
+        Ok((fact_hash, memory_hash, prod))
+    }
+
+    // Registers a page of values stored at consecutive addresses starting at start_addr.
+    // Unlike regular pages, only the values are hashed; start_addr is part of the fact instead.
+    #[inline]
+    pub fn register_continuous_memory_page(
+        &mut self,
+        start_addr: U256,
+        values: Vec<U256>,
+        z: U256,
+        alpha: U256,
+        prime: U256,
+    ) -> Result<(FixedBytes<32>, U256, U256), Vec<u8>> {
+        require!(
+            values.len() < 2usize.pow(20),
+            TooManyMemoryValues { nValues: U256::from(values.len()) }
         );
+        // The prime must be small enough for the optimizations in this function.
+        require!(prime < U256::from(1) << 254, PrimeTooBig { prime });
+        require!(z < prime, InvalidInteractionElement { value: z });
+        require!(alpha < prime, InvalidInteractionElement { value: alpha });
+        // Ensure 'start_addr' less then prime and bounded as a sanity check (the bound is somewhat arbitrary).
         require!(
-            memory_pairs.len() % 2 == 0,
-            "Size of memoryPairs must be even."
+            start_addr < prime && start_addr < U256::from(1) << 64,
+            InvalidStartAddr { startAddr: start_addr }
         );
-        require!(z < prime, "Invalid value of z.");
-        require!(alpha < prime, "Invalid value of alpha.");
 
         let (fact_hash, memory_hash, prod) =
-            Self::compute_fact_hash(&memory_pairs, z, alpha, prime);
+            Self::compute_continuous_fact_hash(start_addr, &values, z, alpha, prime);
         self.fact_registry.register_fact(fact_hash);
 
         Ok((fact_hash, memory_hash, prod))
@@ -144,12 +201,87 @@ impl MemoryPageFactRegistry {
 
         (fact_hash_output, memory_hash_output, prod)
     }
+
+    fn compute_continuous_fact_hash(
+        start_addr: U256,
+        values: &[U256],
+        z: U256,
+        alpha: U256,
+        prime: U256,
+    ) -> (FixedBytes<32>, U256, U256) {
+        let mut prod = U256::from(1);
+        let mut memory_data = Vec::with_capacity(values.len() * 32);
+
+        let mut addr = start_addr;
+        for value in values {
+            let val_alpha = value.mul_mod(alpha, prime);
+            let address_value_lin_comb = addr.add_mod(val_alpha, prime);
+            let term = z + prime - address_value_lin_comb;
+            prod = prod.mul_mod(term, prime);
+            addr += U256::from(1);
+
+            memory_data.extend_from_slice(&value.to_be_bytes::<32>());
+        }
+
+        let memory_hash = U256::from_be_bytes::<32>(keccak(&memory_data).into());
+
+        let mut hash_buffer = Vec::with_capacity(256);
+        hash_buffer.extend_from_slice(&Self::CONTINUOUS_PAGE);
+        hash_buffer.extend_from_slice(&prime.to_be_bytes::<32>());
+        hash_buffer.extend_from_slice(&U256::from(values.len()).to_be_bytes::<32>());
+        hash_buffer.extend_from_slice(&z.to_be_bytes::<32>());
+        hash_buffer.extend_from_slice(&alpha.to_be_bytes::<32>());
+        hash_buffer.extend_from_slice(&prod.to_be_bytes::<32>());
+        hash_buffer.extend_from_slice(&memory_hash.to_be_bytes::<32>());
+        hash_buffer.extend_from_slice(&start_addr.to_be_bytes::<32>());
+        let fact_hash_output = keccak(&hash_buffer);
+
+        (fact_hash_output, memory_hash, prod)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{B256, U256};
+    use alloy_primitives::{Address, B256, U256};
+    use motsu::prelude::*;
+
+    // The arguments of a registerContinuousMemoryPage transaction:
+    // (startAddr, values, z, alpha, prime).
+    fn continuous_page_input() -> (U256, Vec<U256>, U256, U256, U256) {
+        let calldata = hex::decode(
+            include_str!("../../../inputs/mpfr_cont.txt")
+                .trim()
+                .trim_start_matches("0x"),
+        )
+        .unwrap();
+        let words: Vec<U256> = calldata[4..]
+            .chunks_exact(32)
+            .map(U256::from_be_slice)
+            .collect();
+        let n_values: usize = words[5].to();
+        (words[0], words[6..6 + n_values].to_vec(), words[2], words[3], words[4])
+    }
+
+    // The expected (fact, memory hash, product) of continuous_page_input.
+    fn continuous_page_output() -> (FixedBytes<32>, U256, U256) {
+        (
+            B256::from_slice(
+                &hex::decode("acc10b48ab7bbd00600dd2625587e0281aa09e720168c5fb7b7c837aa15fc5ab")
+                    .unwrap(),
+            ),
+            U256::from_str_radix(
+                "81661721188385702157042927837006178290415694098901503511909758845531821834230",
+                10,
+            )
+            .unwrap(),
+            U256::from_str_radix(
+                "1067825186783046787734270318674220356295405810188424644023662921002132144979",
+                10,
+            )
+            .unwrap(),
+        )
+    }
 
     #[motsu::test]
     fn test_compute_fact_hash() {
@@ -202,4 +334,90 @@ mod tests {
             "Fact hash mismatch"
         );
     }
+
+    #[motsu::test]
+    fn test_compute_continuous_fact_hash() {
+        let (start_addr, values, z, alpha, prime) = continuous_page_input();
+
+        let (fact_hash, memory_hash, prod) = MemoryPageFactRegistry::compute_continuous_fact_hash(
+            start_addr, &values, z, alpha, prime,
+        );
+
+        let (expected_fact_hash, expected_memory_hash, expected_prod) = continuous_page_output();
+        assert_eq!(prod, expected_prod, "Product mismatch");
+        assert_eq!(memory_hash, expected_memory_hash, "Memory hash mismatch");
+        assert_eq!(fact_hash, expected_fact_hash, "Fact hash mismatch");
+    }
+
+    #[motsu::test]
+    fn test_register_continuous_memory_page(
+        contract: Contract<MemoryPageFactRegistry>,
+        alice: Address,
+    ) {
+        let (start_addr, values, z, alpha, prime) = continuous_page_input();
+        let error = |err: MemoryPageError| Vec::<u8>::from(err);
+
+        // The interaction elements and the start address must be in the field, and the prime
+        // small enough.
+        let err = contract
+            .sender(alice)
+            .register_continuous_memory_page(start_addr, values.clone(), prime, alpha, prime)
+            .motsu_unwrap_err();
+        assert_eq!(
+            err,
+            error(MemoryPageError::InvalidInteractionElement(InvalidInteractionElement {
+                value: prime
+            }))
+        );
+        let err = contract
+            .sender(alice)
+            .register_continuous_memory_page(start_addr, values.clone(), z, prime + U256::from(1), prime)
+            .motsu_unwrap_err();
+        assert_eq!(
+            err,
+            error(MemoryPageError::InvalidInteractionElement(InvalidInteractionElement {
+                value: prime + U256::from(1)
+            }))
+        );
+        let big_prime = U256::from(1) << 254;
+        let err = contract
+            .sender(alice)
+            .register_continuous_memory_page(start_addr, values.clone(), z, alpha, big_prime)
+            .motsu_unwrap_err();
+        assert_eq!(err, error(MemoryPageError::PrimeTooBig(PrimeTooBig { prime: big_prime })));
+        for bad_start_addr in [U256::from(1) << 64, prime] {
+            let err = contract
+                .sender(alice)
+                .register_continuous_memory_page(bad_start_addr, values.clone(), z, alpha, prime)
+                .motsu_unwrap_err();
+            assert_eq!(
+                err,
+                error(MemoryPageError::InvalidStartAddr(InvalidStartAddr {
+                    startAddr: bad_start_addr
+                }))
+            );
+        }
+        let too_many_values = vec![U256::ZERO; 1 << 20];
+        let err = contract
+            .sender(alice)
+            .register_continuous_memory_page(start_addr, too_many_values, z, alpha, prime)
+            .motsu_unwrap_err();
+        assert_eq!(
+            err,
+            error(MemoryPageError::TooManyMemoryValues(TooManyMemoryValues {
+                nValues: U256::from(1 << 20)
+            }))
+        );
+        assert!(!contract.sender(alice).fact_registry.has_registered_fact());
+
+        // A valid page returns its fact, hash and product, and registers the fact.
+        let (fact_hash, memory_hash, prod) = contract
+            .sender(alice)
+            .register_continuous_memory_page(start_addr, values, z, alpha, prime)
+            .motsu_unwrap();
+        assert_eq!((fact_hash, memory_hash, prod), continuous_page_output());
+        assert!(contract.sender(alice).fact_registry.is_valid(fact_hash));
+        assert!(contract.sender(alice).fact_registry.has_registered_fact());
+        assert!(!contract.sender(alice).fact_registry.is_valid(FixedBytes::ZERO));
+    }
 }
@@ -0,0 +1,22 @@
+//! Prints the coefficients of a compiled periodic column as a `uint256[]` for uploadCoefficients,
+//! and on a second line the hash to pass to seal, e.g.
+//!
+//! ```text
+//! cargo run --example coefficients -- PoseidonFullRoundKey0
+//! ```
+use std::{env, process};
+
+use stark_verifier_core::periodic_columns::{self, PeriodicColumn};
+
+fn main() {
+    let name = env::args().nth(1).unwrap_or_default();
+    let Some(column) = PeriodicColumn::ALL.into_iter().find(|column| format!("{column:?}") == name)
+    else {
+        eprintln!("expected one of {:?}", PeriodicColumn::ALL);
+        process::exit(2);
+    };
+    let coefficients = column.coefficients();
+    let words: Vec<String> = coefficients.iter().map(|coeff| coeff.to_string()).collect();
+    println!("[{}]", words.join(","));
+    println!("{}", periodic_columns::coefficients_hash(coefficients));
+}
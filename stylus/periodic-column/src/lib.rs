@@ -0,0 +1,192 @@
+// Allow `cargo stylus export-abi` to generate a main function.
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
+
+#[macro_use]
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use stark_verifier_core::periodic_columns;
+use stylus_sdk::{
+    alloy_primitives::{Address, FixedBytes, U256},
+    prelude::*,
+    storage::{StorageBool, StorageFixedBytes, StorageU256, StorageVec},
+};
+use utils::{felt252::K_MODULUS, ownable::Ownable, require, require::*};
+
+// A periodic column whose coefficients are stored instead of compiled in, so a column of a new
+// layout is a deployment of this contract and an upload of its coefficients. The owner uploads
+// the coefficients, from the highest degree down to the constant term, in as many calls as
+// needed and then seals the table; after that it cannot change and compute can be called.
+//
+// compute has the same ABI as the compiled column contracts, so the CPU verifier can be wired to
+// either.
+#[storage]
+#[entrypoint]
+pub struct PeriodicColumn {
+    #[borrow]
+    ownable: Ownable,
+    version: StorageU256,
+    coefficients: StorageVec<StorageU256>,
+    coefficients_hash: StorageFixedBytes<32>,
+    sealed: StorageBool,
+}
+
+#[public]
+#[inherit(Ownable)]
+impl PeriodicColumn {
+    // The owner uploads and seals the coefficients. The version is reported by getVersion, e.g. to
+    // tell the layout the column belongs to.
+    #[constructor]
+    pub fn constructor(&mut self, owner: Address, version: U256) -> Result<(), Vec<u8>> {
+        self.ownable.init_owner(owner)?;
+        self.version.set(version);
+        Ok(())
+    }
+
+    // Appends coefficients to the table.
+    pub fn upload_coefficients(&mut self, coefficients: Vec<U256>) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner()?;
+        require!(!self.sealed.get(), ColumnSealed {});
+        for coefficient in coefficients {
+            require!(coefficient < K_MODULUS, InvalidCoefficient { coefficient });
+            self.coefficients.push(coefficient);
+        }
+        Ok(())
+    }
+
+    // Seals the table if it hashes to expected_hash, see periodic_columns::coefficients_hash.
+    pub fn seal(&mut self, expected_hash: FixedBytes<32>) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner()?;
+        require!(!self.sealed.get(), ColumnSealed {});
+        let hash = periodic_columns::coefficients_hash(&self.stored_coefficients());
+        require!(
+            hash == expected_hash,
+            CoefficientsHashMismatch { expected: expected_hash, actual: hash }
+        );
+        self.coefficients_hash.set(hash);
+        self.sealed.set(true);
+        Ok(())
+    }
+
+    pub fn compute(&self, x: U256) -> Result<U256, Vec<u8>> {
+        require!(self.sealed.get(), ColumnNotSealed {});
+        Ok(periodic_columns::evaluate(self.stored_coefficients(), x))
+    }
+
+    pub fn get_version(&self) -> U256 {
+        self.version.get()
+    }
+
+    // Zero until the table is sealed.
+    pub fn get_coefficients_hash(&self) -> FixedBytes<32> {
+        self.coefficients_hash.get()
+    }
+
+    pub fn get_coefficients_count(&self) -> U256 {
+        U256::from(self.coefficients.len())
+    }
+
+    pub fn is_sealed(&self) -> bool {
+        self.sealed.get()
+    }
+}
+
+impl PeriodicColumn {
+    fn stored_coefficients(&self) -> Vec<U256> {
+        (0..self.coefficients.len()).map(|i| self.coefficients.get(i).unwrap_or_default()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use stark_verifier_core::periodic_columns::PeriodicColumn as Column;
+    use stylus_sdk::testing::*;
+
+    use super::*;
+
+    // The deployment, with the sender as the owner.
+    fn deploy(vm: &TestVM, version: U256) -> PeriodicColumn {
+        let mut contract = PeriodicColumn::from(vm);
+        contract.constructor(vm.msg_sender(), version).unwrap();
+        contract
+    }
+
+    fn upload(contract: &mut PeriodicColumn, column: Column) {
+        for chunk in column.coefficients().chunks(100) {
+            contract.upload_coefficients(chunk.to_vec()).unwrap();
+        }
+    }
+
+    #[motsu::test]
+    fn test_compute_matches_compiled_columns() {
+        let x = U256::from(0x1234567);
+        for column in Column::ALL {
+            let vm = TestVM::default();
+            let mut contract = deploy(&vm, U256::from(7));
+            upload(&mut contract, column);
+            let hash = periodic_columns::coefficients_hash(column.coefficients());
+            contract.seal(hash).unwrap();
+
+            assert!(contract.is_sealed());
+            assert_eq!(contract.get_version(), U256::from(7));
+            assert_eq!(contract.get_coefficients_hash(), hash);
+            assert_eq!(contract.get_coefficients_count(), U256::from(column.coefficients().len()));
+            assert_eq!(contract.compute(x).unwrap(), periodic_columns::compute(column, x));
+        }
+    }
+
+    #[motsu::test]
+    fn test_seal() {
+        let vm = TestVM::default();
+        let mut contract = deploy(&vm, U256::from(7));
+        upload(&mut contract, Column::PoseidonFullRoundKey0);
+
+        let err = contract.compute(U256::from(1)).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::ColumnNotSealed(ColumnNotSealed {})));
+
+        // A table that is missing its last coefficient is not sealed.
+        let coefficients = Column::PoseidonFullRoundKey0.coefficients();
+        let expected = periodic_columns::coefficients_hash(&coefficients[..coefficients.len() - 1]);
+        let actual = periodic_columns::coefficients_hash(coefficients);
+        let err = contract.seal(expected).unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::CoefficientsHashMismatch(CoefficientsHashMismatch { expected, actual }))
+        );
+
+        contract.seal(actual).unwrap();
+        let err = contract.upload_coefficients(vec![U256::ZERO]).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::ColumnSealed(ColumnSealed {})));
+        let err = contract.seal(actual).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::ColumnSealed(ColumnSealed {})));
+    }
+
+    #[motsu::test]
+    fn test_upload_coefficients_rejects_invalid_input() {
+        let vm = TestVM::default();
+        let mut contract = deploy(&vm, U256::ZERO);
+
+        let err = contract.upload_coefficients(vec![K_MODULUS]).unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::InvalidCoefficient(InvalidCoefficient { coefficient: K_MODULUS }))
+        );
+
+        // Whoever sees the deployment cannot upload or seal a table of their own.
+        vm.set_sender(Address::repeat_byte(0x11));
+        let err = contract.upload_coefficients(vec![U256::ZERO]).unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::OnlyOwner(OnlyOwner { caller: Address::repeat_byte(0x11) }))
+        );
+        let err = contract.seal(periodic_columns::coefficients_hash(&[])).unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::OnlyOwner(OnlyOwner { caller: Address::repeat_byte(0x11) }))
+        );
+        assert_eq!(contract.get_coefficients_count(), U256::ZERO);
+        assert!(!contract.is_sealed());
+    }
+}
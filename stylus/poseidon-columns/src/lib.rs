@@ -0,0 +1,26 @@
+// Allow `cargo stylus export-abi` to generate a main function.
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
+
+#[macro_use]
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use stark_verifier_core::periodic_columns::{self, PeriodicColumnFamily};
+use stylus_sdk::alloy_primitives::U256;
+use stylus_sdk::prelude::*;
+
+// The Poseidon full round key 0-2 and partial round key 0-1 columns. The CPU verifier evaluates
+// them at the same point, so they are deployed as one contract and computed with a single call.
+#[storage]
+#[entrypoint]
+pub struct PoseidonColumns;
+
+#[public]
+impl PoseidonColumns {
+    // The values of the columns at x, in the order of PeriodicColumnFamily::columns.
+    pub fn compute_all(x: U256) -> Vec<U256> {
+        periodic_columns::compute_all(PeriodicColumnFamily::Poseidon, x)
+    }
+}
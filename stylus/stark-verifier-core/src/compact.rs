@@ -0,0 +1,374 @@
+//! The compact encoding of the verifier input, taken by the `bytes` entrypoints of the contracts.
+//!
+//! The ABI encoding of a `uint256[]` spends 32 bytes on every element, also on the proof params and
+//! on the public input, which are mostly small integers. Calldata is most of the cost of a proof
+//! on Arbitrum, so the compact encoding drops the padding. All integers are big-endian:
+//!
+//!     params:  u8 n, then n u32 values,
+//!     words:   u32 n, then n values, each a u8 byte length l <= 32 followed by l bytes (0 is a
+//!              single 0x00 byte),
+//!     proof:   u32 n_bytes, then the bytes of the proof words.
+//!
+//! The proof keeps the byte layout of the `uint256[]` proof (see `proof`), only the zero padding
+//! at the end of the last word is dropped. The verifier reads its words in place, through the
+//! same byte pointers as the `uint256[]` proof (`proof::ProofWords::Bytes`).
+//!
+//! A STARK input (`CpuVerifier::verify_proof_compact`) is
+//!
+//!     u8 version, params (proof params), proof, words (public input),
+//!
+//! and a GPS input (`GpsStatementVerifier::verify_proof_and_register_compact`) is
+//!
+//!     u8 version, words (task metadata), u32 cairo verifier id,
+//!     params (proof params), proof, words (cairo public input),
+//!     words (interaction elements z and alpha).
+//!
+//! The cairo aux input is the cairo public input followed by the interaction elements. The
+//! sections from the params to the cairo public input are the ones of the STARK input, so the GPS
+//! statement verifier forwards that byte range to the CPU verifier as it is.
+use alloc::vec::Vec;
+
+use stylus_sdk::alloy_primitives::U256;
+use utils::require::InvalidCompactInput;
+
+pub const COMPACT_VERSION: u8 = 1;
+
+// Reads the sections of a compact input in order. Every error carries the offset it occurred at.
+pub struct CompactReader<'a> {
+    data: &'a [u8],
+    ptr: usize,
+}
+
+impl<'a> CompactReader<'a> {
+    // Starts reading an input, after checking its version.
+    pub fn new(data: &'a [u8]) -> Result<Self, Vec<u8>> {
+        let mut reader = CompactReader { data, ptr: 0 };
+        if reader.read_u8()? != COMPACT_VERSION {
+            return Err(InvalidCompactInput { offset: U256::ZERO }.into());
+        }
+        Ok(reader)
+    }
+
+    // The offset of the next section.
+    pub fn offset(&self) -> usize {
+        self.ptr
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], Vec<u8>> {
+        let bytes = self
+            .data
+            .get(self.ptr..self.ptr + n)
+            .ok_or_else(|| Vec::from(InvalidCompactInput { offset: U256::from(self.ptr) }))?;
+        self.ptr += n;
+        Ok(bytes)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, Vec<u8>> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, Vec<u8>> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    pub fn read_params(&mut self) -> Result<Vec<U256>, Vec<u8>> {
+        let n = self.read_u8()? as usize;
+        (0..n).map(|_| self.read_u32().map(U256::from)).collect()
+    }
+
+    pub fn read_words(&mut self) -> Result<Vec<U256>, Vec<u8>> {
+        let n = self.read_u32()? as usize;
+        // Every word takes at least one byte, which bounds the allocation by the input size.
+        if n > self.data.len() - self.ptr {
+            return Err(InvalidCompactInput { offset: U256::from(self.ptr) }.into());
+        }
+        let mut words = Vec::with_capacity(n);
+        for _ in 0..n {
+            let offset = self.ptr;
+            let len = self.read_u8()? as usize;
+            if len > 32 {
+                return Err(InvalidCompactInput { offset: U256::from(offset) }.into());
+            }
+            words.push(U256::from_be_slice(self.take(len)?));
+        }
+        Ok(words)
+    }
+
+    // Returns the bytes of the proof words, see `proof_bytes`.
+    pub fn read_proof(&mut self) -> Result<&'a [u8], Vec<u8>> {
+        let n_bytes = self.read_u32()? as usize;
+        self.take(n_bytes)
+    }
+
+    // Checks that the whole input was read.
+    pub fn finish(self) -> Result<(), Vec<u8>> {
+        if self.ptr != self.data.len() {
+            return Err(InvalidCompactInput { offset: U256::from(self.ptr) }.into());
+        }
+        Ok(())
+    }
+}
+
+// The bytes of the proof words, without the zero padding at the end of the last one.
+pub fn proof_bytes(proof: &[U256]) -> Vec<u8> {
+    let mut bytes: Vec<u8> = proof.iter().flat_map(|word| word.to_be_bytes::<32>()).collect();
+    // Keep at least one byte of the last word, so that the proof keeps its number of words.
+    let min_len = bytes.len().saturating_sub(31);
+    while bytes.len() > min_len && bytes.last() == Some(&0) {
+        bytes.pop();
+    }
+    bytes
+}
+
+// Writes the sections of a compact input in order.
+pub struct CompactWriter {
+    data: Vec<u8>,
+}
+
+impl Default for CompactWriter {
+    fn default() -> Self {
+        CompactWriter::new()
+    }
+}
+
+impl CompactWriter {
+    pub fn new() -> Self {
+        CompactWriter { data: vec![COMPACT_VERSION] }
+    }
+
+    pub fn write_u32(&mut self, value: u32) {
+        self.data.extend_from_slice(&value.to_be_bytes());
+    }
+
+    // Panics if there are more than 255 params or a param does not fit in 32 bits, which no
+    // params accepted by verifier-init do.
+    pub fn write_params(&mut self, params: &[U256]) {
+        self.data.push(u8::try_from(params.len()).expect("too many params"));
+        for param in params {
+            self.write_u32(param.to::<u32>());
+        }
+    }
+
+    pub fn write_words(&mut self, words: &[U256]) {
+        self.write_u32(words.len() as u32);
+        for word in words {
+            let bytes = word.to_be_bytes::<32>();
+            let start = bytes.iter().position(|&b| b != 0).unwrap_or(32);
+            self.data.push((32 - start) as u8);
+            self.data.extend_from_slice(&bytes[start..]);
+        }
+    }
+
+    // Writes the bytes of the proof words, see `proof_bytes`.
+    pub fn write_proof(&mut self, proof: &[u8]) {
+        self.write_u32(proof.len() as u32);
+        self.data.extend_from_slice(proof);
+    }
+
+    // Writes the params, proof and public input sections of a STARK input.
+    pub fn write_stark_sections(&mut self, input: &StarkInput) {
+        self.write_params(&input.proof_params);
+        self.write_proof(input.proof);
+        self.write_words(&input.public_input);
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+// The proof is kept as bytes, which the verifier reads in place.
+pub struct StarkInput<'a> {
+    pub proof_params: Vec<U256>,
+    pub proof: &'a [u8],
+    pub public_input: Vec<U256>,
+}
+
+impl<'a> StarkInput<'a> {
+    pub fn decode(data: &'a [u8]) -> Result<Self, Vec<u8>> {
+        let mut reader = CompactReader::new(data)?;
+        let input = StarkInput::read_sections(&mut reader)?;
+        reader.finish()?;
+        Ok(input)
+    }
+
+    fn read_sections(reader: &mut CompactReader<'a>) -> Result<Self, Vec<u8>> {
+        let proof_params = reader.read_params()?;
+        let proof = reader.read_proof()?;
+        let public_input = reader.read_words()?;
+        Ok(StarkInput { proof_params, proof, public_input })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut writer = CompactWriter::new();
+        writer.write_stark_sections(self);
+        writer.into_bytes()
+    }
+}
+
+pub struct GpsInput<'a> {
+    pub task_metadata: Vec<U256>,
+    pub cairo_aux_input: Vec<U256>,
+    pub cairo_verifier_id: U256,
+    // The params, proof and cairo public input sections, as they are in the input.
+    stark_sections: &'a [u8],
+}
+
+impl<'a> GpsInput<'a> {
+    pub fn decode(data: &'a [u8]) -> Result<Self, Vec<u8>> {
+        let mut reader = CompactReader::new(data)?;
+        let task_metadata = reader.read_words()?;
+        let cairo_verifier_id = U256::from(reader.read_u32()?);
+        let stark_start = reader.offset();
+        let StarkInput { public_input: mut cairo_aux_input, .. } = StarkInput::read_sections(&mut reader)?;
+        let stark_sections = &data[stark_start..reader.offset()];
+        let interaction_elements_offset = reader.offset();
+        let interaction_elements = reader.read_words()?;
+        if interaction_elements.len() != 2 {
+            return Err(InvalidCompactInput { offset: U256::from(interaction_elements_offset) }.into());
+        }
+        reader.finish()?;
+        cairo_aux_input.extend(interaction_elements);
+        Ok(GpsInput { task_metadata, cairo_aux_input, cairo_verifier_id, stark_sections })
+    }
+
+    // The public input of the STARK input is the cairo public input, which the interaction
+    // elements follow in the cairo aux input.
+    pub fn encode(
+        task_metadata: &[U256],
+        cairo_verifier_id: u32,
+        stark_input: &StarkInput,
+        interaction_elements: &[U256],
+    ) -> Vec<u8> {
+        let mut writer = CompactWriter::new();
+        writer.write_words(task_metadata);
+        writer.write_u32(cairo_verifier_id);
+        writer.write_stark_sections(stark_input);
+        writer.write_words(interaction_elements);
+        writer.into_bytes()
+    }
+
+    // The input of the CPU verifier: the version, then the STARK sections of this input.
+    pub fn stark_input(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(1 + self.stark_sections.len());
+        data.push(COMPACT_VERSION);
+        data.extend_from_slice(self.stark_sections);
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proof::ProofWords;
+    use utils::require::StarkError;
+
+    fn parse_input(input: &str) -> Vec<U256> {
+        input
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(',')
+            .map(|val| U256::from_str_radix(val.trim(), 10).unwrap())
+            .collect()
+    }
+
+    // The proof params, proof and public input of the test vectors.
+    fn test_vectors() -> (Vec<U256>, Vec<U256>, Vec<U256>) {
+        (
+            parse_input(include_str!("../../../inputs/proof_params.txt")),
+            parse_input(include_str!("../../../inputs/proof.txt")),
+            parse_input(include_str!("../../../inputs/public_input.txt")),
+        )
+    }
+
+    // The size of the ABI encoding of the uint256[] arrays, with their offsets and lengths.
+    fn abi_size(arrays: &[&[U256]]) -> usize {
+        arrays.iter().map(|array| 64 + 32 * array.len()).sum()
+    }
+
+    #[test]
+    fn test_stark_input_round_trip() {
+        let (proof_params, proof, public_input) = test_vectors();
+        let proof_bytes = proof_bytes(&proof);
+        let input = StarkInput {
+            proof_params: proof_params.clone(),
+            proof: &proof_bytes,
+            public_input: public_input.clone(),
+        };
+        let data = input.encode();
+        let decoded = StarkInput::decode(&data).unwrap();
+        assert_eq!(decoded.proof_params, proof_params);
+        assert_eq!(decoded.public_input, public_input);
+        // The proof is a slice of the input, whose words are the ones of the uint256[] proof.
+        let words = ProofWords::Bytes(decoded.proof);
+        assert_eq!(words.words(0, words.len()).into_owned(), proof);
+
+        let abi = abi_size(&[&proof_params, &proof, &public_input]);
+        assert!(data.len() < abi * 3 / 4, "{} bytes, {} ABI encoded", data.len(), abi);
+    }
+
+    #[test]
+    fn test_proof_keeps_its_number_of_words() {
+        let proof = vec![U256::from(1) << 255, U256::ZERO];
+        let bytes = proof_bytes(&proof);
+        assert_eq!(bytes.len(), 33);
+        let mut writer = CompactWriter::new();
+        writer.write_proof(&bytes);
+        let data = writer.into_bytes();
+        let read = CompactReader::new(&data).unwrap().read_proof().unwrap();
+        let words = ProofWords::Bytes(read);
+        assert_eq!(words.words(0, words.len()).into_owned(), proof);
+    }
+
+    #[test]
+    fn test_gps_input_forwards_stark_sections() {
+        let (proof_params, proof, public_input) = test_vectors();
+        let proof_bytes = proof_bytes(&proof);
+        let stark_input = StarkInput { proof_params, proof: &proof_bytes, public_input };
+        let task_metadata = [U256::from(1), U256::from(2)];
+        let interaction_elements = [U256::from(4), U256::from(5)];
+        let data = GpsInput::encode(&task_metadata, 3, &stark_input, &interaction_elements);
+
+        let decoded = GpsInput::decode(&data).unwrap();
+        assert_eq!(decoded.task_metadata, task_metadata);
+        assert_eq!(decoded.cairo_verifier_id, U256::from(3));
+        let cairo_aux_input = [stark_input.public_input.as_slice(), &interaction_elements].concat();
+        assert_eq!(decoded.cairo_aux_input, cairo_aux_input);
+        // The CPU verifier gets the sections of the GPS input as they are.
+        assert_eq!(decoded.stark_input(), stark_input.encode());
+        // The interaction elements take 4 bytes for their number and 2 for each.
+        let start = data.len() - decoded.stark_sections.len() - 8;
+        assert!(core::ptr::eq(decoded.stark_sections, &data[start..start + decoded.stark_sections.len()]));
+    }
+
+    #[test]
+    fn test_decode_rejects_malformed_input() {
+        let (proof_params, proof, public_input) = test_vectors();
+        let proof_bytes = proof_bytes(&proof);
+        let data = StarkInput { proof_params, proof: &proof_bytes, public_input }.encode();
+        let offset = |data: &[u8]| match StarkError::decode(&StarkInput::decode(data).err().unwrap()) {
+            Some(StarkError::InvalidCompactInput(err)) => err.offset.to::<usize>(),
+            err => panic!("unexpected error {err:?}"),
+        };
+        // Unknown version.
+        assert_eq!(offset(&[2]), 0);
+        // A truncated public input fails at its last word.
+        assert!(offset(&data[..data.len() - 1]) > data.len() - 34);
+        // Trailing bytes.
+        assert_eq!(offset(&[data.as_slice(), &[0]].concat()), data.len());
+        // A word longer than 32 bytes, after no params, an empty proof and the number of words.
+        assert_eq!(offset(&[COMPACT_VERSION, 0, 0, 0, 0, 0, 0, 0, 0, 1, 33]), 10);
+    }
+
+    #[test]
+    fn test_gps_input_needs_two_interaction_elements() {
+        let stark_input = StarkInput { proof_params: vec![], proof: &[], public_input: vec![] };
+        let data = GpsInput::encode(&[], 1, &stark_input, &[U256::from(1)]);
+        let err = GpsInput::decode(&data).err().unwrap();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::InvalidCompactInput(InvalidCompactInput { offset: U256::from(18) }))
+        );
+    }
+}
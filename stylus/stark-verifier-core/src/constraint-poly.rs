@@ -0,0 +1,12 @@
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::U256;
+
+use crate::constraint_poly_finalizer::ConstraintPolyFinalizer;
+use crate::constraint_poly_preparer::ConstraintPolyPreparer;
+
+// Evaluates the composition polynomial at the OODS point from ctx[317..551]. On-chain the
+// preparer and the finalizer are separate contracts to stay below the contract size limit.
+pub fn compute(calldata: &[U256]) -> Result<U256, Vec<u8>> {
+    let cp_and_domains = ConstraintPolyPreparer::compute(calldata)?;
+    ConstraintPolyFinalizer::compute(&[calldata, &cp_and_domains].concat())
+}
@@ -0,0 +1,319 @@
+use alloc::vec::Vec;
+use utils::{
+    felt252::Felt252,
+    prime_field_element0::PrimeFieldElement0,
+    require,
+    require::*,
+};
+
+use stylus_sdk::{
+    alloy_primitives::{uint, FixedBytes, U256},
+    crypto::keccak,
+};
+
+use crate::memory_map::*;
+use crate::proof::{Decommitment, ProofWords};
+
+// Verifies the decommitment of the trace and composition queries and the FRI layers of a proof,
+// given the ctx prepared by the StarkVerifier. The Merkle and FRI statements are proven
+// separately; the verifier only checks that they were registered.
+pub trait FriVerifier {
+
+    fn compute_oods(&mut self, ctx: &[U256]) -> Result<Vec<U256>, Vec<u8>>;
+
+    fn is_merkle_statement_valid(&mut self, statement: FixedBytes<32>) -> Result<bool, Vec<u8>>;
+
+    fn is_fri_statement_valid(&mut self, statement: FixedBytes<32>) -> Result<bool, Vec<u8>>;
+
+    fn verify(
+        &mut self,
+        proof: ProofWords,
+        ctx: &mut [U256],
+        fri_step_sizes: &[U256],
+    ) -> Result<(), Vec<u8>> {
+        adjust_query_indices_and_prepare_eval_points(ctx);
+        let decommitment = Decommitment::decode(proof, ctx, fri_step_sizes.len())?;
+
+        let trace_commitment0 = ctx.trace_commitment(0);
+        let trace_commitment1 = ctx.trace_commitment(1);
+        let oods_commitment = ctx.oods_commitment();
+        self.read_query_responses_and_decommit(
+            ctx,
+            &decommitment.trace_query_responses[0],
+            N_COLUMNS_IN_MASK,
+            N_COLUMNS_IN_TRACE0,
+            MM_TRACE_QUERY_RESPONSES,
+            trace_commitment0,
+        )?;
+        self.read_query_responses_and_decommit(
+            ctx,
+            &decommitment.trace_query_responses[1],
+            N_COLUMNS_IN_MASK,
+            N_COLUMNS_IN_TRACE1,
+            MM_TRACE_QUERY_RESPONSES + N_COLUMNS_IN_TRACE0,
+            trace_commitment1,
+        )?;
+        self.read_query_responses_and_decommit(
+            ctx,
+            &decommitment.composition_query_responses,
+            CONSTRAINTS_DEGREE_BOUND,
+            CONSTRAINTS_DEGREE_BOUND,
+            MM_COMPOSITION_QUERY_RESPONSES,
+            oods_commitment,
+        )?;
+
+        let oods_result = self.compute_oods(ctx)?;
+        ctx[MM_FRI_QUEUE..MM_FRI_QUEUE + oods_result.len()].copy_from_slice(&oods_result);
+
+        self.fri_verify_layers(ctx, &decommitment, fri_step_sizes)?;
+        Ok(())
+    }
+
+    // Copies the query responses of one commitment (n_columns values per query) into ctx and
+    // checks the Merkle statement of their rows.
+    fn read_query_responses_and_decommit(
+        &mut self,
+        ctx: &mut [U256],
+        responses: &[U256],
+        n_total_columns: usize,
+        n_columns: usize,
+        mut proof_data_ptr: usize,
+        merkle_root: FixedBytes<32>,
+    ) -> Result<(), Vec<u8>> {
+        require!(
+            n_columns <= n_total_columns,
+            TooManyColumns { nColumns: U256::from(n_columns), nTotalColumns: U256::from(n_total_columns) }
+        );
+
+        let n_unique_queries = ctx.n_unique_queries();
+        let channel_ptr = MM_CHANNEL;
+        let fri_queue = MM_FRI_QUEUE;
+        let fri_queue_end = fri_queue + n_unique_queries * FRI_QUEUE_SLOT_SIZE;
+        let merkle_queue_ptr = MM_MERKLE_QUEUE;
+        let row_size = n_columns * 32;
+        let proof_data_skip_bytes = n_total_columns - n_columns;
+
+        let mut proof_ptr = ctx[channel_ptr].to::<usize>();
+        let mut merkle_ptr = merkle_queue_ptr;
+
+        let mut rows = responses.chunks_exact(n_columns);
+        let mut i = fri_queue;
+        while i < fri_queue_end {
+            let row = rows.next().ok_or("Query responses do not match the queries.")?;
+            let mut input_data = Vec::new();
+            for value in row {
+                ctx[proof_data_ptr] = *value;
+                input_data.extend_from_slice(&value.to_be_bytes::<32>());
+                proof_data_ptr += 1;
+            }
+
+            let merkle_leaf_hash: U256 = keccak(&input_data).into();
+            let mut merkle_leaf = merkle_leaf_hash
+                & uint!(0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF000000000000000000000000_U256);
+
+            if row_size == 32 {
+                merkle_leaf = row[0];
+            }
+
+            ctx[merkle_ptr] = ctx[i];
+            ctx[merkle_ptr + 1] = merkle_leaf;
+            merkle_ptr += 2;
+
+            i += FRI_QUEUE_SLOT_SIZE;
+            proof_ptr += row_size;
+            proof_data_ptr += proof_data_skip_bytes;
+        }
+
+        ctx[channel_ptr] = U256::from(proof_ptr);
+        self.verify_merkle(ctx, merkle_queue_ptr, merkle_root, n_unique_queries)?;
+        Ok(())
+    }
+
+    fn fri_verify_layers(
+        &mut self,
+        ctx: &mut [U256],
+        decommitment: &Decommitment,
+        fri_step_sizes: &[U256],
+    ) -> Result<(), Vec<u8>> {
+        let n_queries = ctx.n_unique_queries();
+        // The FRI statement contract takes the queue values in Montgomery form.
+        for query in ctx.fri_queue_mut().chunks_exact_mut(FRI_QUEUE_SLOT_SIZE) {
+            query[1] = Felt252::new(query[1]).to_montgomery();
+        }
+
+        let mut input_data = Vec::new();
+        for value in ctx.fri_queue() {
+            input_data.extend_from_slice(&value.to_be_bytes::<32>());
+        }
+        let mut input_layer_hash: U256 = uint!(keccak(&input_data).into());
+
+        let n_fri_inner_layers = fri_step_sizes.len() - 1;
+        let mut fri_step = 1;
+        let mut sum_of_step_sizes = fri_step_sizes[1];
+        while fri_step < n_fri_inner_layers {
+            let mut data_to_hash = Vec::new();
+            let output_layer_hash =
+                receive_layer_hash(ctx, MM_CHANNEL, decommitment.fri_layer_hashes[fri_step - 1]);
+            data_to_hash.extend_from_slice(&ctx.fri_eval_point(fri_step).to_be_bytes::<32>());
+            data_to_hash.extend_from_slice(&fri_step_sizes[fri_step].to_be_bytes::<32>());
+            data_to_hash.extend_from_slice(&input_layer_hash.to_be_bytes::<32>());
+            data_to_hash.extend_from_slice(&output_layer_hash.to_be_bytes::<32>());
+            data_to_hash.extend_from_slice(&ctx.fri_commitment(fri_step - 1).to_be_bytes::<32>());
+
+            let hash: FixedBytes<32> = keccak(&data_to_hash);
+            require!(self.is_fri_statement_valid(hash)?, InvalidatedFriStatement { statement: hash });
+
+            input_layer_hash = output_layer_hash;
+            fri_step += 1;
+            sum_of_step_sizes += fri_step_sizes[fri_step];
+        }
+
+        let mut data_to_hash = Vec::new();
+        data_to_hash.extend_from_slice(&ctx.fri_eval_point(fri_step).to_be_bytes::<32>());
+        data_to_hash.extend_from_slice(&fri_step_sizes[fri_step].to_be_bytes::<32>());
+        data_to_hash.extend_from_slice(&input_layer_hash.to_be_bytes::<32>());
+        data_to_hash.extend_from_slice(
+            &compute_last_layer_hash(&decommitment.last_layer, ctx, n_queries, sum_of_step_sizes)?
+                .to_be_bytes::<32>(),
+        );
+        data_to_hash.extend_from_slice(&ctx.fri_commitment(fri_step - 1).to_be_bytes::<32>());
+
+        let hash: FixedBytes<32> = keccak(&data_to_hash);
+        require!(self.is_fri_statement_valid(hash)?, InvalidatedFriStatement { statement: hash });
+
+        Ok(())
+    }
+
+    fn verify_merkle(
+        &mut self,
+        ctx: &[U256],
+        queue_ptr: usize,
+        root: FixedBytes<32>,
+        n: usize,
+    ) -> Result<FixedBytes<32>, Vec<u8>> {
+        require!(n <= 128, TooManyMerkleQueries { nQueries: U256::from(n) });
+
+        let que_end_ptr = queue_ptr + n * 2;
+        let mut input_data = Vec::new();
+        for value in &ctx[queue_ptr..que_end_ptr] {
+            input_data.extend_from_slice(&value.to_be_bytes::<32>());
+        }
+        input_data.extend_from_slice(root.as_slice());
+
+        let statement: FixedBytes<32> = keccak(&input_data);
+        require!(self.is_merkle_statement_valid(statement)?, InvalidatedMerkleStatement { statement });
+        Ok(root)
+    }
+}
+
+pub fn adjust_query_indices_and_prepare_eval_points(ctx: &mut [U256]) {
+    let n_unique_queries = ctx.n_unique_queries();
+    let fri_queue = MM_FRI_QUEUE;
+    let fri_queue_end = fri_queue + n_unique_queries * FRI_QUEUE_SLOT_SIZE;
+
+    let mut eval_points_ptr = MM_OODS_EVAL_POINTS;
+    let log_eval_domain_size = ctx.log_eval_domain_size();
+    let eval_domain_size = ctx.eval_domain_size();
+    let eval_domain_generator = Felt252::new(ctx.eval_domain_generator());
+
+    let mut i = fri_queue;
+    while i < fri_queue_end {
+        let query_idx = ctx[i];
+        let adjusted_query_idx = query_idx + eval_domain_size;
+        ctx[i] = adjusted_query_idx;
+        ctx[eval_points_ptr] = eval_domain_generator
+            .pow(PrimeFieldElement0::bit_reverse(query_idx, log_eval_domain_size))
+            .value();
+        eval_points_ptr += 1;
+        i += FRI_QUEUE_SLOT_SIZE;
+    }
+}
+
+pub fn read_ptr(proof: &[U256], ptr: usize, offset: usize) -> U256 {
+    let element_index = ptr / 32;
+
+    if ptr.is_multiple_of(32) {
+        proof[element_index]
+    } else {
+        let bit_shift = offset * 8;
+        let element1 = proof[element_index] << bit_shift;
+        let element2 = proof[element_index + 1] >> (256 - bit_shift);
+        element1 | element2
+    }
+}
+
+// Mixes the hash of the output queue of an inner FRI layer into the channel, which points at it.
+pub fn receive_layer_hash(ctx: &mut [U256], channel_ptr: usize, val: U256) -> U256 {
+    let proof_ptr = ctx[channel_ptr];
+    ctx[channel_ptr] = proof_ptr + U256::from(32);
+
+    let mut input_data = Vec::new();
+    input_data.extend_from_slice(&(ctx[channel_ptr + 1] + U256::from(1)).to_be_bytes::<32>());
+    input_data.extend_from_slice(&val.to_be_bytes::<32>());
+    ctx[channel_ptr + 1] = uint!(keccak(&input_data).into());
+    ctx[channel_ptr + 2] = U256::ZERO;
+
+    val
+}
+
+pub fn compute_last_layer_hash(
+    last_layer: &[U256],
+    ctx: &mut [U256],
+    n_points: usize,
+    sum_of_step_sizes: U256,
+) -> Result<U256, Vec<u8>> {
+    let fri_last_layer_deg_bound = ctx[MM_FRI_LAST_LAYER_DEG_BOUND];
+    let group_order_minus_one = fri_last_layer_deg_bound * ctx.blow_up_factor() - U256::from(1);
+    let exponent = U256::from(1) << sum_of_step_sizes;
+    let mut cur_point_index = 0;
+    let mut prev_query = U256::ZERO;
+    let fri_queue = &mut ctx[MM_FRI_QUEUE..MM_FRI_QUEUE + FRI_QUEUE_SLOT_SIZE * n_points];
+
+    for i in 0..n_points {
+        let query = fri_queue[FRI_QUEUE_SLOT_SIZE * i] >> sum_of_step_sizes;
+        if query == prev_query {
+            continue;
+        }
+
+        fri_queue[FRI_QUEUE_SLOT_SIZE * cur_point_index] = query;
+        prev_query = query;
+
+        let point = Felt252::new(fri_queue[FRI_QUEUE_SLOT_SIZE * i + 2]).pow(exponent);
+        fri_queue[FRI_QUEUE_SLOT_SIZE * cur_point_index + 2] = point.value();
+
+        fri_queue[FRI_QUEUE_SLOT_SIZE * cur_point_index + 1] = horner_eval(
+            last_layer,
+            0,
+            point.pow(group_order_minus_one).value(),
+            fri_last_layer_deg_bound.to::<usize>(),
+        )?;
+
+        cur_point_index += 1;
+    }
+
+    let mut data_to_hash = Vec::new();
+    for value in &fri_queue[..cur_point_index * FRI_QUEUE_SLOT_SIZE] {
+        data_to_hash.extend_from_slice(&value.to_be_bytes::<32>());
+    }
+    Ok(uint!(keccak(&data_to_hash).into()))
+}
+
+pub fn horner_eval(
+    proof: &[U256],
+    coefs_start: usize,
+    point: U256,
+    n_coefs: usize,
+) -> Result<U256, Vec<u8>> {
+    // The number of coefficients must be divisible by 8 and at most 4096.
+    require!(
+        n_coefs.is_multiple_of(8) && n_coefs < 4096,
+        InvalidPolynomialSize { nCoefs: U256::from(n_coefs) }
+    );
+
+    let point = Felt252::new(point);
+    let result = proof[coefs_start..coefs_start + n_coefs]
+        .iter()
+        .rev()
+        .fold(Felt252::ZERO, |acc, coef| acc * point + Felt252::new(*coef));
+    Ok(result.value())
+}
@@ -0,0 +1,803 @@
+use stylus_sdk::alloy_primitives::{uint, U256};
+pub const SIMPLE_BOOTLOADER_PROGRAM_HASH: U256 =
+    uint!(160268921359133235574810995023520895391777547407923205700393332203861498631_U256);
+
+pub const APPLICATION_BOOTLOADER_PROGRAM_HASH: U256 =
+    uint!(1104316318711847786071125527957082259001554753246760931396914052122269757907_U256);
+
+pub static BOOTLOADER_PROGRAM: [U256; 794] = uint!([
+    290341444919459839_U256,
+    11_U256,
+    1226245742482522112_U256,
+    778_U256,
+    74168662805676031_U256,
+    0_U256,
+    2345108766317314046_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020480_U256,
+    5198420613823102976_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020479_U256,
+    2345108766317314046_U256,
+    146226256843603965_U256,
+    4_U256,
+    5191102238658887680_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    3_U256,
+    4632937381316558848_U256,
+    4612671182992932865_U256,
+    4612671182992998402_U256,
+    146226256843603968_U256,
+    4_U256,
+    74168662805676031_U256,
+    4_U256,
+    4612671182993063937_U256,
+    4612671182993129474_U256,
+    5198983563776196608_U256,
+    1_U256,
+    5198983563776262144_U256,
+    1_U256,
+    5200109459388203008_U256,
+    5200109459388268544_U256,
+    5198983563776458752_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020480_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020458_U256,
+    2345108766317314046_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020449_U256,
+    5207990763031068672_U256,
+    10_U256,
+    5198420613823168512_U256,
+    12_U256,
+    5191102230068953088_U256,
+    5191102234363920384_U256,
+    5191102242953854976_U256,
+    5201798292068466688_U256,
+    5191102238658887680_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020444_U256,
+    4623648689905041407_U256,
+    291467327646433279_U256,
+    2345108766317314046_U256,
+    5199827962936983548_U256,
+    5208553695804948479_U256,
+    4612389708016287743_U256,
+    5198983563776262144_U256,
+    1_U256,
+    2345108766317314046_U256,
+    146226256843603965_U256,
+    4_U256,
+    5191102230068953088_U256,
+    2345108766317314046_U256,
+    5191102230068953088_U256,
+    5188850460319711232_U256,
+    5188850460319776768_U256,
+    5188850460319842304_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020467_U256,
+    5198983563776262144_U256,
+    1_U256,
+    5198983563776327680_U256,
+    1_U256,
+    5198983563776393216_U256,
+    1_U256,
+    5198983563776458752_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020480_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020463_U256,
+    2345108766317314046_U256,
+    5189976364521848832_U256,
+    0_U256,
+    5189976364521848832_U256,
+    0_U256,
+    5189976364521848832_U256,
+    0_U256,
+    5191102247248822272_U256,
+    5191102238658887680_U256,
+    290341444919459839_U256,
+    1_U256,
+    145944781866893311_U256,
+    4_U256,
+    74168662805676031_U256,
+    58_U256,
+    5188287510366486528_U256,
+    5201798304953368576_U256,
+    4611826758062997503_U256,
+    5188287514661322752_U256,
+    5201798304953303040_U256,
+    4611826762357833727_U256,
+    4611826766652801016_U256,
+    5188287523251191808_U256,
+    5188287518956093440_U256,
+    5201798304953696256_U256,
+    4611826783832473599_U256,
+    5188287527545962496_U256,
+    5188287523250864128_U256,
+    5201798304953696256_U256,
+    4611826788127244287_U256,
+    5188287531840733184_U256,
+    4611826792422146047_U256,
+    5188287549020536832_U256,
+    5188287527545569280_U256,
+    5201798304953696256_U256,
+    4611826809601818623_U256,
+    5188287553315307520_U256,
+    5188287531840339968_U256,
+    5201798304953696256_U256,
+    4611826813896589311_U256,
+    5188287557610078208_U256,
+    4611826818191491071_U256,
+    5188287574789881856_U256,
+    5188287536135045120_U256,
+    5201798304953696256_U256,
+    4611826835371163647_U256,
+    5188287579084652544_U256,
+    5188287540429815808_U256,
+    5201798304953696256_U256,
+    4611826839665934335_U256,
+    5188287583379423232_U256,
+    4611826843960836095_U256,
+    5188287600559226880_U256,
+    5188287544724520960_U256,
+    5201798304953696256_U256,
+    4611826861140508671_U256,
+    5188287604853997568_U256,
+    5188287549019291648_U256,
+    5201798304953696256_U256,
+    4611826865435279359_U256,
+    5188287609148768256_U256,
+    4611826869730181119_U256,
+    5188287626328571904_U256,
+    5188287630623473664_U256,
+    5188287634918375424_U256,
+    5198420613820743680_U256,
+    10_U256,
+    5198420613820743680_U256,
+    30_U256,
+    74168662805676031_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020421_U256,
+    290341444919459839_U256,
+    1_U256,
+    145944781866893311_U256,
+    4_U256,
+    74168662805676031_U256,
+    18_U256,
+    5188287510366420992_U256,
+    5201798304953303040_U256,
+    4611826758062931967_U256,
+    5188287514661257216_U256,
+    5201798304953237504_U256,
+    4611826762357768191_U256,
+    4611826766652735479_U256,
+    5188287523251126272_U256,
+    5188287527546028032_U256,
+    5188287531840929792_U256,
+    5198420613822513152_U256,
+    2_U256,
+    5198420613822513152_U256,
+    6_U256,
+    74168662805676031_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020399_U256,
+    5200109442208464896_U256,
+    5201798287773958143_U256,
+    145944781866893311_U256,
+    11_U256,
+    5198420613822644224_U256,
+    1_U256,
+    4611826758062866431_U256,
+    4611826762357833719_U256,
+    4611826766652801016_U256,
+    5198420613822840832_U256,
+    6_U256,
+    5188287523251126272_U256,
+    2345108766317314046_U256,
+    4613515612218425343_U256,
+    1_U256,
+    5188287510366289920_U256,
+    5201798304953171968_U256,
+    4611826758062800895_U256,
+    5198420613822578688_U256,
+    1_U256,
+    4611826762357702655_U256,
+    4611826766652669942_U256,
+    5198420613822709760_U256,
+    6_U256,
+    5188287523250995200_U256,
+    2345108766317314046_U256,
+    5188850460319907840_U256,
+    5202361254907052032_U256,
+    5191102242953854976_U256,
+    5188287510366552064_U256,
+    5188287506071519232_U256,
+    5188287510366486527_U256,
+    4611826762357964797_U256,
+    5198420613822906368_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020480_U256,
+    5198420613822906368_U256,
+    3_U256,
+    5188287518956224512_U256,
+    4623085744246521853_U256,
+    145944781866893308_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020472_U256,
+    2345108766317314046_U256,
+    5198983563776458752_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020480_U256,
+    145944781866893311_U256,
+    12_U256,
+    5191102238658887680_U256,
+    5188850460319842304_U256,
+    5198983563776393216_U256,
+    1_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020339_U256,
+    5191102234363920384_U256,
+    5193354047062507520_U256,
+    5193354047062507520_U256,
+    2345108766317314046_U256,
+    5191102234363920384_U256,
+    5191102242953854976_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020449_U256,
+    5193354051357474816_U256,
+    5191102238658887680_U256,
+    5193354047062507520_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    30_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020248_U256,
+    4617174774030761984_U256,
+    4612671182992932866_U256,
+    5189976364521848832_U256,
+    0_U256,
+    4612389712311713791_U256,
+    5188850464614612992_U256,
+    5188850490384416768_U256,
+    5191102264428691456_U256,
+    5191102247248822272_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020446_U256,
+    4612389712311779327_U256,
+    4622804286450008067_U256,
+    4_U256,
+    4612671195878359044_U256,
+    5200109476568596480_U256,
+    5188850468910104576_U256,
+    4625619027626983429_U256,
+    4622804286450073606_U256,
+    2_U256,
+    4617174761145860103_U256,
+    4612671191582867464_U256,
+    4612671195877834761_U256,
+    4612671200172802058_U256,
+    4612671204467769355_U256,
+    4612671208762736652_U256,
+    4617174765440827405_U256,
+    4612671217352671246_U256,
+    4612671221647638543_U256,
+    4612671225942605840_U256,
+    5191102238658887680_U256,
+    5198983563776655360_U256,
+    6_U256,
+    5189976364521848832_U256,
+    11_U256,
+    5191102273018626048_U256,
+    5191102277313593344_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020241_U256,
+    1191342862550269952_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020207_U256,
+    4623648724266090495_U256,
+    5191102238658887680_U256,
+    5198983563776655360_U256,
+    18_U256,
+    5191102273018626048_U256,
+    5191102333148168192_U256,
+    5189976364521848832_U256,
+    11_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020202_U256,
+    4623648719970271231_U256,
+    5191102234363920384_U256,
+    5198983563776655360_U256,
+    6_U256,
+    5198983563776655360_U256,
+    18_U256,
+    5191102242953854976_U256,
+    5189976364521848832_U256,
+    11_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020239_U256,
+    4623930190653653010_U256,
+    4612671182993522717_U256,
+    5198983563776655360_U256,
+    18_U256,
+    5193354051357474816_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    35_U256,
+    4622804286449090561_U256,
+    1_U256,
+    4614922931267403778_U256,
+    4614922982807011331_U256,
+    4614922939857338372_U256,
+    4614922944152305669_U256,
+    4614922948447272966_U256,
+    4614922952742240263_U256,
+    4614922957037207560_U256,
+    4614922961332174857_U256,
+    4614922965627142154_U256,
+    4614922969922109451_U256,
+    4613797087195136012_U256,
+    122550255383924_U256,
+    4613797087195136013_U256,
+    8098989891770344814_U256,
+    4613797087195136014_U256,
+    138277649577220228665140075_U256,
+    4613797087195136015_U256,
+    435459224417_U256,
+    4613797087195136016_U256,
+    27700496658166629_U256,
+    4613797087195136017_U256,
+    435458895728_U256,
+    4613797087195136018_U256,
+    118083203326315_U256,
+    4613797087195136019_U256,
+    8101821134059892590_U256,
+    4613797087195136020_U256,
+    9062164042692704905798619969846_U256,
+    4613797087195136021_U256,
+    27413455001448292_U256,
+    4613797087195136022_U256,
+    30809880779386724_U256,
+    4613797087195136023_U256,
+    1_U256,
+    4613797087195136024_U256,
+    3_U256,
+    4613797087195136025_U256,
+    1_U256,
+    4613797087195136026_U256,
+    2_U256,
+    4613797087195136027_U256,
+    5_U256,
+    4613797087195136028_U256,
+    7_U256,
+    4613797087195136029_U256,
+    16_U256,
+    4613797087195136030_U256,
+    6_U256,
+    4613797087195136031_U256,
+    1_U256,
+    4613797087195136032_U256,
+    7_U256,
+    4613797087195136033_U256,
+    7_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020119_U256,
+    5198420613823102976_U256,
+    1_U256,
+    5191102212889083904_U256,
+    5198420613822971904_U256,
+    12_U256,
+    5198420613822906368_U256,
+    23_U256,
+    5188850460319252480_U256,
+    1226245742482522112_U256,
+    38_U256,
+    4614641507830300671_U256,
+    4617174774030762018_U256,
+    5188850468912267264_U256,
+    5201798300658860031_U256,
+    5189976364521848832_U256,
+    64_U256,
+    1226245742482522112_U256,
+    14_U256,
+    5188850460322332672_U256,
+    5188850464617299968_U256,
+    5188850468912267264_U256,
+    5188850473207234560_U256,
+    5188850477502201856_U256,
+    5188850481797169152_U256,
+    5188850486092136448_U256,
+    5188850490387103744_U256,
+    5188850494682071040_U256,
+    5188850498977038336_U256,
+    5188850503272005632_U256,
+    2345108766317314046_U256,
+    146226256843603965_U256,
+    5_U256,
+    4613797087195135996_U256,
+    0_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    1_U256,
+    5201798304953761792_U256,
+    5202079779930537980_U256,
+    4634344751905079295_U256,
+    5193354047062507520_U256,
+    5198983563776458752_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020480_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020468_U256,
+    2345108766317314046_U256,
+    146226256843603965_U256,
+    5_U256,
+    5191102230068953088_U256,
+    5191102234363920384_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    1_U256,
+    5191102230068953088_U256,
+    5191102234363920384_U256,
+    5191102238658887680_U256,
+    5191102242953854976_U256,
+    5193354038472572928_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020290_U256,
+    5191102238658887680_U256,
+    5191102242953854976_U256,
+    5198983563776458752_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020480_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020463_U256,
+    2345108766317314046_U256,
+    4612671182993129469_U256,
+    5198983563776393216_U256,
+    1_U256,
+    2345108766317314046_U256,
+    5191102238658887680_U256,
+    5199827967231950845_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020475_U256,
+    2345108766317314046_U256,
+    5191102238658887680_U256,
+    5191102242953854976_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020470_U256,
+    5191102242953854976_U256,
+    5191102247248822272_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020470_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    1_U256,
+    5191102230068953088_U256,
+    5191102260133724160_U256,
+    5198983563776393216_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020480_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020466_U256,
+    5209116658642944000_U256,
+    5202361254906855424_U256,
+    4612108233039904765_U256,
+    5193354047062507520_U256,
+    5193354051357474816_U256,
+    2345108766317314046_U256,
+    4612671182993063932_U256,
+    4612671187288031229_U256,
+    5198983563776327680_U256,
+    3_U256,
+    5188850468909711360_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    2_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020010_U256,
+    4613797087195136000_U256,
+    0_U256,
+    4613797087195136001_U256,
+    0_U256,
+    5193354051357474816_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    2_U256,
+    5191102234363920384_U256,
+    5191102242953854976_U256,
+    5191102247248822272_U256,
+    5188850460319776768_U256,
+    1226245742482522112_U256,
+    16_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872019994_U256,
+    4617174769735794688_U256,
+    5188850464614744064_U256,
+    4623367219223429121_U256,
+    5193354038472572928_U256,
+    5193354042767540224_U256,
+    2345108766317314046_U256,
+    5191102242953854976_U256,
+    5188850460319907840_U256,
+    5188850464614875136_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020446_U256,
+    2345108766317314046_U256,
+    146226256843603964_U256,
+    5_U256,
+    5191102234363920384_U256,
+    5191102247248822272_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    1_U256,
+    5198983563776393216_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020480_U256,
+    4626181977580208128_U256,
+    5191102238658887680_U256,
+    5191102234363920384_U256,
+    5191102247248822272_U256,
+    5202079771340603392_U256,
+    4611826758063063038_U256,
+    5188287510366420992_U256,
+    4611826762357964799_U256,
+    5198420613822906368_U256,
+    1_U256,
+    5198420613822906368_U256,
+    3_U256,
+    5188287518956224512_U256,
+    145944781866893307_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020472_U256,
+    2345108766317314046_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020424_U256,
+    5191102238658887680_U256,
+    5193354051357474816_U256,
+    5191102242953854976_U256,
+    5191102247248822272_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020428_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020442_U256,
+    2345108766317314046_U256,
+    146226256843603965_U256,
+    3_U256,
+    2345108766317314046_U256,
+    5191102238658887680_U256,
+    5191102242953854976_U256,
+    5188287510366617600_U256,
+    4611826758063063039_U256,
+    5198420613823037441_U256,
+    1_U256,
+    5198420613823037441_U256,
+    1_U256,
+    722405534170316798_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020475_U256,
+    4623648689905041407_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    14_U256,
+    5191102260133724160_U256,
+    5191102208594116608_U256,
+    5191102212889083904_U256,
+    5191102217184051200_U256,
+    5191102221479018496_U256,
+    5191102225773985792_U256,
+    5191102230068953088_U256,
+    5191102234363920384_U256,
+    5191102238658887680_U256,
+    5191102242953854976_U256,
+    5191102247248822272_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020220_U256,
+    4617174743965990913_U256,
+    4617174748260958210_U256,
+    4617174752555925507_U256,
+    4617174756850892804_U256,
+    4617174761145860101_U256,
+    4617174765440827398_U256,
+    4617174769735794695_U256,
+    4617174774030761992_U256,
+    4617174778325729289_U256,
+    4617174735376056330_U256,
+    4614922926972436492_U256,
+    5191102204299149312_U256,
+    5193354012702769152_U256,
+    5191102307378364416_U256,
+    1226245742482522112_U256,
+    75_U256,
+    4617174774030761997_U256,
+    5198420613823102976_U256,
+    1_U256,
+    5193354051357474816_U256,
+    5191102285903527936_U256,
+    5191102264428691456_U256,
+    5189976364521848832_U256,
+    0_U256,
+    5198983563776655360_U256,
+    1_U256,
+    5191102307378364416_U256,
+    5188850460320104448_U256,
+    5191102311673331712_U256,
+    1226245742482522112_U256,
+    16_U256,
+    4617174778325729290_U256,
+    4612389708017532926_U256,
+    5193354034177605632_U256,
+    5193354034177605632_U256,
+    5193354038472572928_U256,
+    5191102268723658752_U256,
+    5191102273018626048_U256,
+    5191102277313593344_U256,
+    5191102281608560640_U256,
+    5193354012702769152_U256,
+    5191102290198495232_U256,
+    5191102294493462528_U256,
+    5191102298788429824_U256,
+    2345108766317314046_U256,
+    146226256843603964_U256,
+    9_U256,
+    5191102212889083904_U256,
+    5191102217184051200_U256,
+    5191102221479018496_U256,
+    5191102225773985792_U256,
+    5191102230068953088_U256,
+    5191102234363920384_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    0_U256,
+    290341444919459839_U256,
+    1_U256,
+    145944781866893311_U256,
+    14_U256,
+    5191102212889083904_U256,
+    5191102217184051200_U256,
+    5191102221479018496_U256,
+    5191102225773985792_U256,
+    5191102230068953088_U256,
+    5191102234363920384_U256,
+    5191102238658887680_U256,
+    5191102247248822272_U256,
+    1226245742482522112_U256,
+    39_U256,
+    74168662805676031_U256,
+    12_U256,
+    5191102212889083904_U256,
+    5191102217184051200_U256,
+    5191102221479018496_U256,
+    5191102225773985792_U256,
+    5191102230068953088_U256,
+    5191102234363920384_U256,
+    5191102238658887680_U256,
+    5191102247248822272_U256,
+    1226245742482522112_U256,
+    74_U256,
+    5191102238658887680_U256,
+    5198983563776393216_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020480_U256,
+    5191102247248822272_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020440_U256,
+    2345108766317314046_U256,
+    5188850460319907840_U256,
+    4612389708016353279_U256,
+    5188850473204809728_U256,
+    4612389712311320575_U256,
+    5191102242953854976_U256,
+    5188850468909842432_U256,
+    5188850464614875136_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020344_U256,
+    4612389716606287871_U256,
+    5198983563776327680_U256,
+    3_U256,
+    5193354047062507520_U256,
+    2345108766317314046_U256,
+    5198983563776458752_U256,
+    2_U256,
+    5191102247248822272_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    4_U256,
+    5191102225773985792_U256,
+    5191102264428691456_U256,
+    5191102260133724160_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872019877_U256,
+    5191102238658887680_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020469_U256,
+    5189976364521848832_U256,
+    4_U256,
+    4611826758063128575_U256,
+    5191102230068953088_U256,
+    5188850468909776896_U256,
+    5189976364521848832_U256,
+    1_U256,
+    5188850464614809600_U256,
+    5188287514661257216_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020237_U256,
+    5188850460319842304_U256,
+    4611826758060965887_U256,
+    4611826762355933145_U256,
+    4622241336494227458_U256,
+    2_U256,
+    4614922982807011331_U256,
+    5191102217184051200_U256,
+    5191102221479018496_U256,
+    5193353879558782976_U256,
+    5193354034177605632_U256,
+    5191102234363920384_U256,
+    5198983563776655360_U256,
+    1_U256,
+    5191102242953854976_U256,
+    5188850460320104448_U256,
+    5191102247248822272_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020382_U256,
+    4623648711380271103_U256,
+    5193354034177605632_U256,
+    5193354034177605632_U256,
+    5193354034177605632_U256,
+    5193354034177605632_U256,
+    5193354034177605632_U256,
+    5191102268723658752_U256,
+    2345108766317314046_U256,
+    290341444919459839_U256,
+    4_U256,
+    5191102238658887680_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020427_U256,
+    4617174774030761984_U256,
+    5188287514661584896_U256,
+    5188850473204744192_U256,
+    5201798304953827326_U256,
+    145944781866893311_U256,
+    16_U256,
+    4613797087195136003_U256,
+    4_U256,
+    5188287510366420992_U256,
+    4623648719970074623_U256,
+    4612671182993391618_U256,
+    5198983563776655360_U256,
+    1_U256,
+    5191102247248822272_U256,
+    5189976364521848832_U256,
+    3_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020276_U256,
+    74168662805676031_U256,
+    6_U256,
+    4612108233039708161_U256,
+    4612108237334675458_U256,
+    4613797087195136003_U256,
+    0_U256,
+    4612671182992736257_U256,
+    4612671187287703554_U256,
+    5198983563776000000_U256,
+    2_U256,
+    5200109472273432576_U256,
+    5198983563776720896_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020479_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020261_U256,
+    5198983563776000000_U256,
+    2_U256,
+    5198983563776720896_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020479_U256,
+    5200109472273432576_U256,
+    5198983563776720896_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020479_U256,
+    5201798296363630592_U256,
+    5191102221479018496_U256,
+    5191102225773985792_U256,
+    5191102230068953088_U256,
+    5198983563776262144_U256,
+    1_U256,
+    5201798283478532096_U256,
+    2345108766317314046_U256,
+    5191102204299149312_U256,
+    5191102208594116608_U256,
+    5191102212889083904_U256,
+    5191102217184051200_U256,
+    5191102221479018496_U256,
+    5191102225773985792_U256,
+    5191102230068953088_U256,
+    5191102234363920384_U256,
+    5191102238658887680_U256,
+    5191102242953854976_U256,
+    5191102247248822272_U256,
+    1226245742482522112_U256,
+    3618502788666131213697322783095070105623107215331596699973092056135872020248_U256,
+    2345108766317314046_U256,
+]);
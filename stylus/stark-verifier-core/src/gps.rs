@@ -0,0 +1,512 @@
+use alloc::vec::Vec;
+
+use stylus_sdk::{
+    alloy_primitives::{uint, FixedBytes, U256},
+    crypto::keccak,
+};
+use utils::{require, require::*};
+
+#[path = "gps-bootloader.rs"]
+pub mod bootloader;
+#[path = "gps-consts.rs"]
+pub mod consts;
+
+use bootloader::{
+    APPLICATION_BOOTLOADER_PROGRAM_HASH, BOOTLOADER_PROGRAM, SIMPLE_BOOTLOADER_PROGRAM_HASH,
+};
+use consts::{page_info::*, public_input_offsets};
+
+pub const N_BUILTINS: usize = 11;
+const N_MAIN_ARGS: usize = N_BUILTINS;
+const N_MAIN_RETURN_VALUES: usize = N_BUILTINS;
+
+pub const METADATA_TASKS_OFFSET: usize = 1;
+pub const METADATA_OFFSET_TASK_OUTPUT_SIZE: usize = 0;
+pub const METADATA_OFFSET_TASK_PROGRAM_HASH: usize = 1;
+pub const METADATA_OFFSET_TASK_N_TREE_PAIRS: usize = 2;
+pub const METADATA_TASK_HEADER_SIZE: usize = 3;
+
+const METADATA_OFFSET_TREE_PAIR_N_PAGES: usize = 0;
+const METADATA_OFFSET_TREE_PAIR_N_NODES: usize = 1;
+
+/// Node‑stack bookkeeping.
+const NODE_STACK_OFFSET_HASH: usize = 0;
+const NODE_STACK_OFFSET_END: usize = 1;
+// The size of each node in the node stack.
+const NODE_STACK_ITEM_SIZE: usize = 2;
+
+const HASHED_SUPPORTED_VERIFIERS: U256 =
+    uint!(988080400528720010398639244351885480706475299330001427790099377094461351470_U256);
+pub use utils::felt252::K_MODULUS;
+
+const REGULAR_PAGE: U256 = U256::ZERO;
+
+// A fact registered by the GPS statement verifier for one task of the bootloader.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GpsFact {
+    // keccak(program_hash, program_output_fact), the fact that is registered.
+    pub fact: FixedBytes<32>,
+    pub program_output_fact: FixedBytes<32>,
+    // Hashes of the memory pages the program output fact was computed from.
+    pub pages_hashes: Vec<FixedBytes<32>>,
+}
+
+// Returns the public memory pages part of the cairo public input, that is, n_pages followed by
+// the page info of every page and the cumulative products.
+pub fn get_public_memory_pages(
+    cairo_public_input: &[U256],
+    public_memory_offset: U256,
+) -> Result<&[U256], Vec<u8>> {
+    let public_memory_offset: usize = public_memory_offset
+        .try_into()
+        .map_err(|_| Vec::from(InvalidCairoAuxInput {}))?;
+
+    require!(cairo_public_input.len() > public_memory_offset, InvalidCairoAuxInput {});
+
+    let public_memory_pages = &cairo_public_input[public_memory_offset..];
+    let n_pages: usize = public_memory_pages[0]
+        .try_into()
+        .map_err(|_| Vec::from(InvalidNumberOfMemoryPages { nPages: public_memory_pages[0] }))?;
+    require!(n_pages < 10000, InvalidNumberOfMemoryPages { nPages: public_memory_pages[0] });
+
+    require!(public_memory_pages.len() == n_pages * (PAGE_INFO_SIZE + 1), InvalidCairoAuxInput {});
+    Ok(public_memory_pages)
+}
+
+// Computes the fact the memory page fact registry registers for a regular page, see
+// MemoryPageFactRegistry.sol. Returns the fact, the hash of the memory pairs and their product.
+pub fn compute_regular_memory_page_fact(
+    memory_pairs: &[U256],
+    z: U256,
+    alpha: U256,
+    prime: U256,
+) -> (FixedBytes<32>, U256, U256) {
+    let mut prod = U256::from(1);
+    let mut memory_data = Vec::with_capacity(memory_pairs.len() * 32);
+
+    for pair in memory_pairs.chunks(2) {
+        let address_value_lin_comb = pair[0].add_mod(pair[1].mul_mod(alpha, prime), prime);
+        prod = prod.mul_mod(z + prime - address_value_lin_comb, prime);
+
+        memory_data.extend_from_slice(&pair[0].to_be_bytes::<32>());
+        memory_data.extend_from_slice(&pair[1].to_be_bytes::<32>());
+    }
+    let memory_hash = U256::from_be_bytes::<32>(keccak(&memory_data).into());
+
+    let mut hash_buffer = Vec::with_capacity(256);
+    hash_buffer.extend_from_slice(&REGULAR_PAGE.to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&prime.to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&U256::from(memory_pairs.len() / 2).to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&z.to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&alpha.to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&prod.to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&memory_hash.to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&[0; 32]);
+
+    (keccak(&hash_buffer), memory_hash, prod)
+}
+
+// Checks that the main page (page 0) declared in the public input matches the one computed
+// from the bootloader program and registered in the memory page fact registry.
+pub fn verify_main_page_info(
+    public_memory_pages: &[U256],
+    n_pages: usize,
+    public_memory_length: U256,
+    memory_hash: U256,
+    product: U256,
+) -> Result<(), Vec<u8>> {
+    require!(
+        public_memory_pages[PAGE_INFO_SIZE_OFFSET] == public_memory_length,
+        InvalidMainPageSize { expected: public_memory_length, actual: public_memory_pages[PAGE_INFO_SIZE_OFFSET] }
+    );
+    require!(
+        public_memory_pages[PAGE_INFO_HASH_OFFSET] == memory_hash,
+        InvalidMainPageHash { expected: memory_hash, actual: public_memory_pages[PAGE_INFO_HASH_OFFSET] }
+    );
+    require!(
+        public_memory_pages[n_pages * PAGE_INFO_SIZE] == product,
+        InvalidMainPageProduct { expected: product, actual: public_memory_pages[n_pages * PAGE_INFO_SIZE] }
+    );
+    Ok(())
+}
+
+// Writes the (address, value) pairs of the main page: the bootloader program, its arguments
+// and return values and the header of the program output.
+pub fn build_public_memory_main_page(
+    task_metadata: &[U256],
+    aux_input: &[U256],
+    selected_builtins: &mut U256,
+) -> Result<Vec<U256>, Vec<u8>> {
+    let n_tasks: usize = task_metadata[0]
+        .try_into()
+        .map_err(|_| Vec::from(InvalidNumberOfTasks { nTasks: task_metadata[0] }))?;
+    require!(n_tasks < 2usize.pow(30), InvalidNumberOfTasks { nTasks: task_metadata[0] });
+
+    let public_memory_length = BOOTLOADER_PROGRAM.len()
+        + 2
+        + N_MAIN_ARGS
+        + N_MAIN_RETURN_VALUES
+        + 3
+        + 1
+        + 2 * n_tasks;
+
+    let mut public_memory: Vec<U256> = vec![U256::ZERO; 2 * public_memory_length];
+    let mut offset = 0;
+
+    for (i, value) in BOOTLOADER_PROGRAM.iter().enumerate() {
+        public_memory[offset] = U256::from(i + public_input_offsets::INITIAL_PC);
+        public_memory[offset + 1] = *value;
+        offset += 2;
+    }
+
+    {
+        let initial_fp = aux_input[public_input_offsets::OFFSET_EXECUTION_BEGIN_ADDR];
+        require!(initial_fp.gt(&U256::from(2)), InvalidExecutionBeginAddress { initialFp: initial_fp });
+
+        public_memory[offset] = initial_fp - U256::from(2);
+        public_memory[offset + 1] = initial_fp;
+        // Make sure [initial_fp - 1] = 0.
+        public_memory[offset + 2] = initial_fp - U256::ONE;
+        public_memory[offset + 3] = U256::ZERO;
+        offset += 4;
+
+        let return_values_address =
+            aux_input[public_input_offsets::OFFSET_EXECUTION_STOP_PTR] - U256::from(N_BUILTINS);
+        let mut builtin_segment_info_offset = public_input_offsets::OFFSET_OUTPUT_BEGIN_ADDR;
+
+        for i in 0..N_BUILTINS {
+            // Write argument address.
+            public_memory[offset] = initial_fp + U256::from(i);
+            let return_value_offset = offset + 2 * N_BUILTINS;
+
+            // Write return value address.
+            public_memory[return_value_offset] = return_values_address + U256::from(i);
+
+            // Write values.
+            if selected_builtins.bit(0) {
+                // Set the argument to the builtin start pointer.
+                public_memory[offset + 1] = aux_input[builtin_segment_info_offset];
+                // Set the return value to the builtin stop pointer.
+                public_memory[return_value_offset + 1] =
+                    aux_input[builtin_segment_info_offset + 1];
+                builtin_segment_info_offset += 2;
+            } else {
+                // Builtin is not present in layout, set the argument value and return value to 0.
+                public_memory[offset + 1] = U256::ZERO;
+                public_memory[return_value_offset + 1] = U256::ZERO;
+            }
+            offset += 2;
+            *selected_builtins >>= 1;
+        }
+        require!(*selected_builtins == U256::ZERO, SelectedBuiltinsVectorTooLong {});
+
+        // Skip the return values which were already written.
+        offset += 2 * N_BUILTINS;
+    }
+
+    // Program output.
+    {
+        let mut output_address = aux_input[public_input_offsets::OFFSET_OUTPUT_BEGIN_ADDR];
+        // Force that memory[outputAddress] and memory[outputAddress + 1] contain the
+        // bootloader config (which is 2 words size).
+        public_memory[offset] = output_address;
+
+        public_memory[offset + 1] = SIMPLE_BOOTLOADER_PROGRAM_HASH;
+        public_memory[offset + 2] = output_address + U256::ONE;
+        public_memory[offset + 3] = APPLICATION_BOOTLOADER_PROGRAM_HASH;
+        // Force that memory[outputAddress + 3] = nTasks.
+        public_memory[offset + 4] = output_address + U256::from(2);
+        public_memory[offset + 5] = HASHED_SUPPORTED_VERIFIERS;
+        public_memory[offset + 6] = output_address + U256::from(3);
+        public_memory[offset + 7] = U256::from(n_tasks);
+
+        offset += 8;
+        output_address += U256::from(4);
+
+        let mut task_metadata_slice = &task_metadata[METADATA_TASKS_OFFSET..];
+        for _task in 0..n_tasks {
+            let output_size = task_metadata_slice[METADATA_OFFSET_TASK_OUTPUT_SIZE];
+            require!(
+                U256::from(2) <= output_size && output_size < U256::from(1u64 << 30),
+                InvalidTaskOutputSize { outputSize: output_size }
+            );
+            let program_hash = task_metadata_slice[METADATA_OFFSET_TASK_PROGRAM_HASH];
+            let n_tree_pairs: usize = task_metadata_slice[METADATA_OFFSET_TASK_N_TREE_PAIRS]
+                .try_into()
+                .map_err(|_| Vec::from(InvalidTaskMetadata {}))?;
+            // The number of pairs in the Merkle tree structure.
+            require!((1..2usize.pow(20)).contains(&n_tree_pairs), InvalidTaskMetadata {});
+            // Force that memory[outputAddress] = outputSize.
+            public_memory[offset] = output_address;
+            public_memory[offset + 1] = output_size;
+            // Force that memory[outputAddress + 1] = programHash.
+            public_memory[offset + 2] = output_address + U256::from(1);
+            public_memory[offset + 3] = program_hash;
+            offset += 4;
+            output_address += output_size;
+            let start_index = METADATA_TASK_HEADER_SIZE + 2 * n_tree_pairs;
+            task_metadata_slice = &task_metadata_slice[start_index..];
+        }
+        require!(task_metadata_slice.is_empty(), InvalidTaskMetadata {});
+
+        require!(
+            aux_input[public_input_offsets::OFFSET_OUTPUT_STOP_PTR] == output_address,
+            OutputSizeMismatch {
+                expected: output_address,
+                actual: aux_input[public_input_offsets::OFFSET_OUTPUT_STOP_PTR],
+            }
+        );
+    }
+
+    // All the Cairo public inputs must be written.
+    require!(
+        public_memory.len() == offset,
+        PublicMemoryLengthMismatch { expected: U256::from(public_memory.len()), actual: U256::from(offset) }
+    );
+
+    Ok(public_memory)
+}
+
+// Computes the facts the GPS statement verifier registers, one per task, from the merkle tree
+// structure of the program output pages described in the task metadata.
+pub fn compute_gps_facts(
+    task_metadata: &[U256],
+    public_memory_pages: &[U256],
+    output_start_address: U256,
+) -> Result<Vec<GpsFact>, Vec<u8>> {
+    let total_num_pages: usize = public_memory_pages[0]
+        .try_into()
+        .map_err(|_| Vec::from(InvalidNumberOfMemoryPages { nPages: public_memory_pages[0] }))?;
+    let n_tasks: usize = task_metadata[0]
+        .try_into()
+        .map_err(|_| Vec::from(InvalidNumberOfTasks { nTasks: task_metadata[0] }))?;
+
+    let mut facts = Vec::with_capacity(n_tasks);
+    // node_stack capacity bounded by total_num_pages * NODE_STACK_ITEM_SIZE.
+    let mut node_stack: Vec<U256> = vec![U256::ZERO; total_num_pages * NODE_STACK_ITEM_SIZE];
+    let mut cur_addr = output_start_address + U256::from(6);
+    let mut task_metadata_offset = METADATA_TASKS_OFFSET;
+    let mut page_info_index: usize = PAGE_INFO_SIZE;
+    for _task in 0..n_tasks {
+        let mut cur_offset = U256::ZERO;
+        // Hashes of the memory pages that are relevant for this fact.
+        let mut pages_hashes: Vec<FixedBytes<32>> = Vec::new();
+
+        let n_tree_pairs: usize = task_metadata
+            [task_metadata_offset + METADATA_OFFSET_TASK_N_TREE_PAIRS]
+            .try_into()
+            .map_err(|_| Vec::from(InvalidTaskMetadata {}))?;
+        let mut node_stack_len: usize = 0;
+
+        for tree_pair in 0..n_tree_pairs {
+            let n_pages: usize = task_metadata[task_metadata_offset
+                + METADATA_TASK_HEADER_SIZE
+                + 2 * tree_pair
+                + METADATA_OFFSET_TREE_PAIR_N_PAGES]
+                .try_into()
+                .map_err(|_| Vec::from(InvalidTaskMetadata {}))?;
+
+            require!(n_pages < 1usize << 20, InvalidTaskMetadata {});
+            for _page in 0..n_pages {
+                // Push page to node stack
+                let page_addr = public_memory_pages[page_info_index];
+                let page_size = public_memory_pages[page_info_index + PAGE_INFO_SIZE_OFFSET];
+                let page_hash = public_memory_pages[page_info_index + PAGE_INFO_HASH_OFFSET];
+
+                require!(
+                    page_size < MAX_PAGE_SIZE && page_addr == cur_addr,
+                    InvalidMemoryPage { pageAddr: page_addr, pageSize: page_size }
+                );
+
+                let base = NODE_STACK_ITEM_SIZE * node_stack_len;
+                node_stack[base + NODE_STACK_OFFSET_END] = page_size + cur_offset;
+                node_stack[base + NODE_STACK_OFFSET_HASH] = page_hash;
+
+                pages_hashes.push(FixedBytes(page_hash.to_be_bytes()));
+                node_stack_len += 1;
+                cur_addr += page_size;
+                cur_offset += page_size;
+                page_info_index += PAGE_INFO_SIZE;
+            }
+
+            let n_nodes: usize = task_metadata[task_metadata_offset
+                + METADATA_TASK_HEADER_SIZE
+                + 2 * tree_pair
+                + METADATA_OFFSET_TREE_PAIR_N_NODES]
+                .try_into()
+                .map_err(|_| Vec::from(InvalidTaskMetadata {}))?;
+            if n_nodes != 0 {
+                node_stack_len = construct_node(&mut node_stack, node_stack_len, n_nodes)?;
+            }
+        }
+
+        // The node stack must contain exactly one item.
+        require!(node_stack_len == 1, InvalidTaskMetadata {});
+        let program_hash = task_metadata[task_metadata_offset + METADATA_OFFSET_TASK_PROGRAM_HASH];
+
+        require!(
+            node_stack[NODE_STACK_OFFSET_END] + U256::from(2)
+                == task_metadata[task_metadata_offset + METADATA_OFFSET_TASK_OUTPUT_SIZE],
+            OutputSizeMismatch {
+                expected: task_metadata[task_metadata_offset + METADATA_OFFSET_TASK_OUTPUT_SIZE],
+                actual: node_stack[NODE_STACK_OFFSET_END] + U256::from(2),
+            }
+        );
+
+        let program_output_fact = node_stack[NODE_STACK_OFFSET_HASH];
+        let fact = keccak(
+            [
+                program_hash.to_be_bytes::<32>(),
+                program_output_fact.to_be_bytes::<32>(),
+            ]
+            .concat(),
+        );
+
+        // Update taskMetadataOffset.
+        task_metadata_offset += METADATA_TASK_HEADER_SIZE + 2 * n_tree_pairs;
+
+        facts.push(GpsFact {
+            fact,
+            program_output_fact: FixedBytes(program_output_fact.to_be_bytes()),
+            pages_hashes,
+        });
+
+        // Move curAddr to the output of the next task (skipping the size and hash fields).
+        cur_addr += U256::from(2);
+    }
+    Ok(facts)
+}
+
+fn construct_node(
+    node_stack: &mut [U256],
+    node_stack_len: usize,
+    n_nodes: usize,
+) -> Result<usize, Vec<u8>> {
+    require!(n_nodes <= node_stack_len, InvalidTaskMetadata {});
+
+    // End-offset of the right-most child = end of the parent.
+    let new_node_end =
+        node_stack[(node_stack_len - 1) * NODE_STACK_ITEM_SIZE + NODE_STACK_OFFSET_END];
+
+    let new_stack_len = node_stack_len - n_nodes;
+
+    // ---  build byte-buffer to hash  ----------------------------------------------------------
+    let mut buf = Vec::with_capacity(n_nodes * NODE_STACK_ITEM_SIZE * 32);
+    for i in 0..n_nodes {
+        let base = (new_stack_len + i) * NODE_STACK_ITEM_SIZE;
+        buf.extend_from_slice(&node_stack[base + NODE_STACK_OFFSET_HASH].to_be_bytes::<32>());
+        buf.extend_from_slice(&node_stack[base + NODE_STACK_OFFSET_END].to_be_bytes::<32>());
+    }
+
+    let new_node_hash = U256::from_be_bytes::<32>(keccak(&buf).into()) + U256::ONE;
+    // -----------------------------------------------------------------------------------------
+
+    // Over-write the first child slot with the parent.
+    let parent_base = new_stack_len * NODE_STACK_ITEM_SIZE;
+    node_stack[parent_base + NODE_STACK_OFFSET_HASH] = new_node_hash;
+    node_stack[parent_base + NODE_STACK_OFFSET_END] = new_node_end;
+
+    Ok(new_stack_len + 1) // new length after pushing parent
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_input(input: &str) -> Vec<U256> {
+        input
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(',')
+            .map(|val| U256::from_str_radix(val.trim(), 10).unwrap())
+            .collect()
+    }
+
+    fn task_metadata() -> Vec<U256> {
+        parse_input(include_str!("../../../inputs/gps_input_task.txt"))
+    }
+
+    fn aux_input() -> Vec<U256> {
+        parse_input(include_str!("../../../inputs/gps_input_aux.txt"))
+    }
+
+    // See layout_specific::get_layout_info.
+    const PUBLIC_MEMORY_OFFSET: usize = 21;
+    const SELECTED_BUILTINS: U256 = uint!(151_U256);
+
+    // Computes the main page the way the memory page fact registry does when registering it,
+    // and checks it against the page info declared in aux_input.
+    fn verify_main_page(aux_input: &[U256]) -> Result<(), Vec<u8>> {
+        let mut selected_builtins = SELECTED_BUILTINS;
+        let public_memory =
+            build_public_memory_main_page(&task_metadata(), aux_input, &mut selected_builtins)?;
+        let z = aux_input[aux_input.len() - 2];
+        let alpha = aux_input[aux_input.len() - 1];
+        let (_, memory_hash, product) =
+            compute_regular_memory_page_fact(&public_memory, z, alpha, K_MODULUS);
+
+        let public_memory_pages = get_public_memory_pages(
+            &aux_input[..aux_input.len() - 2],
+            U256::from(PUBLIC_MEMORY_OFFSET),
+        )?;
+        let n_pages: usize = public_memory_pages[0].to();
+        verify_main_page_info(
+            public_memory_pages,
+            n_pages,
+            U256::from(public_memory.len() / 2),
+            memory_hash,
+            product,
+        )
+    }
+
+    #[test]
+    fn test_verify_main_page_info() {
+        if let Err(e) = verify_main_page(&aux_input()) {
+            panic!("Error: {:?}", StarkError::decode(&e));
+        }
+    }
+
+    #[test]
+    fn test_verify_main_page_info_tampered_hash() {
+        let mut aux_input = aux_input();
+        aux_input[PUBLIC_MEMORY_OFFSET + PAGE_INFO_HASH_OFFSET] += U256::ONE;
+        let err = verify_main_page(&aux_input).unwrap_err();
+        assert!(matches!(StarkError::decode(&err), Some(StarkError::InvalidMainPageHash(_))));
+    }
+
+    #[test]
+    fn test_verify_main_page_info_tampered_product() {
+        let mut aux_input = aux_input();
+        let n_pages: usize = aux_input[PUBLIC_MEMORY_OFFSET].to();
+        aux_input[PUBLIC_MEMORY_OFFSET + n_pages * PAGE_INFO_SIZE] += U256::ONE;
+        let err = verify_main_page(&aux_input).unwrap_err();
+        assert!(matches!(StarkError::decode(&err), Some(StarkError::InvalidMainPageProduct(_))));
+    }
+
+    #[test]
+    fn test_compute_gps_facts() {
+        let task_metadata = task_metadata();
+        let aux_input = aux_input();
+        let public_memory_pages = &aux_input[PUBLIC_MEMORY_OFFSET..aux_input.len() - 2];
+        let output_start_address = aux_input[public_input_offsets::OFFSET_OUTPUT_BEGIN_ADDR];
+
+        let facts =
+            compute_gps_facts(&task_metadata, public_memory_pages, output_start_address).unwrap();
+        assert_eq!(facts.len(), task_metadata[0].to::<usize>());
+
+        // Every page but the main page belongs to exactly one task, in order.
+        let n_pages: usize = public_memory_pages[0].to();
+        let pages_hashes: Vec<FixedBytes<32>> =
+            facts.iter().flat_map(|fact| fact.pages_hashes.clone()).collect();
+        let expected_hashes: Vec<FixedBytes<32>> = (1..n_pages)
+            .map(|page| {
+                FixedBytes(
+                    public_memory_pages[page * PAGE_INFO_SIZE + PAGE_INFO_HASH_OFFSET]
+                        .to_be_bytes(),
+                )
+            })
+            .collect();
+        assert_eq!(pages_hashes, expected_hashes);
+    }
+}
@@ -0,0 +1,209 @@
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::{FixedBytes, U256};
+
+use crate::constraint_poly;
+use crate::fri_statement_verifier::FriVerifier;
+use crate::layout_specific::LayoutSpecific;
+use crate::oods::Oods;
+use crate::periodic_columns::{self, PeriodicColumn};
+use crate::proof::ProofWords;
+use crate::stark_verifier::StarkVerifier;
+use crate::verifier_init;
+
+// The facts the verifier expects to be registered by other contracts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FactKind {
+    // Registered by the memory page fact registry (mpfr).
+    MemoryPage,
+    // Registered by merkle-statement.
+    MerkleStatement,
+    // Registered by fri-statement.
+    FriStatement,
+}
+
+// Answers the fact registry lookups of an in-process verification.
+pub trait FactOracle {
+    fn is_valid(&mut self, kind: FactKind, fact: FixedBytes<32>) -> bool;
+}
+
+impl<F: FnMut(FactKind, FixedBytes<32>) -> bool> FactOracle for F {
+    fn is_valid(&mut self, kind: FactKind, fact: FixedBytes<32>) -> bool {
+        self(kind, fact)
+    }
+}
+
+// Runs every stage of the verifier in the current process. The stages are the ones the contracts
+// run, only the fact registries are replaced by the oracle.
+pub struct InProcessVerifier<F> {
+    num_security_bits: U256,
+    facts: F,
+}
+
+impl<F: FactOracle> InProcessVerifier<F> {
+    pub fn new(num_security_bits: U256, facts: F) -> Self {
+        Self { num_security_bits, facts }
+    }
+
+    pub fn facts(&self) -> &F {
+        &self.facts
+    }
+
+    pub fn into_facts(self) -> F {
+        self.facts
+    }
+}
+
+impl<F: FactOracle> LayoutSpecific for InProcessVerifier<F> {
+    fn compute_periodic_column(&mut self, column: PeriodicColumn, x: U256) -> Result<U256, Vec<u8>> {
+        Ok(periodic_columns::compute(column, x))
+    }
+}
+
+impl<F: FactOracle> StarkVerifier for InProcessVerifier<F> {
+    fn init_verifier_params(
+        &mut self,
+        public_input: &[U256],
+        proof_params: &[U256],
+    ) -> Result<(Vec<U256>, Vec<U256>), Vec<u8>> {
+        verifier_init::init_verifier_params(public_input, proof_params, self.num_security_bits)
+    }
+
+    fn is_memory_page_fact_valid(&mut self, fact: FixedBytes<32>) -> Result<bool, Vec<u8>> {
+        Ok(self.facts.is_valid(FactKind::MemoryPage, fact))
+    }
+
+    fn compute_constraint_poly(&mut self, calldata: &[U256]) -> Result<U256, Vec<u8>> {
+        constraint_poly::compute(calldata)
+    }
+
+    fn verify_fri(
+        &mut self,
+        proof: ProofWords,
+        ctx: &[U256],
+        fri_step_sizes: &[U256],
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        let mut ctx = ctx.to_vec();
+        FriVerifier::verify(self, proof, &mut ctx, fri_step_sizes)?;
+        Ok(ctx)
+    }
+}
+
+impl<F: FactOracle> FriVerifier for InProcessVerifier<F> {
+    fn compute_oods(&mut self, ctx: &[U256]) -> Result<Vec<U256>, Vec<u8>> {
+        Oods::compute(ctx)
+    }
+
+    fn is_merkle_statement_valid(&mut self, statement: FixedBytes<32>) -> Result<bool, Vec<u8>> {
+        Ok(self.facts.is_valid(FactKind::MerkleStatement, statement))
+    }
+
+    fn is_fri_statement_valid(&mut self, statement: FixedBytes<32>) -> Result<bool, Vec<u8>> {
+        Ok(self.facts.is_valid(FactKind::FriStatement, statement))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use utils::require::*;
+
+    fn parse_input(input: &str) -> Vec<U256> {
+        input
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(',')
+            .map(|val| U256::from_str_radix(val.trim(), 10).unwrap())
+            .collect()
+    }
+
+    // Decodes the abi encoded uint256[] returned by verifyProofExternal.
+    fn parse_abi_words(input: &str) -> Vec<U256> {
+        let bytes = hex::decode(input.trim().trim_start_matches("0x")).unwrap();
+        let words: Vec<U256> = bytes.chunks_exact(32).map(U256::from_be_slice).collect();
+        let len = words[1].to::<usize>();
+        words[2..2 + len].to_vec()
+    }
+
+    // The proof in inputs/ has 96 bits of security, see verifier-init.
+    const NUM_SECURITY_BITS: u64 = 96;
+
+    #[test]
+    fn test_verify_proof_matches_contracts() {
+        let proof_params = parse_input(include_str!("../../../inputs/proof_params.txt"));
+        let proof = parse_input(include_str!("../../../inputs/proof.txt"));
+        let public_input = parse_input(include_str!("../../../inputs/public_input.txt"));
+
+        // The inputs do not come with the Merkle and FRI statements, so accept every fact and
+        // only record the memory pages that were checked.
+        let mut memory_pages = vec![];
+        let mut verifier = InProcessVerifier::new(
+            U256::from(NUM_SECURITY_BITS),
+            |kind: FactKind, fact: FixedBytes<32>| {
+                if kind == FactKind::MemoryPage {
+                    memory_pages.push(fact);
+                }
+                true
+            },
+        );
+        let ctx = verifier
+            .verify_proof(&proof_params, (&proof).into(), &public_input)
+            .unwrap();
+
+        // The final ctx of the deployed contracts for the same inputs.
+        assert_eq!(ctx, parse_abi_words(include_str!("../../../inputs/expected_out.txt")));
+        assert_eq!(memory_pages.len(), public_input[21].to::<usize>());
+    }
+
+    #[test]
+    fn test_verify_compact_proof_in_place() {
+        let proof_params = parse_input(include_str!("../../../inputs/proof_params.txt"));
+        let proof = parse_input(include_str!("../../../inputs/proof.txt"));
+        let public_input = parse_input(include_str!("../../../inputs/public_input.txt"));
+
+        let proof_bytes = crate::compact::proof_bytes(&proof);
+        let mut verifier = InProcessVerifier::new(U256::from(NUM_SECURITY_BITS), |_, _| true);
+        let ctx = verifier
+            .verify_proof(&proof_params, ProofWords::Bytes(&proof_bytes), &public_input)
+            .unwrap();
+        assert_eq!(ctx, parse_abi_words(include_str!("../../../inputs/expected_out.txt")));
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_unregistered_fact() {
+        let proof_params = parse_input(include_str!("../../../inputs/proof_params.txt"));
+        let proof = parse_input(include_str!("../../../inputs/proof.txt"));
+        let public_input = parse_input(include_str!("../../../inputs/public_input.txt"));
+
+        let mut verifier = InProcessVerifier::new(
+            U256::from(NUM_SECURITY_BITS),
+            |kind: FactKind, _fact: FixedBytes<32>| kind != FactKind::FriStatement,
+        );
+        let err = verifier
+            .verify_proof(&proof_params, (&proof).into(), &public_input)
+            .unwrap_err();
+        assert!(matches!(StarkError::decode(&err), Some(StarkError::InvalidatedFriStatement(_))));
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_malformed_decommitment() {
+        let proof_params = parse_input(include_str!("../../../inputs/proof_params.txt"));
+        let mut proof = parse_input(include_str!("../../../inputs/proof.txt"));
+        let public_input = parse_input(include_str!("../../../inputs/public_input.txt"));
+        let mut verifier = InProcessVerifier::new(U256::from(NUM_SECURITY_BITS), |_, _| true);
+
+        // The last word holds the end of the hash of the output queue of the last inner layer.
+        let err = verifier
+            .verify_proof(&proof_params, proof[..proof.len() - 1].into(), &public_input)
+            .unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::ProofTruncated(ProofTruncated { section: 8, index: U256::from(6) }))
+        );
+
+        // Words after the decommitment are ignored, as by the Solidity verifier.
+        proof.push(U256::ZERO);
+        verifier.verify_proof(&proof_params, (&proof).into(), &public_input).unwrap();
+    }
+}
@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+use utils::{felt252::Felt252, require, require::InvalidPeriodicColumnValues};
+use stylus_sdk::alloy_primitives::U256;
+
+use crate::memory_map::*;
+use crate::periodic_columns::{PeriodicColumn, PeriodicColumnFamily};
+use crate::verifier_init::safe_div;
+
+pub const OFFSET_N_PUBLIC_MEMORY_PAGES: usize = 21;
+
+pub const OUTPUT_BUILTIN_BIT: usize = 0;
+pub const PEDERSEN_BUILTIN_BIT: usize = 1;
+pub const RANGE_CHECK_BUILTIN_BIT: usize = 2;
+pub const BITWISE_BUILTIN_BIT: usize = 4;
+pub const POSEIDON_BUILTIN_BIT: usize = 7;
+
+// Returns the offset of n_public_memory_pages in the public input and the builtins used by
+// this layout, see CairoVerifierContract.sol for the bit of each builtin.
+pub fn get_layout_info() -> (U256, U256) {
+    let public_memory_offset = U256::from(OFFSET_N_PUBLIC_MEMORY_PAGES);
+    let selected_builtins = U256::from(
+        (1 << OUTPUT_BUILTIN_BIT)
+            | (1 << PEDERSEN_BUILTIN_BIT)
+            | (1 << RANGE_CHECK_BUILTIN_BIT)
+            | (1 << BITWISE_BUILTIN_BIT)
+            | (1 << POSEIDON_BUILTIN_BIT),
+    );
+    (public_memory_offset, selected_builtins)
+}
+
+pub trait LayoutSpecific {
+
+    // Evaluates the periodic column at x. The contracts forward this to the column contracts.
+    fn compute_periodic_column(&mut self, column: PeriodicColumn, x: U256) -> Result<U256, Vec<u8>>;
+
+    // Evaluates all the columns of the family at x, in the order of `PeriodicColumnFamily::columns`.
+    // Defaults to one column at a time; the contracts can forward this to a batched contract.
+    fn compute_periodic_columns(&mut self, family: PeriodicColumnFamily, x: U256) -> Result<Vec<U256>, Vec<u8>> {
+        family.columns().iter().map(|&column| self.compute_periodic_column(column, x)).collect()
+    }
+
+    fn prepare_for_oods_check(&mut self, ctx: &mut [U256]) -> Result<(), Vec<u8>> {
+        let oods_point = ctx.oods_point();
+        let n_steps = U256::from(1) << ctx.log_n_steps();
+        let n_pedersen_hash_copies = safe_div(n_steps, U256::from(128))?;
+        let z_point_pow_pedersen = Felt252::new(oods_point).pow(n_pedersen_hash_copies).value();
+
+        let values = self.compute_periodic_columns(PeriodicColumnFamily::Pedersen, z_point_pow_pedersen)?;
+        store_periodic_columns(ctx, PeriodicColumnFamily::Pedersen, &values)?;
+
+        ctx[MM_DILUTED_CHECK__PERMUTATION__INTERACTION_ELM] = ctx.interaction_elements()[3];
+        ctx[MM_DILUTED_CHECK__INTERACTION_Z] = ctx.interaction_elements()[4];
+        ctx[MM_DILUTED_CHECK__INTERACTION_ALPHA] = ctx.interaction_elements()[5];
+        ctx[MM_DILUTED_CHECK__FINAL_CUM_VAL] = compute_diluted_cumulative_value(ctx);
+
+        let n_poseidon_hash_copies = safe_div(n_steps, U256::from(8))?;
+        let z_point_pow_poseidon = Felt252::new(oods_point).pow(n_poseidon_hash_copies).value();
+
+        let values = self.compute_periodic_columns(PeriodicColumnFamily::Poseidon, z_point_pow_poseidon)?;
+        store_periodic_columns(ctx, PeriodicColumnFamily::Poseidon, &values)?;
+
+        Ok(())
+    }
+}
+
+// The slot of the ctx that holds the value of the periodic column at the OODS point.
+pub fn periodic_column_slot(column: PeriodicColumn) -> usize {
+    match column {
+        PeriodicColumn::PedersenPointsX => MM_PERIODIC_COLUMN__PEDERSEN__POINTS__X,
+        PeriodicColumn::PedersenPointsY => MM_PERIODIC_COLUMN__PEDERSEN__POINTS__Y,
+        PeriodicColumn::PoseidonFullRoundKey0 => MM_PERIODIC_COLUMN__POSEIDON__POSEIDON__FULL_ROUND_KEY0,
+        PeriodicColumn::PoseidonFullRoundKey1 => MM_PERIODIC_COLUMN__POSEIDON__POSEIDON__FULL_ROUND_KEY1,
+        PeriodicColumn::PoseidonFullRoundKey2 => MM_PERIODIC_COLUMN__POSEIDON__POSEIDON__FULL_ROUND_KEY2,
+        PeriodicColumn::PoseidonPartialRoundKey0 => MM_PERIODIC_COLUMN__POSEIDON__POSEIDON__PARTIAL_ROUND_KEY0,
+        PeriodicColumn::PoseidonPartialRoundKey1 => MM_PERIODIC_COLUMN__POSEIDON__POSEIDON__PARTIAL_ROUND_KEY1,
+    }
+}
+
+// Stores the values of a family of columns, which may come from an external batched contract.
+fn store_periodic_columns(ctx: &mut [U256], family: PeriodicColumnFamily, values: &[U256]) -> Result<(), Vec<u8>> {
+    require!(
+        values.len() == family.columns().len(),
+        InvalidPeriodicColumnValues { nValues: U256::from(values.len()) }
+    );
+    for (&column, &value) in family.columns().iter().zip(values) {
+        ctx[periodic_column_slot(column)] = value;
+    }
+    Ok(())
+}
+
+pub fn compute_diluted_cumulative_value(ctx: &[U256]) -> U256 {
+    let z = Felt252::new(ctx[MM_DILUTED_CHECK__INTERACTION_Z]);
+    let alpha = Felt252::new(ctx[MM_DILUTED_CHECK__INTERACTION_ALPHA]);
+    let diff_multiplier = Felt252::from(16);
+    let mut diff_x = diff_multiplier - Felt252::from(2);
+    let mut p = Felt252::ONE + z;
+    let mut q = Felt252::ONE;
+    let mut x = Felt252::ONE;
+
+    for _ in 1..16 {
+        x += diff_x;
+        diff_x *= diff_multiplier;
+        let x_p = x * p;
+        let y = p + z * x_p;
+        q = q * y + x * x_p + q;
+        p *= y;
+    }
+    (p + q * alpha).value()
+}
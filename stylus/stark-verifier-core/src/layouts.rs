@@ -0,0 +1,115 @@
+extern crate alloc;
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::{uint, U256};
+
+use utils::require::LayoutCodeMismatch;
+
+use crate::layout_specific::OFFSET_N_PUBLIC_MEMORY_PAGES;
+use crate::memory_map::*;
+
+// Public input offsets that are shared by all the layouts (see CpuPublicInputOffsetsBase.sol).
+pub const OFFSET_LOG_N_STEPS: usize = 1;
+pub const OFFSET_RC_MIN: usize = 2;
+pub const OFFSET_RC_MAX: usize = 3;
+pub const OFFSET_LAYOUT_CODE: usize = 4;
+pub const OFFSET_PROGRAM_BEGIN_ADDR: usize = 5;
+pub const OFFSET_PROGRAM_STOP_PTR: usize = 6;
+pub const OFFSET_EXECUTION_BEGIN_ADDR: usize = 7;
+pub const OFFSET_EXECUTION_STOP_PTR: usize = 8;
+pub const OFFSET_OUTPUT_BEGIN_ADDR: usize = 9;
+pub const OFFSET_OUTPUT_STOP_PTR: usize = 10;
+// The builtin segments of layout 7 (see its CpuPublicInputOffsets.sol), each followed by its
+// stop pointer.
+pub const OFFSET_PEDERSEN_BEGIN_ADDR: usize = 11;
+pub const OFFSET_RANGE_CHECK_BEGIN_ADDR: usize = 13;
+pub const OFFSET_BITWISE_BEGIN_ADDR: usize = 15;
+pub const OFFSET_POSEIDON_BEGIN_ADDR: usize = 17;
+
+// The program segment starts from 1, so that memory address 0 is kept for the null pointer.
+pub const INITIAL_PC: u64 = 1;
+// The first Cairo instructions are `ap += n_args; call main; jmp rel 0`, which puts the
+// "jmp rel 0" instruction at offset 4 relative to INITIAL_PC.
+pub const FINAL_PC: u64 = INITIAL_PC + 4;
+
+// Each page but the first one has (address, size, hash), the first one has no address.
+const PAGE_INFO_SIZE: usize = 3;
+
+pub const PEDERSEN_SHIFT_POINT_X: U256 =
+    uint!(0x49ee3eba8c1600700ee1b87eb599f16716b0b1022947733551fde4050ca6804_U256);
+pub const PEDERSEN_SHIFT_POINT_Y: U256 =
+    uint!(0x3ca0cfe4b3bc6ddf346d49d06ea0ed34e621062c0e056c1d0405d266e10268a_U256);
+
+// A builtin memory segment. The stop pointer follows the begin address in the public input.
+pub struct Builtin {
+    pub begin_addr_offset: usize,
+    pub ctx_initial_addr: usize,
+    pub ratio: u64,
+    pub cells_per_instance: u64,
+}
+
+// The parameters of a layout that verifier-init reads the public input with. The ctx it writes is
+// the one of memory_map, which is the ctx of layout 7.
+pub struct Layout {
+    pub code: U256,
+    pub log_cpu_component_height: usize,
+    // OFFSET_N_PUBLIC_MEMORY_PAGES, the public memory page infos follow it.
+    pub public_memory_offset: usize,
+    pub builtins: &'static [Builtin],
+}
+
+impl Layout {
+    pub fn get_offset_page_size(&self, page_id: usize) -> usize {
+        self.public_memory_offset + 1 + PAGE_INFO_SIZE * page_id
+    }
+
+    pub fn get_public_input_length(&self, n_pages: usize) -> usize {
+        self.public_memory_offset + (PAGE_INFO_SIZE + 1) * n_pages
+    }
+}
+
+// recursive_large_output, see memory_map.
+pub const LAYOUT7: Layout = Layout {
+    code: uint!(42800643258479064999893963318903811951182475189843316_U256),
+    log_cpu_component_height: 4,
+    public_memory_offset: OFFSET_N_PUBLIC_MEMORY_PAGES,
+    builtins: &[
+        Builtin {
+            begin_addr_offset: OFFSET_PEDERSEN_BEGIN_ADDR,
+            ctx_initial_addr: MM_INITIAL_PEDERSEN_ADDR,
+            ratio: 128,
+            cells_per_instance: 3,
+        },
+        Builtin {
+            begin_addr_offset: OFFSET_RANGE_CHECK_BEGIN_ADDR,
+            ctx_initial_addr: MM_INITIAL_RANGE_CHECK_ADDR,
+            ratio: 8,
+            cells_per_instance: 1,
+        },
+        Builtin {
+            begin_addr_offset: OFFSET_BITWISE_BEGIN_ADDR,
+            ctx_initial_addr: MM_INITIAL_BITWISE_ADDR,
+            ratio: 8,
+            cells_per_instance: 5,
+        },
+        Builtin {
+            begin_addr_offset: OFFSET_POSEIDON_BEGIN_ADDR,
+            ctx_initial_addr: MM_INITIAL_POSEIDON_ADDR,
+            ratio: 8,
+            cells_per_instance: 6,
+        },
+    ],
+};
+
+// The layouts verifier-init accepts, by layout code. Only layout 7 is supported: the other
+// layouts of the Solidity verifier (starknet, recursive, dex, ...) are out of scope. Each has
+// its own ctx memory map, OODS, constraint polynomial and periodic columns, which would all have
+// to be ported before its parameters can be added here.
+pub const LAYOUTS: &[&Layout] = &[&LAYOUT7];
+
+pub fn get_layout(layout_code: U256) -> Result<&'static Layout, Vec<u8>> {
+    LAYOUTS
+        .iter()
+        .copied()
+        .find(|layout| layout.code == layout_code)
+        .ok_or_else(|| LayoutCodeMismatch { layoutCode: layout_code }.into())
+}
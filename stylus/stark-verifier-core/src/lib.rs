@@ -0,0 +1,52 @@
+//! The STARK verifier pipeline on plain slices.
+//!
+//! The Stylus contracts are thin wrappers over this crate: each one implements the
+//! `StarkVerifier` / `FriVerifier` hooks with external calls to the contract that holds the
+//! corresponding stage. `InProcessVerifier` implements the same hooks by calling the stages
+//! directly, which lets host-side tools run exactly the code that runs on-chain.
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+#[cfg(test)]
+#[path = "tests/test_constants.rs"]
+pub mod test_constants;
+
+#[path = "memory-map.rs"]
+pub mod memory_map;
+#[path = "layouts.rs"]
+pub mod layouts;
+#[path = "verifier-init.rs"]
+pub mod verifier_init;
+#[path = "verifier-channel.rs"]
+pub mod verifier_channel;
+#[path = "layout-specific.rs"]
+pub mod layout_specific;
+#[path = "periodic-columns.rs"]
+pub mod periodic_columns;
+#[path = "constraint-poly.rs"]
+pub mod constraint_poly;
+#[path = "constraint-poly-preparer.rs"]
+pub mod constraint_poly_preparer;
+#[path = "constraint-poly-finalizer.rs"]
+pub mod constraint_poly_finalizer;
+#[path = "oods.rs"]
+pub mod oods;
+#[path = "fri-statement-verifier.rs"]
+pub mod fri_statement_verifier;
+#[path = "proof.rs"]
+pub mod proof;
+#[path = "compact.rs"]
+pub mod compact;
+#[path = "stark-verifier.rs"]
+pub mod stark_verifier;
+#[path = "gps.rs"]
+pub mod gps;
+#[path = "in-process.rs"]
+pub mod in_process;
+
+#[macro_use]
+extern crate alloc;
+
+pub use crate::fri_statement_verifier::FriVerifier;
+pub use crate::in_process::{FactKind, FactOracle, InProcessVerifier};
+pub use crate::memory_map::VerifierCtx;
+pub use crate::stark_verifier::StarkVerifier;
@@ -0,0 +1,257 @@
+//! The layout of the verifier ctx, ported from the MemoryMap.sol of layout 7
+//! (recursive_large_output), the layout the verifier contracts are generated for.
+//!
+//! Every stage addresses the ctx through these constants, or through the typed accessors of
+//! `VerifierCtx`. The slots below MM_FRI_LAST_LAYER_DEG_BOUND are the same for every layout; the
+//! other layouts are out of scope, see `layouts::LAYOUTS`.
+use stylus_sdk::alloy_primitives::{FixedBytes, U256};
+
+// Sizes of the arrays in the ctx.
+pub const MAX_N_QUERIES: usize = 48;
+pub const FRI_QUEUE_SLOT_SIZE: usize = 3;
+pub const MAX_FRI_STEPS: usize = 10;
+pub const N_INTERACTION_ELEMENTS: usize = 6;
+pub const N_OODS_VALUES: usize = 192;
+pub const N_COLUMNS_IN_TRACE0: usize = 9;
+pub const N_COLUMNS_IN_TRACE1: usize = 3;
+pub const N_COLUMNS_IN_MASK: usize = N_COLUMNS_IN_TRACE0 + N_COLUMNS_IN_TRACE1;
+pub const CONSTRAINTS_DEGREE_BOUND: usize = 2;
+
+pub const MM_EVAL_DOMAIN_SIZE: usize = 0;
+pub const MM_BLOW_UP_FACTOR: usize = 1;
+pub const MM_LOG_EVAL_DOMAIN_SIZE: usize = 2;
+pub const MM_PROOF_OF_WORK_BITS: usize = 3;
+pub const MM_EVAL_DOMAIN_GENERATOR: usize = 4;
+pub const MM_PUBLIC_INPUT_PTR: usize = 5;
+// uint256[2], one per interaction.
+pub const MM_TRACE_COMMITMENT: usize = 6;
+pub const MM_OODS_COMMITMENT: usize = 8;
+pub const MM_N_UNIQUE_QUERIES: usize = 9;
+// uint256[3]: the proof pointer, the digest and the counter of the channel.
+pub const MM_CHANNEL: usize = 10;
+// uint256[96]
+pub const MM_MERKLE_QUEUE: usize = 13;
+// uint256[144], (index, value, inverse point) for each query.
+pub const MM_FRI_QUEUE: usize = 109;
+pub const MM_FRI_QUERIES_DELIMITER: usize = 253;
+// uint256[40]
+pub const MM_FRI_CTX: usize = 254;
+pub const MM_FRI_STEP_SIZES_PTR: usize = 294;
+// uint256[10]
+pub const MM_FRI_EVAL_POINTS: usize = 295;
+// uint256[10]
+pub const MM_FRI_COMMITMENTS: usize = 305;
+pub const MM_FRI_LAST_LAYER_DEG_BOUND: usize = 315;
+pub const MM_FRI_LAST_LAYER_PTR: usize = 316;
+pub const MM_CONSTRAINT_POLY_ARGS_START: usize = 317;
+pub const MM_PERIODIC_COLUMN__PEDERSEN__POINTS__X: usize = 317;
+pub const MM_PERIODIC_COLUMN__PEDERSEN__POINTS__Y: usize = 318;
+pub const MM_PERIODIC_COLUMN__POSEIDON__POSEIDON__FULL_ROUND_KEY0: usize = 319;
+pub const MM_PERIODIC_COLUMN__POSEIDON__POSEIDON__FULL_ROUND_KEY1: usize = 320;
+pub const MM_PERIODIC_COLUMN__POSEIDON__POSEIDON__FULL_ROUND_KEY2: usize = 321;
+pub const MM_PERIODIC_COLUMN__POSEIDON__POSEIDON__PARTIAL_ROUND_KEY0: usize = 322;
+pub const MM_PERIODIC_COLUMN__POSEIDON__POSEIDON__PARTIAL_ROUND_KEY1: usize = 323;
+pub const MM_TRACE_LENGTH: usize = 324;
+pub const MM_OFFSET_SIZE: usize = 325;
+pub const MM_HALF_OFFSET_SIZE: usize = 326;
+pub const MM_INITIAL_AP: usize = 327;
+pub const MM_INITIAL_PC: usize = 328;
+pub const MM_FINAL_AP: usize = 329;
+pub const MM_FINAL_PC: usize = 330;
+pub const MM_MEMORY__MULTI_COLUMN_PERM__PERM__INTERACTION_ELM: usize = 331;
+pub const MM_MEMORY__MULTI_COLUMN_PERM__HASH_INTERACTION_ELM0: usize = 332;
+pub const MM_MEMORY__MULTI_COLUMN_PERM__PERM__PUBLIC_MEMORY_PROD: usize = 333;
+pub const MM_RANGE_CHECK16__PERM__INTERACTION_ELM: usize = 334;
+pub const MM_RANGE_CHECK16__PERM__PUBLIC_MEMORY_PROD: usize = 335;
+pub const MM_RANGE_CHECK_MIN: usize = 336;
+pub const MM_RANGE_CHECK_MAX: usize = 337;
+pub const MM_DILUTED_CHECK__PERMUTATION__INTERACTION_ELM: usize = 338;
+pub const MM_DILUTED_CHECK__PERMUTATION__PUBLIC_MEMORY_PROD: usize = 339;
+pub const MM_DILUTED_CHECK__FIRST_ELM: usize = 340;
+pub const MM_DILUTED_CHECK__INTERACTION_Z: usize = 341;
+pub const MM_DILUTED_CHECK__INTERACTION_ALPHA: usize = 342;
+pub const MM_DILUTED_CHECK__FINAL_CUM_VAL: usize = 343;
+pub const MM_PEDERSEN__SHIFT_POINT_X: usize = 344;
+pub const MM_PEDERSEN__SHIFT_POINT_Y: usize = 345;
+pub const MM_INITIAL_PEDERSEN_ADDR: usize = 346;
+pub const MM_INITIAL_RANGE_CHECK_ADDR: usize = 347;
+pub const MM_INITIAL_BITWISE_ADDR: usize = 348;
+pub const MM_INITIAL_POSEIDON_ADDR: usize = 349;
+pub const MM_TRACE_GENERATOR: usize = 350;
+pub const MM_OODS_POINT: usize = 351;
+// uint256[6]
+pub const MM_INTERACTION_ELEMENTS: usize = 352;
+pub const MM_COMPOSITION_ALPHA: usize = 358;
+// uint256[192]
+pub const MM_OODS_VALUES: usize = 359;
+pub const MM_CONSTRAINT_POLY_ARGS_END: usize = 551;
+// uint256[2]
+pub const MM_COMPOSITION_OODS_VALUES: usize = 551;
+// uint256[48]
+pub const MM_OODS_EVAL_POINTS: usize = 553;
+pub const MM_OODS_ALPHA: usize = 601;
+// uint256[576]
+pub const MM_TRACE_QUERY_RESPONSES: usize = 602;
+// uint256[96]
+pub const MM_COMPOSITION_QUERY_RESPONSES: usize = 1178;
+pub const MM_LOG_N_STEPS: usize = 1274;
+pub const MM_N_PUBLIC_MEM_ENTRIES: usize = 1275;
+pub const MM_N_PUBLIC_MEM_PAGES: usize = 1276;
+pub const MM_CONTEXT_SIZE: usize = 1277;
+
+// Named reads of the ctx slots that are shared between the stages. Slots are written through the
+// MM_ constants, e.g. `ctx[MM_OODS_POINT] = ...`.
+pub trait VerifierCtx {
+    fn eval_domain_size(&self) -> U256;
+    fn blow_up_factor(&self) -> U256;
+    fn log_eval_domain_size(&self) -> usize;
+    fn proof_of_work_bits(&self) -> U256;
+    fn eval_domain_generator(&self) -> U256;
+    fn public_input_ptr(&self) -> usize;
+    fn trace_commitment(&self, interaction: usize) -> FixedBytes<32>;
+    fn oods_commitment(&self) -> FixedBytes<32>;
+    fn n_unique_queries(&self) -> usize;
+    // The (index, value, inverse point) triplets of the queries.
+    fn fri_queue(&self) -> &[U256];
+    fn fri_queue_mut(&mut self) -> &mut [U256];
+    fn fri_eval_point(&self, step: usize) -> U256;
+    fn fri_commitment(&self, step: usize) -> U256;
+    fn fri_last_layer_deg_bound(&self) -> usize;
+    fn fri_last_layer_ptr(&self) -> usize;
+    fn constraint_poly_args(&self) -> &[U256];
+    fn trace_length(&self) -> U256;
+    fn oods_point(&self) -> U256;
+    fn interaction_elements(&self) -> &[U256];
+    fn composition_oods_values(&self) -> &[U256];
+    fn oods_eval_points(&self) -> &[U256];
+    fn log_n_steps(&self) -> U256;
+    fn n_public_mem_entries(&self) -> U256;
+    fn n_public_mem_pages(&self) -> usize;
+}
+
+impl VerifierCtx for [U256] {
+    fn eval_domain_size(&self) -> U256 {
+        self[MM_EVAL_DOMAIN_SIZE]
+    }
+
+    fn blow_up_factor(&self) -> U256 {
+        self[MM_BLOW_UP_FACTOR]
+    }
+
+    fn log_eval_domain_size(&self) -> usize {
+        self[MM_LOG_EVAL_DOMAIN_SIZE].to::<usize>()
+    }
+
+    fn proof_of_work_bits(&self) -> U256 {
+        self[MM_PROOF_OF_WORK_BITS]
+    }
+
+    fn eval_domain_generator(&self) -> U256 {
+        self[MM_EVAL_DOMAIN_GENERATOR]
+    }
+
+    fn public_input_ptr(&self) -> usize {
+        self[MM_PUBLIC_INPUT_PTR].to::<usize>()
+    }
+
+    fn trace_commitment(&self, interaction: usize) -> FixedBytes<32> {
+        FixedBytes(self[MM_TRACE_COMMITMENT + interaction].to_be_bytes())
+    }
+
+    fn oods_commitment(&self) -> FixedBytes<32> {
+        FixedBytes(self[MM_OODS_COMMITMENT].to_be_bytes())
+    }
+
+    fn n_unique_queries(&self) -> usize {
+        self[MM_N_UNIQUE_QUERIES].to::<usize>()
+    }
+
+    fn fri_queue(&self) -> &[U256] {
+        let n_unique_queries = self.n_unique_queries();
+        &self[MM_FRI_QUEUE..MM_FRI_QUEUE + FRI_QUEUE_SLOT_SIZE * n_unique_queries]
+    }
+
+    fn fri_queue_mut(&mut self) -> &mut [U256] {
+        let n_unique_queries = self.n_unique_queries();
+        &mut self[MM_FRI_QUEUE..MM_FRI_QUEUE + FRI_QUEUE_SLOT_SIZE * n_unique_queries]
+    }
+
+    fn fri_eval_point(&self, step: usize) -> U256 {
+        self[MM_FRI_EVAL_POINTS + step]
+    }
+
+    fn fri_commitment(&self, step: usize) -> U256 {
+        self[MM_FRI_COMMITMENTS + step]
+    }
+
+    fn fri_last_layer_deg_bound(&self) -> usize {
+        self[MM_FRI_LAST_LAYER_DEG_BOUND].to::<usize>()
+    }
+
+    fn fri_last_layer_ptr(&self) -> usize {
+        self[MM_FRI_LAST_LAYER_PTR].to::<usize>()
+    }
+
+    fn constraint_poly_args(&self) -> &[U256] {
+        &self[MM_CONSTRAINT_POLY_ARGS_START..MM_CONSTRAINT_POLY_ARGS_END]
+    }
+
+    fn trace_length(&self) -> U256 {
+        self[MM_TRACE_LENGTH]
+    }
+
+    fn oods_point(&self) -> U256 {
+        self[MM_OODS_POINT]
+    }
+
+    fn interaction_elements(&self) -> &[U256] {
+        &self[MM_INTERACTION_ELEMENTS..MM_INTERACTION_ELEMENTS + N_INTERACTION_ELEMENTS]
+    }
+
+    fn composition_oods_values(&self) -> &[U256] {
+        &self[MM_COMPOSITION_OODS_VALUES..MM_COMPOSITION_OODS_VALUES + CONSTRAINTS_DEGREE_BOUND]
+    }
+
+    fn oods_eval_points(&self) -> &[U256] {
+        let n_unique_queries = self.n_unique_queries();
+        &self[MM_OODS_EVAL_POINTS..MM_OODS_EVAL_POINTS + n_unique_queries]
+    }
+
+    fn log_n_steps(&self) -> U256 {
+        self[MM_LOG_N_STEPS]
+    }
+
+    fn n_public_mem_entries(&self) -> U256 {
+        self[MM_N_PUBLIC_MEM_ENTRIES]
+    }
+
+    fn n_public_mem_pages(&self) -> usize {
+        self[MM_N_PUBLIC_MEM_PAGES].to::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrays_do_not_overlap() {
+        assert_eq!(MM_MERKLE_QUEUE + 2 * MAX_N_QUERIES, MM_FRI_QUEUE);
+        assert_eq!(MM_FRI_QUEUE + FRI_QUEUE_SLOT_SIZE * MAX_N_QUERIES, MM_FRI_QUERIES_DELIMITER);
+        assert_eq!(MM_FRI_EVAL_POINTS + MAX_FRI_STEPS, MM_FRI_COMMITMENTS);
+        assert_eq!(MM_FRI_COMMITMENTS + MAX_FRI_STEPS, MM_FRI_LAST_LAYER_DEG_BOUND);
+        assert_eq!(MM_INTERACTION_ELEMENTS + N_INTERACTION_ELEMENTS, MM_COMPOSITION_ALPHA);
+        assert_eq!(MM_OODS_VALUES + N_OODS_VALUES, MM_COMPOSITION_OODS_VALUES);
+        assert_eq!(MM_COMPOSITION_OODS_VALUES + CONSTRAINTS_DEGREE_BOUND, MM_OODS_EVAL_POINTS);
+        assert_eq!(MM_OODS_EVAL_POINTS + MAX_N_QUERIES, MM_OODS_ALPHA);
+        assert_eq!(
+            MM_TRACE_QUERY_RESPONSES + N_COLUMNS_IN_MASK * MAX_N_QUERIES,
+            MM_COMPOSITION_QUERY_RESPONSES
+        );
+        assert_eq!(
+            MM_COMPOSITION_QUERY_RESPONSES + CONSTRAINTS_DEGREE_BOUND * MAX_N_QUERIES,
+            MM_LOG_N_STEPS
+        );
+    }
+
+}
@@ -0,0 +1,3 @@
+pub mod stark_params {
+    pub const N_ROWS_IN_MASK: usize = 98;
+}
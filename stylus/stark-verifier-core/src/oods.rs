@@ -0,0 +1,2554 @@
+#[path = "oods-consts.rs"]
+mod consts;
+
+use crate::memory_map::*;
+use alloc::vec::Vec;
+use consts::stark_params::*;
+use utils::felt252::Felt252;
+use utils::require;
+use utils::require::BatchInverseProductZero;
+
+use stylus_sdk::alloy_primitives::U256;
+
+// The mask rows of each trace column, as indices into the per-query denominators. The mask
+// values in MM_OODS_VALUES follow this order.
+const MASK_ROWS: [&[usize]; N_COLUMNS_IN_MASK] = [
+    &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    &[
+        0, 1, 2, 4, 6, 8, 10, 12, 14, 16, 18, 19, 20, 22, 23, 25, 26, 27, 28, 42, 43, 57, 59, 61,
+        62, 63, 64, 71, 73, 75, 77,
+    ],
+    &[0, 1],
+    &[
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 16, 20, 21, 23, 24, 29, 30, 31, 32, 38, 44,
+        45, 46, 47, 55, 56, 60, 65, 66, 73, 74, 78, 79, 80, 81, 94, 95, 97,
+    ],
+    &[0, 1, 2, 3],
+    &[0, 1, 2, 3, 4, 5, 6, 73, 75, 77],
+    &[
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 25, 33, 39, 48, 61, 67, 75, 88, 90, 92, 93, 96,
+    ],
+    &[
+        0, 1, 2, 3, 4, 5, 7, 9, 11, 13, 49, 51, 52, 53, 54, 56, 58, 82, 83, 84, 85, 86, 87, 89, 91,
+    ],
+    &[
+        0, 1, 2, 4, 5, 6, 8, 9, 10, 12, 13, 14, 16, 17, 20, 22, 26, 34, 35, 36, 37, 40, 41, 43, 44,
+        50, 68, 69, 70, 72, 76, 77,
+    ],
+    &[0, 1],
+    &[0, 1],
+    &[0, 1, 2, 5],
+];
+// Per query: a denominator for each mask row, one for the composition columns (shifted point
+// minus oods_point^2) and the eval point itself, whose inverse goes to the FRI queue.
+const DENOMINATORS_PER_QUERY: usize = N_ROWS_IN_MASK + 2;
+const FRI_QUEUE_LEN: usize = 144;
+
+pub struct Oods;
+
+impl Oods {
+    // Computes the FRI queue (index, value, inverse point) of the first FRI layer from the
+    // trace and composition query responses in ctx.
+    pub fn compute(ctx: &[U256]) -> Result<Vec<U256>, Vec<u8>> {
+        let n_queries: usize = ctx.n_unique_queries();
+        let inverses = Self::prepare_inverses(ctx, n_queries)?;
+        Ok(Self::compute_fri_queue(ctx, n_queries, &inverses))
+    }
+
+    // Sums alpha^i * (column_value - oods_value_i) / (x - g^row_i * z) over the mask and the
+    // composition columns of each query, given the inverted denominators of prepare_inverses.
+    pub fn compute_fri_queue(ctx: &[U256], n_queries: usize, inverses: &[Felt252]) -> Vec<U256> {
+        let oods_alpha = Felt252::new(ctx[MM_OODS_ALPHA]);
+        let mut fri_queue: Vec<U256> = Vec::with_capacity(FRI_QUEUE_LEN);
+
+        for query in 0..n_queries {
+            let denominators = &inverses[query * DENOMINATORS_PER_QUERY..];
+            let trace_values = &ctx[MM_TRACE_QUERY_RESPONSES + query * N_COLUMNS_IN_MASK..];
+            let composition_values =
+                &ctx[MM_COMPOSITION_QUERY_RESPONSES + query * CONSTRAINTS_DEGREE_BOUND..];
+
+            let mut res = Felt252::ZERO;
+            let mut oods_alpha_pow = Felt252::ONE;
+            let mut oods_values = ctx[MM_OODS_VALUES..MM_OODS_VALUES + N_OODS_VALUES].iter();
+            for (column, rows) in MASK_ROWS.iter().enumerate() {
+                let column_value = Felt252::from_montgomery(trace_values[column]);
+                for (row, oods_value) in rows.iter().zip(&mut oods_values) {
+                    res += denominators[*row]
+                        * oods_alpha_pow
+                        * (column_value - Felt252::new(*oods_value));
+                    oods_alpha_pow *= oods_alpha;
+                }
+            }
+            for (column, oods_value) in ctx.composition_oods_values().iter().enumerate() {
+                let column_value = Felt252::from_montgomery(composition_values[column]);
+                res += denominators[N_ROWS_IN_MASK]
+                    * oods_alpha_pow
+                    * (column_value - Felt252::new(*oods_value));
+                oods_alpha_pow *= oods_alpha;
+            }
+
+            fri_queue.push(ctx[MM_FRI_QUEUE + FRI_QUEUE_SLOT_SIZE * query]);
+            fri_queue.push(res.value());
+            fri_queue.push(denominators[N_ROWS_IN_MASK + 1].value());
+        }
+        fri_queue.resize(FRI_QUEUE_LEN, U256::ZERO);
+        fri_queue
+    }
+
+    // Inverts the DENOMINATORS_PER_QUERY denominators of every query at once. Reverts if any of
+    // them is zero, as the Solidity verifier does when their product is.
+    pub fn prepare_inverses(ctx: &[U256], n_queries: usize) -> Result<Vec<Felt252>, Vec<u8>> {
+        let trace_generator = Felt252::new(ctx[MM_TRACE_GENERATOR]);
+        let mut expmods = [Felt252::ZERO; 13];
+        // traceGenerator^2, ^3, ^4, ^5.
+        expmods[0] = trace_generator.square();
+        expmods[1] = expmods[0] * trace_generator;
+        expmods[2] = expmods[1] * trace_generator;
+        expmods[3] = expmods[2] * trace_generator;
+        // traceGenerator^7, ^12, ^13.
+        expmods[4] = expmods[3] * expmods[0];
+        expmods[5] = expmods[4] * expmods[3];
+        expmods[6] = expmods[5] * trace_generator;
+        // traceGenerator^28, ^48.
+        expmods[7] = expmods[6] * expmods[6] * expmods[0];
+        expmods[8] = expmods[7] * expmods[6] * expmods[4];
+        // traceGenerator^216, ^245, ^320, ^1010.
+        expmods[9] = expmods[8] * expmods[8] * expmods[8] * expmods[8] * expmods[5] * expmods[5];
+        expmods[10] = expmods[9] * expmods[7] * trace_generator;
+        expmods[11] = expmods[9] * expmods[8] * expmods[7] * expmods[7];
+        expmods[12] = expmods[11] * expmods[11] * expmods[11] * expmods[8] * expmods[0];
+
+        // points[row] = -g^row_offset * oods_point for each mask row, stepping from the previous
+        // row by the power of the trace generator between them.
+        let oods_point = Felt252::new(ctx[MM_OODS_POINT]);
+        let mut points = [Felt252::ZERO; N_ROWS_IN_MASK];
+        let mut point = -oods_point;
+        points[0] = point;
+        for (row, slot) in points.iter_mut().enumerate().skip(1) {
+            let step = match row {
+                19 | 20 | 23 | 26 | 27 | 39 | 42 | 52 | 53 | 54 | 62 | 63 | 71 | 93 => expmods[0],
+                31 | 37 | 46 => expmods[1],
+                35 | 69 | 83 | 85 | 87 => expmods[2],
+                29 | 34 | 44 | 65 | 67 | 68 => expmods[3],
+                94 => expmods[4],
+                84 => expmods[5],
+                88 | 97 => expmods[6],
+                78 => expmods[7],
+                79 => expmods[8],
+                86 => expmods[9],
+                82 => expmods[10],
+                80 => expmods[11],
+                96 => expmods[12],
+                _ => trace_generator,
+            };
+            point *= step;
+            *slot = point;
+        }
+
+        let minus_point_pow = -oods_point.square();
+        let mut denominators = Vec::with_capacity(n_queries * DENOMINATORS_PER_QUERY);
+        for eval_point in &ctx.oods_eval_points()[..n_queries] {
+            let eval_point = Felt252::new(*eval_point);
+            let shifted_eval_point = eval_point * Felt252::GENERATOR;
+            denominators.extend(points.iter().map(|point| shifted_eval_point + *point));
+            denominators.push(shifted_eval_point + minus_point_pow);
+            denominators.push(eval_point);
+        }
+        require!(
+            denominators.iter().all(|d| !d.is_zero()),
+            BatchInverseProductZero {}
+        );
+        Ok(Felt252::batch_inverse(&denominators))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex::FromHex;
+    use std::fs::File;
+    use std::io::Read;
+    use stylus_sdk::alloy_primitives::uint;
+
+    #[test]
+    fn test_batch_inverse() {
+        let mut fd = File::open(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../testdata/oods_input.hex"
+        ))
+        .expect("Failed to open file");
+
+        let mut buffer: String = String::new();
+        fd.read_to_string(&mut buffer).expect("Failed to read file");
+        let bytes = <Vec<u8>>::from_hex(buffer).expect("Failed to parse hex");
+
+        let ctx_words: Vec<U256> = bytes[32..]
+            .chunks_exact(32)
+            .map(U256::from_be_slice)
+            .collect();
+        let n_queries: usize = ctx_words[MM_N_UNIQUE_QUERIES].try_into().unwrap();
+        let inverses =
+            Oods::prepare_inverses(&ctx_words, n_queries).expect("Failed to prepare batch inverse");
+
+        // The Solidity array holds the inverses followed by the denominators.
+        let expected_inverses = &BATCH_INV_ARR_SECOND[..BATCH_INV_ARR_SECOND.len() / 2];
+        assert_eq!(inverses.len(), expected_inverses.len());
+        for (i, (inverse, expected)) in inverses.iter().zip(expected_inverses).enumerate() {
+            assert_eq!(inverse.value(), *expected, "batch_inverse {i}");
+        }
+
+        let res = Oods::compute_fri_queue(&ctx_words, n_queries, &inverses);
+        assert_eq!(res, FRI_Q_RES);
+        assert_eq!(Oods::compute(&ctx_words), Ok(FRI_Q_RES.to_vec()));
+    }
+
+    const FRI_Q_RES: [U256; 144] = uint!([
+        0x0000000000000000000000000000000000000000000000000000000105ad16cc_U256,
+        0x04796ce99a95878c8047ea646e48395f47ed6b964f28b299dc2944e63992b252_U256, // 0
+        0x0730fe8c334c13ef86460494b6dc23f8868dd43368929ecbd8e8a725c3a200a5_U256, // 0
+        0x0000000000000000000000000000000000000000000000000000000110a71761_U256, // 1
+        0x00eb74a12be923dd1c6aae7e81437fbaaa02976ccbec94f8d2bc40f6865a09d1_U256, //1
+        0x030a275a1776e6ef202b22c960368ec19a6b5511eed3e3837653b5406f9b9c72_U256, //2
+        0x0000000000000000000000000000000000000000000000000000000136668b0e_U256, // 2
+        0x025655d12b0e22465255323bba080beecb14c541af15b6474654f212e4313d1e_U256, //3
+        0x071a39aa3fc753e142435a493fc7d2e58f7029d625f548b712f9157c28404689_U256, //3
+        0x000000000000000000000000000000000000000000000000000000013b6bfec1_U256,
+        0x001da3108e5e5806bdbc43cca7b299a40b24122957b9c546b531d5df272df47e_U256,
+        0x00a3518e33ea1156dba463a2cd9b61f238e005e15ac7e2b2f15870cbfc79fa89_U256,
+        0x0000000000000000000000000000000000000000000000000000000151af7814_U256,
+        0x031266eddaedababe57b744a2e20d9ab75fcd86873443c85fa3f6fd5e27c5194_U256,
+        0x03f19ca3b1acce97d236a360d0aac5715dc0d5d3232fd562d0ef53a0f5891de5_U256,
+        0x000000000000000000000000000000000000000000000000000000017403e3c8_U256,
+        0x02204d4554fa8fa3b7389d3111c3cadcaa362f37853ba26bf822b016b1bc4304_U256,
+        0x0514018d730804bbc8b329ebb157e3d3eed8476f08f9030d255e31b4b229ceb7_U256,
+        0x0000000000000000000000000000000000000000000000000000000185b83bb6_U256,
+        0x02c3061a8972348612bae6c3ed8c687ce4ed4d9dfdcb383e2d92f7053bf4678f_U256,
+        0x0088897cce81b623a29d2a85d4bc94d8e9f0f2af93e4c6286e973db47c19d93a_U256,
+        0x000000000000000000000000000000000000000000000000000000019fa97dac_U256,
+        0x07451ed80803444ce81f8bdb6661675510cef541ca72b38c29512524a4132376_U256,
+        0x00b4d6b317a8f8004dcfd4c8f58ba40334c8e7fb59199fbd6b549ad73ad81976_U256,
+        0x00000000000000000000000000000000000000000000000000000001a1de270a_U256,
+        0x068407b766d8ed2dce4062839c2f6ee29e0d5c6b16535936bc4cffd5be4ab87e_U256,
+        0x03e274131c2320b78d664d4c5173d9a860b3d8956dab8ddf43fd9bcc959de400_U256,
+        0x00000000000000000000000000000000000000000000000000000001df52c0ca_U256,
+        0x02f3916ff7cdee649423964c1bcdda9e7a1033640ee2a7031a5faa8aa060a2c4_U256,
+        0x0786d9ca8c37e2ab0a833e5250b067a916d2a783ab71227f7c0c6b643c88bc74_U256,
+        0x00000000000000000000000000000000000000000000000000000001eff182cc_U256,
+        0x02d7f43cc63b8ca4fff9401968b6d1ed24e0a4c95c2ed6e91b36108a9d11412c_U256,
+        0x0545300c9de3863170e91a18851a4b275fc9ca2c5e4aea82ca0ce3b3aadc4b42_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+        0x0000000000000000000000000000000000000000000000000000000000000000_U256,
+    ]);
+
+    static BATCH_INV_ARR_SECOND: [U256; 2200] = uint!([
+        2534169235705024626686324808634393853585831565907943482108690313257161253518_U256, // 0
+        3178252021768546495191420069283503855810875081547085704974573445290156150927_U256, // 1
+        750306408810217142172154772332431346133157644931327398773192217132801523894_U256,  // 2
+        3226280128804137150814229573229732102116176707945794809609240073015777100017_U256, // 3
+        1728901241630097182059191654514694233909215300052604286143205399576265614847_U256, // 4
+        383501082780033161475888335751640970775698014844945809500465250304571990195_U256,  // 5
+        1584321064206502489556508792864705557197499051322008238517055077709978776821_U256, // 6
+        455841209177497985407595792597740188442367879533611807591014116537629763433_U256,
+        1205830117758478584577299048162335030848062282744963524255847308124292036425_U256,
+        2022033382836826563130788327682594238737560526522754668866373235252035795654_U256,
+        3507688993053009418952311773828574700976638397465804363766919984364222578097_U256,
+        93356116281415718058168999399579625939077166728255141296443851116574607229_U256,
+        2898291710395736610409806087877150514105978819692423514166061967572455604547_U256,
+        3222442012156780726654618365996870741753862504851649837345343607691098706773_U256,
+        1141401507686903489089781345110050672430209236865307234589794031597308222301_U256,
+        2209915893899457909552033716599980245306306797839940941310188185414604565457_U256,
+        1600662784285624470041170382496273213872531996042015683347532295728492250564_U256,
+        2602825397775892154561291813554825995366676001050076068131634619587399617794_U256,
+        357941984161029621281374348684501683123911866009071958441396743337981295422_U256,
+        3161787247258412110446740563392219082490011236627373570974345677785141753516_U256,
+        3058756318444824218096299107024181088942801133424946866269291204917909489810_U256,
+        1165997552129888129847351745279781667905361237149154860291050664627945017918_U256,
+        159144354498274428683022172796619300066461661984581771856432839870482464762_U256,
+        2191145945267949684032608452258898463377614339731089218176415375178750576449_U256,
+        823826585814052955193002881092104986823942424927249112577607627786975250718_U256,
+        1269544679006262618207085613685487254040052506703861663225183824762212823732_U256,
+        1268202985678294291783966875079777552030034205809872111337941611159945164473_U256,
+        593571318114602227916265249549526062700882371013387853768856137803293827293_U256,
+        1467891796343518002785796832655083084625284999421770361269135312920878722165_U256,
+        1021338557138089583310279082609927110044006423529407523880928786857778287904_U256,
+        200690964103884489853887439600316728626122203527102799551715860039789919571_U256,
+        2706272604581295489262564444568925836711474666673464699576221189566538573464_U256,
+        2706018893646534917779358269927001213281701414509776634550063997935265287406_U256,
+        966002468609909948171773894517618136272326292249775473212459619121614457997_U256,
+        1450395536788339275079378370928708246518690389941847698668524435508094088719_U256,
+        2540699155915361198923496968934349457225282120343998288660414154159347413343_U256,
+        1923422972714099030699714215126567325412704748591817935774720950994565713050_U256,
+        3470890217255768757264586848866380951284819998362893598004152670912200411062_U256,
+        1874613469229500865857707186935947257528356180770069719822585008889154248312_U256,
+        951845660064794570432855225542252099793686442644559841281703953107004183141_U256,
+        2634233660544053617344711891609758226425101422682001641174158462331613027787_U256,
+        2221212023801157589213063525461852341471124260119717724251079786980685957667_U256,
+        711130095768664492376736497872736032612128516329201736618401035594824854300_U256,
+        3430784389240027280365522912238760936096171486285306363847988630596270273425_U256,
+        2378111714474650728983760718907081897978434922555912605566595285502236840157_U256,
+        1644852100182509138208604229366085453566504613455221486788054389910376597609_U256,
+        3584321625253952869756861111206468685653573915964368898043950056881578548844_U256,
+        3613510737380825509232826109090859785022222338173771513083901842441783413372_U256,
+        1341048261688507508896397376129970415837286555608786154031866426167374399067_U256,
+        671431161450456467697710130648339644531942321333591985480486629233443779437_U256,
+        953469027531311774483334977253374469286232119668216535598584923276289581401_U256,
+        812848575846767927248481897714782273280720348840349642330843818041773359769_U256,
+        367272222090926463298839361163183244780258964235832178102641038131033503085_U256,
+        1783412827693005325992391790622326106942654592816512204199403106082564601221_U256,
+        1651929956415265528813908947376907268387165812585080196926186370993238455907_U256,
+        1128139070425926374621683297918562054339933763274016913497025639234021901718_U256,
+        2465384422035521483154363745018118897282025551223044229908149237284096907453_U256,
+        264550639284999009062458459614131197957572286443769514328734504260599399775_U256,
+        420275041211780398671117024849671680649636843260495484676057699710393380747_U256,
+        2045721614948874689088017590042864606776282104607894009579577245303400362217_U256,
+        3542028453381110680332887497150454472815761368154284115330429960369898763032_U256,
+        1506397949619023682497602255744428306962046847765859224574167304361517975052_U256,
+        795662505441720958864418224506826898267417854710137183683166287637041571424_U256,
+        1592005306133168162291005994894607629104515470396292601911883087023720220863_U256,
+        3389462349181433457551529309391184498656015231377440786902642351367627358900_U256,
+        2067545914360408460429349973496229562860746228102086674174502396580064480778_U256,
+        2814554226672063641340907369746256869847073264413739858495102579911011572469_U256,
+        2856951137367149432605392617801144629869527253278585107763311652176871846463_U256,
+        567830317238514677805709606855565402327012767428759290007294644851590061176_U256,
+        3576722267939731046957271388405528664748287707407693943108171025960303997794_U256,
+        140754394886744405811231408732069116668826112385266631207411617108277252951_U256,
+        198919034671608638859871200080182994086187143661607203383733920775835637300_U256,
+        1694879370172290383692338348959879007310447822705483080531648932133634193656_U256,
+        206695343045207472808349204529931157636608816675933880420472586789900801267_U256,
+        3433497172282280293103834628595473663871295366126136247892773771678159448978_U256,
+        2094212689505766426629511554461314119787117141880398003484492658286398292555_U256,
+        3503025290198784981099482430263714086793012590501879880272076501967276572941_U256,
+        2760005105106269403870646950281835505003761713889381989048655906632034780352_U256,
+        1100094577852209458813135681603192013889662849575334446874331466817647433720_U256,
+        831149295368992328054015099555114849507602413796622195458833848498493622125_U256,
+        3115356493939895269237083310428968905732246151551072462522858542090851861416_U256,
+        289511649554996254111414196933639002870022653720348811673579079081103022162_U256,
+        128589452947824115474504139375385539992777697868043324225743581594362517927_U256,
+        1084533717737398070013466182091663166030822185512479019110592870001073800541_U256,
+        2806590283116372637416107505801261584177132077473534430024746797383864889994_U256,
+        974454434525279537587410445566129441363792552460040238422245869832980262629_U256,
+        794046207562762637412780077269015607746889562642045709176227663429145212070_U256,
+        373127727035042630892560755831706511871663829688953376267464203655015095213_U256,
+        673890832493547041738085368403298242404889652981904491804906671900651772470_U256,
+        2156616733102412001508394344077372797028334046902827027158283196616944866719_U256,
+        2028003900364041372555397783835260305077525107457710705167869611648694389407_U256,
+        1527936951233697247025094170278446421182615140580802485649235837479247703235_U256,
+        2712218589417243775519700403563151184006522645669609782842453737335110776120_U256,
+        1973910984155080475400977715850946841794815605904702064668864857679261529223_U256,
+        2833797480464137703525392829740031490235820697473802498330045798373027888032_U256,
+        3577649231732251740388929787021510783321012875114123960321532537509727450398_U256,
+        2177066889049042186596305155126817401358090670021076432703607086105556683965_U256,
+        1232497072452737468958041755799450558494067099494648117447560681183151541876_U256,
+        927644977476075974805424880774122973415403493758728355345061216875684813098_U256,
+        3252755422559068867752899018896420842331332634622011678508648796101380538533_U256, // 99
+        847468018790012909622703755186139810981870229683545653010397275401907163700_U256,  // 100
+        2236077500578183194093496301410421895236386880893392036849442540862166284695_U256,
+        1060068620929631398286180888979989667987727888928459788434391567841167672142_U256,
+        1281407390036856534399523291115364998955327729486289734982023022619492548893_U256,
+        1252954009424704382964890039538601726030525384494194080373029528993340143814_U256,
+        50229572897382337214769024084882317673348789531628145999314812404152373516_U256,
+        1020799680940029637374033596792843494854187068473717158760693078442839994675_U256,
+        1021341301367940860853542905604857054862454716700489966625336290089509205105_U256,
+        1832845419815259433644328595470674721237286340977022747791216691878992286442_U256,
+        1776635344327983155781188964428996773657500879893540690947369306005299969675_U256,
+        1206704838369522100529249789227451334653937217070963316456030593412827951847_U256,
+        2309064812756986868558032507638076889006300119642866886062667240432714626046_U256,
+        1934356757976342515445599138153270779285662018235462413637571111020812919327_U256,
+        1176737480704356360891525694286688209808427349003412941595468595429045910826_U256,
+        154068980132002372193466157979906546648955379145676261573807094809805960500_U256,
+        1729295087498632790070069513740708855512034531745897586195261566827477594700_U256,
+        1734454795254052106443040626079990754433100819240070564965531598596311216220_U256,
+        2916497421164588171731149456366757633675046173362928051587092491182450775152_U256,
+        2251068962328276412849170808571209644309687752717849495753109965751841976327_U256,
+        1795573708035521007817930490080013948798535377258216215974443367098504956607_U256,
+        1138139161305166478675404422569033867312641586917470819416431392677330405110_U256,
+        3170319136510621611020082220020536701986486307835965581282654507725727912916_U256,
+        2781752710791437564979682166595879089373978091845630212496534557456156958367_U256,
+        1311702762846036208591129582616380736816896293250419335349049819835949013702_U256,
+        2937672906436325332668792378380014886943298337880753100720680910242219618869_U256,
+        2477616467604024043355663456269116413835352235328269694054152552019611248389_U256,
+        1922070311250731097619208484974206738219986290076247578898683069133780421264_U256,
+        1700186439240843711328414365973962349399550658395407426286515288333579384096_U256,
+        723498943560505137881775129983265588075855166043356272786242902329848939942_U256,
+        290872177934088067201101840291005736457479980970122009210955936286073743675_U256,
+        622843648203891775066296358319242900518650454860836467004405129508240117959_U256,
+        1139080448979837094115434823543367060515204462784031855637202029092810851130_U256,
+        403671796406110065776045780323890700718135171765440575599181536354051118611_U256,
+        3097925170586771121264786623884975337033038729894868001899280612136203230816_U256,
+        1014767650082219143406283957875993419614965703082725928292411428851989846314_U256,
+        1376911483543883479684228921418687727449863642028682353386544340994581237045_U256,
+        3143500516195791280573158690281942234623809282195641176323529731504580467500_U256,
+        3548368229052761223805086496977551376430701266490798726225781422677307663766_U256,
+        742302278489692429323579116790798890333547905428690965488837065581161338055_U256,
+        472691375263902203751041769973075638935175512765876521809353312401766195249_U256,
+        3445457299228842664677501064948527641005998731465351658303012228648498786396_U256,
+        1803633793990467062164216606551319835292756181983479135940084308062525168802_U256,
+        2535348016032786179941297345382968275361105263236784685807977606723502901487_U256,
+        2960157958734590669518930995285066293880225994721623553938548896551711597343_U256,
+        1135573408407998877680218318439295934370190165344821223701273154201335504337_U256,
+        1414397889342943405753379143671931353492141637661328357997367967215096941164_U256,
+        400495526199748452705992356076566820212130515275618830974813722599846025714_U256,
+        247169740519836301575450029747128986213342958629569176583481867339806460202_U256,
+        1881447657017227768884671117629369343769270074703456204380182793783390052004_U256,
+        592821816255952055877031424107047410092338150061434192966976242709489768006_U256,
+        588425433870012568255660702382810414179461614965800780954298807908212093036_U256,
+        955197761130108390302345594273817326259696843404654334812333836781559279241_U256,
+        3582464997053668945480572640176143389439730152158413968499643181246229528554_U256,
+        684268012624470793053122538498596129161303345632148145710954521897110130373_U256,
+        2403056732685053338807409613293422871808639269850964363686056045519387212196_U256,
+        3415985862319639576594263801469812970815832704484340547405207548346723391362_U256,
+        2647969340830954449294449165650218154237420263548878896283907148653267915089_U256,
+        1938189357484738363722658731676078608343675080887660751484821634166138302872_U256,
+        2921463772906796566906238009104615064919610058057749844357332009018353431045_U256,
+        1577795813979746669734290698305743544034154541285267753147020096150788234021_U256,
+        2694790265359686339273854947405795733399203615526160197542457543971977111915_U256,
+        2787062076238070506482657602367252329012505774016672733461338075992414711339_U256,
+        2083371215269952406064554058642907291847577933521134435090097397988407570283_U256,
+        3606355314250632078174388079643456182609629223921602162086220133411725831698_U256,
+        2639178879502837467271563317450045865334368389706285248756631909603260527425_U256,
+        1356598900276491771266374732107112678455008859947168379766948682536669285760_U256,
+        3126044850332415663062328851986367895450537555886441083270204270568651462421_U256,
+        391000402029560795792898140281963551077154609553753664360267019531605847640_U256,
+        1200351587681278236908796589434570646857271861455479204920164081632089120689_U256,
+        1871035512628218987652950560916890151109429944755514821364611724490051326173_U256,
+        1409403955960759731927496466741928265162410063920042501572380221362552947252_U256,
+        1686826211142028228659349597065129099631506032100368812506201425029749186413_U256,
+        748641430974021852107092577154510989920531957478756774189434037028208143844_U256,
+        1105673100865265999683752319627501500803131895122394511146264861684900718338_U256,
+        2556060659982543725379330074992222914787282644658596149478018659836260449733_U256,
+        264998269468667157542691958179294046472370813175744432102076425620806913084_U256,
+        1701209022254452471498994582079071850966891668897309081462338139447245353174_U256,
+        844864337897024059148403580086418168818197509651017567749311831065396481992_U256,
+        980006033372314809872280010222354710055334772056537681347119898049294074358_U256,
+        847825472545655033592837268118906940995062952223371978260042008637399284469_U256,
+        2842659133193317333516380842031602413048702511571710088202149306349432650778_U256,
+        1183980601124956643665521309139668419962097076455185602718820736721477495443_U256,
+        3071165468158924679011688122197600516456082579631216082728442286745427068629_U256,
+        3102673007433448443420506975124636738039643504526713178027418487880594377860_U256,
+        1445780142050140041175272411016056488982940390247620075441345857560988100064_U256,
+        3254642116848724071364588804886038070777117933505226933532123215772513387390_U256,
+        3268450145640073371676300145597897185721121557342988382084796358970486244877_U256,
+        2928212603240481674904358122393895163472011197431843975719633569856898961867_U256,
+        2157845155919474316210841670933060209971295727389883921381039497101820909874_U256,
+        2978082460412976617415314097755743510187426847754578585002341127056461302051_U256,
+        2094355999084155656882452087629081595684943256883949898444582277945229444583_U256,
+        1977534939210885847735824274190594154347769242292471283985344526032188730575_U256,
+        3043671619145798602000046309568544019250237934363916358321002295130605897854_U256,
+        1408532910648363243592206287359205435857068478189692567691418228906388607225_U256,
+        3145393365115715943861483900824653569586731275168704280429623500074910196343_U256,
+        1462522718076139594631909355316412312877483622996350805033032594782983451619_U256,
+        223915350043371487103489864354195450024345497835719604690024412659756806118_U256,
+        1534545439841955326977129703227487919119566629878273667379919855652234802177_U256,
+        1370620215561139641548354697550613110010931272415531349459786954012072980878_U256,
+        1374878613371404073545151442474513070258926215186956721276122343322045619314_U256,
+        2771044992449542556455574438971674040982870317459186408761167097982438913038_U256,
+        3165803718293162968981986441606837682188149868574722512267611739122469793609_U256,
+        939388495700714353431085815389828372255422064809399066459053293351865529194_U256,
+        3509825112108797199859278721991122179602001343614088547817907192969146686774_U256,
+        3009671241671043336757514366313455401542696734241651590695308772558621635032_U256,
+        1024896689916966049379766288007491439967861013248888112522623291034129729177_U256,
+        3455145016369489842022461727013219859778857876668585000160734880629023432428_U256,
+        2201294343013483960418102269536468259633038598567603110880400718202739355425_U256,
+        2964542411739722302436351993099187832016040894903054427675044899433179674600_U256,
+        716977614996311587847502217142395447579779390260092646860196138450179946920_U256,
+        2282184003011229182428472728308177368997797217322200664792500964340470885772_U256,
+        557016269587029616471278094968063872813502074675396378137586627347847524729_U256,
+        1033779993220387745232896190974809556563791257622463299085055391812388112834_U256,
+        1149005332151745564230048093585578751727088384494066950391980402905383280760_U256,
+        894899299019382928253803132303185077869244957277091726717064557689815841338_U256,
+        2234364200444457958948033706256861703959118034480013709551796872010692546793_U256,
+        3223187020849178230606908749459866323873344006569745002078389461874796208158_U256,
+        2203798004724621883575070356446527449305966578935764552202007909357082691_U256,
+        361959987103584554780888720544839474042445970487783568658705632911462520869_U256,
+        2769791963423133422141312170522361332942249238058619683625415262793951988522_U256,
+        993200708537543662401957674911526741032629287190009552623351550134473761725_U256,
+        3593568576440190493685648951154248586404063271272718839465349607349721285648_U256,
+        746163700294630432468399293924733660025771954134031437288262305537693634224_U256,
+        172801250269866714056352888665826819316465191537952824490743067300795847380_U256,
+        3152847418185041665818939424334480414210419905790296320183833839473279529230_U256,
+        744938454955129676895812968529557863192879339605121912791303116890610441854_U256,
+        360339983489529044262849241186411510141879194367616886294461247801527031472_U256,
+        1828309575847176231475009332249210371473150989315006750256271626315240671999_U256,
+        3115099263047638066375748992163085237562612412696906022547444692803670053545_U256,
+        1149009766797882927887632584124419544281037362091133700570524995002632975140_U256,
+        1240725027439233640268693135426572750752513284209801077861985387103697814854_U256,
+        38005405527749027135166368150593239904527060163915901671949001734168574970_U256,
+        3414787293852015342649497603231569082012371525045983082972359839009272756433_U256,
+        1677100823296949885369548216056399056117690750275544548069161909683912614720_U256,
+        1763601059278557414195721022368830110194600459688748187674810397055729559355_U256,
+        3013496490545108512527691833550550809453944437086602848112815030246193411224_U256,
+        2002329387963683301395989983909915820521788877118369127166979124392586462583_U256,
+        2540747539634032789154535872180555359343375144230840581400254711537122818413_U256,
+        2924839077666662084290768147238117048628552296068249259922763577231494090064_U256,
+        1522546917477133811448390021051321483487341443260096062571602224445789169838_U256,
+        1211754986774477055893861519554422178731217937607743323350062931743185370349_U256,
+        43250319453307620461249071748332296933420172697560704200533874706027543748_U256,
+        3389904331278592612301381105565393737199013293504665190859490445455018972740_U256,
+        3536373438562491890351470665349895182746058891239840626270965072488178237304_U256,
+        3173411800077897484101511201247873876670730711610312482075882950962137238006_U256,
+        2312083853339524816729652166242619541975134197234008966436840875947976682765_U256,
+        1509643130070801843133364042549625276749382177579939816074172348845560393481_U256,
+        2624324254436726743518625983322511798313900089779006887299732552267695106847_U256,
+        310179876133996192429216715412180389394709418810670080713385481324991576039_U256,
+        2729136102025307196761002083201214293279728323105016791863735520595108178385_U256,
+        2527931067450005147764226684910427187746332471996720980409256519167060362054_U256,
+        3364354590868595575445104816360833518589493059077137173983388612475739634800_U256,
+        764315116108685025128289496577289767987967235750316838006378403397145035953_U256,
+        738977854156507412575242069032969116426432695484067265588442746649634779631_U256,
+        3106749260849972302618520883116095057149284738629994523853928524052333576620_U256,
+        3472420937015836263134800282469748628834749408021040588517776911906651099004_U256,
+        1882831084399986356427815920741131366142614978566670439464080625390280222033_U256,
+        644326200075780871587766472291593656425550969346029824802427733464323761243_U256,
+        3048868104411346723831403682782276816428911762737893792969923636288890106110_U256,
+        1247641675708810315085408497355181804604947519277819646741324379865703601125_U256,
+        1838633832264045077434946425650292441019394656581476284481755412136855552695_U256,
+        3013617119172319866718742334606175202789718850063643562103683534513692752709_U256,
+        1120994675449862801466607747826269773027213661011515344539389749354945892897_U256,
+        3088329893708023841374472893550392096688868536618302701552804040406720200923_U256,
+        1221208725181973865623156614506974498765554876046842353026432556116580484916_U256,
+        2108647162887916833232859581519677081825227038914516559178375104140130509156_U256,
+        309866672439697648285078522749013181777454589230881322529176624037341793968_U256,
+        2844101138248649304014984317524484126987420998953501526929070010801233380345_U256,
+        1342573872596441653234188962114388328443016429057663713657096727432101929316_U256,
+        2891181951219958551088477733376914104470776272449214170339339585952727401295_U256,
+        2986530620081773370853168083949399642761472379315617096794334758922842390908_U256,
+        806287848877755565857430277003367101806250660261936248436187948552105788222_U256,
+        1747325837600911991199487016645336745759765404553219992473531030684389505689_U256,
+        742855226173911511892930979212238249443350500825009936919864053686804170634_U256,
+        791110513610102119054110625974237225862908234035968905939332135731411104235_U256,
+        587393525063060209511758412698932146169260634654358078877856954286330656233_U256,
+        1127191993542799081774746563922016332640301684304265601026808817838393572542_U256,
+        205213666148556697335872853462410919703392569531592821060387891197272669756_U256,
+        801152772262111179828454794190354302060631246758374865259047650383107085186_U256,
+        1075505571395056431160209302417574405835074666692051218871534780693478660475_U256,
+        1998998742053975114327944924084128172979565979954083802470992758327528218509_U256,
+        2110852327112019679142693293878307686090311801588295081280580416506279201319_U256,
+        2491959804969026404995663118989791325663244101004259766665479582550821600396_U256,
+        2842631004903865293508927134254496171619639341885331177107035771399034880609_U256,
+        1209009767225910452586242150459580973965531755732036123334430145023579934001_U256,
+        2015574824888861135933863039997808190061002510247975800706336416658806177374_U256,
+        2008458520621660643705771596479163015897972148655387314422114101167372206836_U256,
+        3117232636311008537608793670571473711898755885970146307558358324178582999670_U256,
+        2579644189516760280343300635437243178458514409950149072977014573655440077072_U256,
+        2475984160191629482312711401925862547148515894013973113853256827528582018125_U256,
+        550923873657539099703387090968558069873476356388438405467906874601751228378_U256,
+        953287837375127492153234599868461186268728866050473539870900504250419268386_U256,
+        2007796502970171400312340522551531651068002418191630031096578969623450262648_U256,
+        304387619412975605136439573462649064102321488697465798064791093002837245772_U256,
+        2871381491073155623204213236719474149914852206090739123123748076619531844884_U256,
+        1204213156931968951107835728730900198427813040058957391772130470495908578796_U256,
+        958654025184538047407320321043553334834911017981930866666045974153484761707_U256,
+        956551168151917309434707675853994181540577337250784646335739886508850813599_U256,
+        1473344882006405894883204882347415518857445176495129224652530218593132360010_U256,
+        3212525953216476205603246847872648564103837566363857354518204315035238418057_U256,
+        3134229965057380868638666259347123780451262452427065166598757388239807263352_U256,
+        2449303854488020883085480400677394935973088964202442590562089143530945032316_U256,
+        3556105036425277155867668095507895016637719749934028620139796948383919371043_U256,
+        770922720617320737141994754398685706385800135395047671671338145120922145950_U256,
+        3383263449164846521757272329522250428809092314234636270393797047379827889164_U256,
+        976328512572818419753389061074032696126806490058944003036456368728039549590_U256,
+        736705327878992798726808542364771512554999918262170853580102041014742473344_U256,
+        874115078811781124004316939912537443693476287416851528198550016259120957872_U256,
+        1688515357575258247095425876052050504696410499935929222972730220660848276827_U256,
+        3361113571729664442856498559780685759505090953095206276439544046627515451663_U256,
+        920018042791999310992004463969504542732663768785123685262161784094826561515_U256,
+        854646864405334490396468851638283945239035074530357162186098171093971707284_U256,
+        5242999649798584449424529961408582865728587589150176522808001435224444937_U256,
+        2514564832852373924931912198744596453237905314794469785614206051450809893935_U256,
+        3427377245841453488987188485094315613546451181063102756939838030487202398413_U256,
+        2165836825122878590860698455975635314033121335391743412693877287544833522698_U256,
+        295029935966892994732361795398481617319134668462709840612975929039749966481_U256,
+        2404904570316800311986225338360300708460597064686677639492081440255306874024_U256,
+        1581693500324867181896305881274057661036367965801923745251861508711968300176_U256,
+        127544226397858552291693053746251795168717052053047055490238789598200532712_U256,
+        2157520005852653274816483860913393501474392330898498693965908472959015044376_U256,
+        1347237151202958950228611819538481797286084618660405984123796864817460493724_U256,
+        2007000427335975033853214019099269125836230064724744836310796453935835375127_U256,
+        774290212063107457565550283300597091629064262805898619006912811443681206195_U256,
+        3138178983703950236389312853043668682263346822401177600430212116124121248141_U256,
+        1434735813863770484552543396228648314781771008482323861380826635616246404718_U256,
+        397560872667823576731742411372441223343158919629435635305900771884148519910_U256,
+        351887357525594266357322092591820966893855863556032028166174575925329113924_U256,
+        3526564674748792119404889822758605989030416958672916061018566929615500705219_U256,
+        3341165513245726489199047132471312603436281038129355478219688858320905946788_U256,
+        3539400988292343687010571540186205799173689492310814588452060875427656709945_U256,
+        3294588993904396358496799161546715019350140285085461428103788229392176089772_U256,
+        3073546514957530824313258089096813105390290718219645242465663899619431805328_U256,
+        2250803363043219720209584852540456977368960956396170451820021214433218222365_U256,
+        364169279490597535048942706062414955227866896038624943387116348684926450346_U256,
+        1790473947211083455251720467449809766094339097198200559966930832717539392869_U256,
+        2054047831732948759854154767505346465165265019958985606564597220050890147681_U256,
+        3412660498201662380792469639656813309432854998114412032156324794376632397879_U256,
+        2551343525249326822886653630868019699587891592262998783416683824690013472341_U256,
+        243246395086513255556597379752956980216008257881013494476320864970019682072_U256,
+        2553829260421988491812208379185011549392945296426971613164636818984495767135_U256,
+        2466368115603189340822370661716539312668842790067969354135897917388976036811_U256,
+        2751108003442168778123608172173436439191749348643897598898480618046249507950_U256,
+        1446838918101048851902603639613606668720947060246456034008243110755412142938_U256,
+        124443833076244723222366219155690394237819717848974355990979608486901765629_U256,
+        1513585607664930119709189930630700262080337870518153972670230813637662812105_U256,
+        1401641242069280225514222458217636484386439779952492317526466863590038494953_U256,
+        941317543806029604883745807395397489650289529381529970960444673977740188190_U256,
+        128277329920227530126614028829309374536201585253150970690412352799614468577_U256,
+        676381285687760246855361228114877068231604627474432373187711637819558795572_U256,
+        67729529106669790870125881342990269806387218250706320520882950425271442314_U256,
+        3132593745131556400274090455134678808568587304159386947430695807470268693191_U256,
+        358270883104336602172280013288756806925719390239185243157943510774405327928_U256,
+        2106027132828586198079828562376334030509642256565988711267474168396580820695_U256,
+        3205330614336181336674752672924892497293969656681646558708107331859221682779_U256,
+        2753075243089798437308745231854430274599426296034896964418047936062721255396_U256,
+        965275466739961485610753063739954558191120130096114121240597991146103410876_U256,
+        935680607071718028146322680432604930844314622975077011226190068632057590388_U256,
+        2624553932827531447092157301441147811506586669524427130560194507095794108967_U256,
+        3399966084615387920621150887503748747446220266192222049147388354299015835205_U256,
+        3405862355317789231448438837251083414216980849458364031667809687522412720211_U256,
+        3439542016335960853844140643695079117403098103558006511122201601972259940129_U256,
+        1107044792631779392122782261481200982266479521031004408551804057781994070263_U256,
+        1395769665554410713698010553583100146717500831999163208450213617362993372864_U256,
+        3297873818687150971971013363824077689187982383231229549282015976393466974771_U256,
+        2969029065218984353898161562166899406915234778808270798538972371725871108469_U256,
+        522210117198206931654250067468832108196884515212221114465941153030555536571_U256,
+        1153885833071608845299923846993738913309118393441548786406349873315803387089_U256,
+        245539061349439109602821300729349496727582966546534866674970741222552190128_U256,
+        2769585440023687849027054583160190490408070767263228283271546098481352613200_U256,
+        2011910856456420451053455965325115350343186226485793174076353673956359611288_U256,
+        3376839525019005231803636429172658281386496305228018279907866112848302313343_U256,
+        943731841518777137408942925442961496931976672263034377312161401171267430348_U256,
+        2874795502770500320128195117905089584625801322360429970396723109366413277834_U256,
+        1369286031430965896150228318927541930294725209288675611582053959662333025860_U256,
+        3605557166153006911300432276670138458082789598074545680463289318986248263526_U256,
+        140927200886095769108128083864642784100353897143636744172403374549674350004_U256,
+        2353700687293562935087722872096384542762451494853822982537797613725787274418_U256,
+        3365088407479434207210184573003213817552819487459555728254489465359983295438_U256,
+        327900388081345090440977163016212972860386752114607154103336821047132936764_U256,
+        2966541632697836364491690458498669250270246839884537854679900032317549246554_U256,
+        2808332407201227478938342267571184847335353216726581068676109517043330193320_U256,
+        3266242102699258726263931581095831463402686968020624356746086672378791290387_U256,
+        3599716184311423532992071811084577964958253081587165672103258185384409742147_U256,
+        590845080472949651707549389906026911737673728990047466251200702871371025203_U256,
+        768123064709949814908870083047468140426378231006752417902066080026975450313_U256,
+        372275826369694009146208310922773750172594979389567630003499973004516830759_U256,
+        137911298435988129638332879162516172834988201483564154113026939263097504141_U256,
+        660113437662042128822871044732935370289040817341495562294499447785797254042_U256,
+        1149005425841985818842806089401612826012775438179752095319214513326569182354_U256,
+        3286371570121599197729596161435102782818123038475463105562245160007692155231_U256,
+        2532674011921137363443392619532838455725328678750097193385790494055710717687_U256,
+        1696357190041539009568375739233794828371769841572845401618916586756677317669_U256,
+        2291487065822129476361198716073976357990051890837367734304238705286883648086_U256,
+        3437068551756989333486063317293512135291199500279377748180946848236518123940_U256,
+        3258750015839762086588212981251860780506215175565075687840063017561814962468_U256,
+        3109726355215078583054111399924327466211351146334483320025261363652567666661_U256,
+        117274183522510750405577858510420222656466863019768416868548675006302291090_U256,
+        3454568552085881144631059091118211196403273699949987722007835897182926302677_U256,
+        288558946792610171642529004433717464545321549461503961966713295589693061769_U256,
+        300133206542381991833661940509173463037410677234740792580848906525222784927_U256,
+        3223057473131339557203703624455279653243085932228666889944286541630589245347_U256,
+        3099016369503888078317398171210275908980127023109249123894613228440242758068_U256,
+        1419943754394826405554873266665217224430404474668860126496401408562994547375_U256,
+        1491853970033490955856558015588951781919176031984563787700066333132581494613_U256,
+        2891953433601843531294941429651403681581166778263264281083180338865662931140_U256,
+        2003291585900682056017520294007651225908348928397917884385673676404364186068_U256,
+        725523097571701417474631173925832645900160326892402523493690820978041996702_U256,
+        3393623540659009786553142616515978279742986416270254244542532179054406239281_U256,
+        653314978500191595635615479979089362046800738321835612464599412482494184796_U256,
+        3129008776569764310445108477159821786765472911000595625898594256220075223910_U256,
+        2484010189296316348234129791138573175815965176633418324933527493546520099749_U256,
+        183191198206362910602425784588390715909475516835137271348343060705107012502_U256,
+        1691161168418953150697126827484647714419460599963427472004342811423979080123_U256,
+        1326139881501301462110415141337653239585511082182959268773713578476920864538_U256,
+        3340650545867256739177246495174686699115267175068755275610392118408013442153_U256,
+        1597967174738713379684009634864768472679306015865426520566972181037541353077_U256,
+        72137688201794794985260682815278993094311712295515068265614494435961308734_U256,
+        2608785209526292007575771288148577913113947710276691450561847469013146856569_U256,
+        265242874756078941202303831752127306743196378140984606299189840394882086208_U256,
+        1569652792002491825934218075487678377997273570081299515028343232559220231297_U256,
+        3563245739349483933696970210147673522866144894974801584666237329366697643550_U256,
+        233084981012840980643952153253535862499375745031116366160501624419424529894_U256,
+        2835729421889018983880340288845402293994266213737127649567531678203235997940_U256,
+        409639752241882360724770156953084392362107467973774433951181133287609792626_U256,
+        1977012221470283042573431809145227186985244882867598026932353076625895275369_U256,
+        1719010600610785609385689029663336785097886445695536703039708788702768620802_U256,
+        555179102652029552932673224906667172360354257772377890424581832732074906631_U256,
+        741429765477372886369931346401981244190822022409781770588249654875032798527_U256,
+        2471459530672807647093062175848668089665233948526988529724728528109436008582_U256,
+        915714169683918411761784630603613934652615091935396025594779370115636940847_U256,
+        27363343208193535465023422523882821769074650250081112415420853590849634436_U256,
+        2037092810211482426016517524739242436008303112096419975870041852580393100506_U256,
+        287139237720181452519563815727061716850183954600928034466926051160944662899_U256,
+        3111243294011141113623778776743588022077048834717458369611242783874757647441_U256,
+        1438521868461825870554238257241996823629217448046593763272625386595587046481_U256,
+        2962937167314440535946083024893109388160666630315437285053393379843424913403_U256,
+        3144709895490229788440135734029596566611618299890670406655081093072580475430_U256,
+        1247834897823736935681953002138330999984625161839545771968679854105171222222_U256,
+        173609869091914444457547819705427855873054823835144971623446955928922672566_U256,
+        952873938901270676701063868065762289558522168071329554505101393123594456713_U256,
+        3494352566206336351145504802072404846509434657384487680833923727027373891041_U256,
+        3333926770707525802099430627694856337613735957509390542163441328287668873494_U256,
+        1990430667140269369116306995392152453645434733521983106501482117975299190162_U256,
+        1573719816975198981160412721475316099315223495413103735293179336893366931012_U256,
+        717198038709429173348392158424397414676100424745345545262553671238241080218_U256,
+        637961672111021430884626073103550000211091956067090907276060333019125680452_U256,
+        816977488217079548548348710238679194500218762666982982442537016430765189861_U256,
+        2372648999351894982964298702400605131325979559573906284988088449317536666038_U256,
+        181729654921592501739836844235352297441722628697988137028079920840419024971_U256,
+        589596341774873156678380201107777425245296073846809231847716475092770867471_U256,
+        2794484030597246686848694849515356323748024612095859744863397958522344756867_U256,
+        1994232330866665026010513111501312476350585406663169903774459681912558325870_U256,
+        1450016106923869279431486453088496897306083616787974274130832435321785303939_U256,
+        1970062186973952142776371215709374068334092107058176259375279530335214561972_U256,
+        2306243962016085697721110241235579525268850344584095088163547833154318362559_U256,
+        2459427593296069120610231947239783983498526001405437807602065830286649752326_U256,
+        1479166530378794990869545481979234847302991664499826848908792104767055259370_U256,
+        34767795216121432039322077535996159630573961771524096578728527619352342234_U256,
+        1720137849826188078617644810926480435834268588025194127641386046456627514515_U256,
+        3043527191030072145290402737789742813157182211732212702777437627869627923526_U256,
+        1456163485432546405006402207214892951091416661066500160889376596351260009392_U256,
+        2580577638158795302146955863797085155502628722258637155162034850371850892662_U256,
+        736432504958129884096545074578288468139103325952773963035549992285444129390_U256,
+        979541295258835178829930801995877028341181373939398573775964863992872550580_U256,
+        1470877429545300368264964379382875275125202873985998292505455407348528822548_U256,
+        2932467322366267496104387693859619198270094094278114752647621419542836630684_U256,
+        1988788485413945812311552609212653853862349639014390181878909255968364689178_U256,
+        2438253499879264162062122280791080158869247652528242193448077779777844057606_U256,
+        1805908984500302471390693709271972325001002532728011384195791797634359362249_U256,
+        152786763044327034587817036317543861213798164645258342990133628326971260093_U256,
+        758982786441948932298259752478108070686310026973633956414441228425159050700_U256,
+        316788049336106681286095904242180002392829007367430606739796818233928600630_U256,
+        1390308914760890941725808602743778945065981252691435788664313400328689385658_U256,
+        338397744366170464273762705424070382954826114649745058124619222821617986924_U256,
+        2593556786382454134983248598645391095954522400068976352342392312869558105039_U256,
+        2253424248335649214427502914810644220535651255505071276130751841032621745352_U256,
+        1411555140890185385970536189483614075582071682050432194559228075271701641184_U256,
+        735022713706276950792118147054975060562580788451413307915441772002382030155_U256,
+        1489155551465728654483071097982819423809023716778304431806717911025052664514_U256,
+        235466052187104232632739439745794261529948806655671837226365296946706328700_U256,
+        3465875780030051193512231910478069081387633058219812925621337709742140166898_U256,
+        1892791742318558333447736132227291234619331853756962183328544526664025984559_U256,
+        1983846297456284804284209486203288944519316915807642224197271149399405429879_U256,
+        3612911456326790017074187925437275901494431697224740658912576452028835526917_U256,
+        1791783350447950806729102793310617164447516590414418534047907099624940223543_U256,
+        590245671292344103776768870256754991736915201384606075390468980296391523264_U256,
+        541543377437595598827592072877627022791252495455698820144869898640541646464_U256,
+        2561689581851630415526072068020280245962941917757779979627819722322410008858_U256,
+        3276322059814663180032868126671192385943524782533036643481061309540538045982_U256,
+        3362540061013988016558243926332632385285406156802111632679260480868472161817_U256,
+        332881920462697296100562662514101581569378475070483023251191481604080678555_U256,
+        2569157265747028297572149386501924939546310450506036375244691589414715845239_U256,
+        1865382938272691188356724547461075885127152932780359278801916608630233985699_U256,
+        1992565996672825078227502163272674726649112770897452492169243108244404792527_U256,
+        2087682607229253100097737772860129088077257952747188571603547212231472184676_U256,
+        2057905398955747494619009495431518517487535216644202386329560547348825868636_U256,
+        1778157721403641689008169517007669185075941033574249841545945242462267258946_U256,
+        323925868336695859435386015283993749901207906118374513045334818753781713072_U256,
+        1783829773974160165588005148412857814271791894465555881729475549117840694757_U256,
+        899635730594757714502499545394716669610073308523397515980337708786023454418_U256,
+        1927499950875925378614542134465940065774621301169246326646536908538489159056_U256,
+        772805115176270248857360752837137908563969830676171074300000364413380223858_U256,
+        1214712825794756584762721461546355000155965384514810504124887139806274387969_U256,
+        27782347241791851461565508286485892945184866432719605794226222779392442668_U256,
+        2736629122977536326222637109506688473367167683092231641459633603381864767471_U256,
+        3574703858899895339399942515689549270752399440439242488726810439418514929405_U256,
+        2849280973903441212615824738767944114617276334186381552683716257423466451188_U256,
+        3038389675642087636714555450812374691209381870865369267005696409508052911365_U256,
+        1497515907082741377353198728004188971000068265179291956561868658876276097093_U256,
+        3300235790324365754881249796079505939995249389315103920908723760129720416557_U256,
+        257375289689144564130255310941692641876839948398435199098964595949107771452_U256,
+        969740222016949440596773967833982503984708594112800332939940044608677786406_U256,
+        3347503519623546090913967157789298329631724597279569953964772637656210730790_U256,
+        3571145921803736401441156276672736645021839508229445104672304988494675363432_U256,
+        2780764407933593681085160444061914946352033471239765694284526523779881899995_U256,
+        2903741938465009844813579098411494110054305181207912483267180194853620755455_U256,
+        563257535195678631576452593104692587891001576400161897361500371435079078848_U256,
+        637772695535284962669469792857991203513345139845264021343233415998375203092_U256,
+        3055047572806581280604023262534437761778759794102184037135737185503912119962_U256,
+        552592343042689730141537166052930210602387535782701114932462931249397220399_U256,
+        376917791261273450023023491733606472858563709205836290177562681328872959034_U256,
+        2108573285449709756989537722368499898553671298332327548334999421124336601285_U256,
+        1542663846916280521098838383227865096121158489408501091484155787784772312176_U256,
+        2752706087944391674569478328606626148011034395052943987883886984735843944266_U256,
+        1528874922919652912235667321976407126945754335241950882460739984182742226095_U256,
+        65815724982139328115477647299947874776336411997940438912802928448837122459_U256,
+        2853853158499856027667522364533187394366687100727335011403649138706546107938_U256,
+        1739007337213416200740671832221141581584222643276773389405972296672094004426_U256,
+        2501789491720350571180895560886610769736794376902200173227654958714425170538_U256,
+        3123456580697686391554666769651460242269950303454047146146535877405052965567_U256,
+        3041019778316718086034613968744103370819111279537557784973711082459913596248_U256,
+        1508897505526336761784525819398234159527317391695073061168116462014737831316_U256,
+        2629235341798771686639737835591030855462543787420442874702519938642333034935_U256,
+        3130075525972325300779654721869241841438327579066988662556894720954875810354_U256,
+        3454067027558811372905129460022342631448142360797659700389627505198010611149_U256,
+        2317483475055699637181586208957769698129984981782731352085452623035261376590_U256,
+        1801005788695175209603860146375357744561057185172821681688879486308394840797_U256,
+        1551073309364401080980536345094193549027134025490414378187898914355427063424_U256,
+        2670972378373436060382098897204027810436063809567224172913193746069262720381_U256,
+        1296807513132647173733792102390967972906794653544723672751579759416211409338_U256,
+        3145178836644960625641749699639974456757871009267234541186508093272574087161_U256,
+        462040228706183677856581219166873250141438614693661178162838535804795153760_U256,
+        541855083707423070309362591125481931416017934466656931498622072979521960721_U256,
+        888642481927163050237146811398773434583128063871509251944251612604916287936_U256,
+        1649810715824106392682198502766966532008177879494434952398382788164219735586_U256,
+        2970695614408594705949762462245821066330758011452076846695533640632662631008_U256,
+        1860847351174044166348316853287381710837646584988498669743076692240542632829_U256,
+        1938964855538096263487207989552356713760155745657862385118703116721127807529_U256,
+        1312296806364181104815793898236471489720517088706815986298730849986663645475_U256,
+        417596586431427703250564938849269575709389611153294630701608394264142111066_U256,
+        2746023854662564652711871114337726788180909891031874661053090752767024705889_U256,
+        3586550107193806480652736899394404594267259862171660211489435754199984698387_U256,
+        2565739312770221427828924285032662593351168269502606562571913710038193797518_U256,
+        556837703963594500785092680092764066651630292985401694813766523507149331222_U256,
+        3458604168489878555782753443958379773035069112662420029420767215992398503120_U256,
+        1184840155708951774175988934619646093154678088632690506517598226343764892677_U256,
+        3256088563844568721374525731074891219492404571550441795301888322495284178056_U256,
+        803001217825915494791917138398268679021167827356561286554216582498118051725_U256,
+        1076466602627184396997746845664615503275391470745694583679925755423821138114_U256,
+        2195859960449575738740064466104857370511422345214461002581966059013707715185_U256,
+        848118758030663349584179341601018761208231808224499218068006132419337428198_U256,
+        200144090685672912100708447516712872523205688733659580026983177832301820860_U256,
+        798753689756965686982325320586855713723810043873789192479470642410188432576_U256,
+        484542210511542683275491524342456350125817079133678313288210266691103302635_U256,
+        2077018916590609394924679648542428410103448665050925481880429946893359200526_U256,
+        557956487493883497995796324058221411620402327239718364899428356667996358076_U256,
+        3485323274629279672080379632772259761864089345351519061511230273281424659442_U256,
+        2759562402716993012542151395900697326981644889720097283504454423870589235504_U256,
+        1345366401142965373705041320941696465055056272128698111973422706677170731749_U256,
+        15407832462128387850497311888474245245630066796797121786438822671617071729_U256,
+        112723027658964565261841783082718056496678727581969292479399683476852523489_U256,
+        916738499014928102491040833765795964850432005538652722761746652502216735427_U256,
+        2463173994158841288963318138922425112687825990994910788381050893553691278420_U256,
+        850948358911444636568854829398538044389817212613599538101075131783963952269_U256,
+        686612023756696865173393496257082069553180950961289917095031936191112274054_U256,
+        1446583772803943863874465164530305765808070557964035274072918283940633475458_U256,
+        88837938634488144333917171391132549926834601164424533850081816192711072597_U256,
+        3268871205641744343308478666945547418256828085366921126349284212296006970097_U256,
+        2278981551348399925966146938678348384286150823053308283628071122255873769757_U256,
+        380410538419712039599966841979209483157916571817250104589139921657420501586_U256,
+        2780305743396643530692414603627446557889965942879205543396452213648844391029_U256,
+        2661709324199926310263058225291901765530702748736834792066344010402425212012_U256,
+        3004305034105374505382123590297221075620444040283008695170570527980042870985_U256,
+        2691627043201002791661755222353403046488130225966879397618733235174296306216_U256,
+        3613471062349482542919652851493478380290811264423144232518390626741497331233_U256,
+        983573347635974678054946944399091542993511529601181153513379975660614129204_U256,
+        3281966400570663424581343064177579688298891674155690822592678141686041104374_U256,
+        682023214028666026640575453388566176028871482113502858050375112137036053929_U256,
+        2420516045246414628623569472351144538781055517824284842513747072467531576891_U256,
+        1110508974771610399341926406309877200058373661710485522723214129477083157770_U256,
+        1897371131209527955803037628730722322902427263952581935509081247190984468140_U256,
+        245534686567086498712082109896640204397859040196652512029481247804278099509_U256,
+        1449400114635510002573437435797997903030313312516207890469839348505505941413_U256,
+        2001340782526889453552755792876073378585853568946572668803047304964791031668_U256,
+        275315378698842812207064459634955311168799578092014497252155322057754194152_U256,
+        1845608782940932039354125915589891973534449339657253883330906506130195803729_U256,
+        927863882401674576258220689288657872709444401477442652775230349194820877861_U256,
+        2592383670151695142859266570529108880439122416034170137526740277582506085223_U256,
+        2296911899424938644752351878406874798640179097903768051062262039241286078135_U256,
+        2342819900939790417384488657177081672782126898523015290727310479532757805667_U256,
+        2717168328764917105410179661938557240064244989555722564911369209201445706282_U256,
+        2275729128865137362108103515373042013556880574831700427767477636314741707997_U256,
+        1464369731254453620996158493037449985592491319030965804513216720085289555089_U256,
+        2786744189884233652422358262940714462094127006231155629571369968012905445922_U256,
+        2180425526026301557686413630506278069404819208407072426523654219336133922948_U256,
+        625232311360743192943105721958525955881248420700240183343240634931971919850_U256,
+        1411007292653891146048769463456818444776982863178593487973410494258994222391_U256,
+        1280930402989844974717161945269369791806448906106354366754771413436281861829_U256,
+        3115007022600658350188145298799209158963540538150696118427069509205154926903_U256,
+        900228736374760813863669766089928143037275289865853678502340813214966041233_U256,
+        563487123655323247097187099061450945100125769906252964341611072028877497117_U256,
+        3068500436744850526987748397753787444137661654985660989086920457430425522426_U256,
+        1072068310727206152049984325872809405384780671070563865848197626592627325676_U256,
+        2356241603066555237067418991235837302685148822118159113140559223671316817583_U256,
+        1458733667347007021713182795067494949914041356808653261997587579867391270942_U256,
+        1877076054248898776146965457431864297491797819760518195208609600828230653428_U256,
+        3441128078947421332772288492605989650810417248700347511008106015509593006980_U256,
+        1744932971245221872904443070467513773493005216415565674873755278866640398483_U256,
+        2060129421940621426324727298688580722316826094519041345762560680317064359350_U256,
+        2457340516768680706967127197048786479415327985244534510751978186922079784171_U256,
+        2600174113484607729001229555400538159260227518359084206374073328854880882191_U256,
+        1837758821520977010403908458048425307450364545570515684335588901739491925950_U256,
+        1090576969968815398762873732285897514385522990887963133232928284333014189010_U256,
+        318345579886562756129392702273830709649776280485234183897188111670862399008_U256,
+        46043702233857156517566854493894767941370122553392196278871899283586291726_U256,
+        1223297159698828702898528207763928315564673600874040905727738989276444666291_U256,
+        1031688944312575716344499647771900596017148894434349365113755925217847926859_U256,
+        16239963985941410647706933325315919590183101300825116267736421201029053353_U256,
+        1829900173314715277735747838952323148833351231114944982766901518837985780531_U256,
+        2909979907760575739702920250642455599569863844959660731766119191122718339154_U256,
+        2067749729451160061956639704232578824850882603689156336602884606651234340418_U256,
+        3003852478402558544216309984430761083510877430760965126579963344651847503101_U256,
+        3263279529388902243106668868837687934503803092355715423822166384116622873072_U256,
+        2972209875673195708889128669818472089579256346321349521725209600812913599945_U256,
+        2843642551670718716438285345587864869147804303804444605812154295974770907019_U256,
+        1080668057960033530192186303621698294848694415577360432401567432650790808685_U256,
+        987473986941599379697776704993631007859194252848635724227511300868622983232_U256,
+        558883390873753363252275715036089286073936729532485884262352222789446208110_U256,
+        3517137667773934807567431128387267045477322300060222904358403178231598911779_U256,
+        2343484260358248783110882410818162144386827881492247094565120609327913835791_U256,
+        2321460249319275571776353533065072342664641305424677254779346481296995860161_U256,
+        2646379610747037887586439169745332117487188044231863693438647415335095750501_U256,
+        2266444573171338618816147463448292181927046710630440996974841332116553831741_U256,
+        1109240010158386113372531964769788785228921167697449109213292033578369499476_U256,
+        1827532494770195389070097236639847446474697796641174582836122943545586141982_U256,
+        1373234680682958772088373196612091513912759784571240826035800675274163233823_U256,
+        1705667461176739469609827719425128877761198239004693777128833607192373816405_U256,
+        1550948705179911666997050581965829572479767978049720853785195087161793983976_U256,
+        2722769798460760541384247717834195441086171579000545644886219660631210711342_U256,
+        2876809262128516349710907223540923329786488679280719956805608380272354188944_U256,
+        1950537010257284282413699488317766013007585911507484175884336829708557292927_U256,
+        2811435245478096237652177349818393677154643625098705632192093137707020855497_U256,
+        2799955328331935773996899028966083897323395957327010359478841686555685676400_U256,
+        3408886213958594110026447605527895170297150608543869655570177061198690542179_U256,
+        3427544207851750979572215922569068137581196944314422878896171954585917965061_U256,
+        2338161102365756633665349614653807739961908281169627646823994084342646848026_U256,
+        313553479315863199020763967473077199538123661309570216371911325329605808749_U256,
+        2407366922081954151745030420417453918759329151406070301472672948946734505152_U256,
+        2783883850456966294100696123976703833477223541188715810963596349322775299869_U256,
+        226946952121807413731026523125804251012475512531978255050323032621907766822_U256,
+        391721712488684744259511430468617952080933762129352746436687096910059822132_U256,
+        811516630816657653384524652427735066272312236847703110784821356258679429971_U256,
+        937510877672336523094793166067409746699325007238306065347278044511897015840_U256,
+        1059141291083914915754865907670990617348326496025701601721533730815293398472_U256,
+        1482599125518422575333716466238127583279447949175259815251981653971600152609_U256,
+        1897000520921213559086604622609283354320818338924642814953722287340726499914_U256,
+        1788697594319165605185542330859152995728866270927657721815758683785480460912_U256,
+        2769827895558105299216010000229925026021730968685089646774697863433062065008_U256,
+        725873137283372897433113148295350808360496072158760267143238685999184438518_U256,
+        2362340719690881126207526927697442222930552380431765172515874239460901448132_U256,
+        1663504676219047891167607344025898806293745111206251780473599453063952981389_U256,
+        388644137547076887550306029195308656268168249258976123835244570876415762990_U256,
+        3139271774209302840548585751435881544309589967229004280895324224759990662006_U256,
+        1315446598595204058375998757583455078159872757587888305157080961803470034118_U256,
+        2563623866281012361133874630842529820745755932556581463641047633549863959062_U256,
+        1495217428227908029541285319819434178741888890640026462659394622548626119092_U256,
+        2602164567111778641736082148689977615483744974130633306661254047280485273827_U256,
+        2533849500015402652903033330452038123857721937228162127526171604158589838750_U256,
+        2964669229599171910438373010931266926998328755932135593064382265079266095526_U256,
+        720181996774078307048879451185314583635921990086270656203432822444194897395_U256,
+        2674482352260124628087650811262791157247433944528850077470060840084994793883_U256,
+        2440664360189616445886223620402135217203821778929158911073101254838451350934_U256,
+        1939867708010694966264931243798915310400685466380736501892612139959251675187_U256,
+        325773793061645534168797453395384067809351586985572548112942521911845897817_U256,
+        2995313893796700006647145068012549981680790782422977718982277916099867852937_U256,
+        1645758320422104424723897852555807735261827545500295371529933008232295682208_U256,
+        1127886699469430512111171066508786495405393216907652349882812100076905540635_U256,
+        2777608257487553385781036435163963449762979760746471722330798125800964266045_U256,
+        1769109955003632511500311047582542122808964518361569594390644588420804906352_U256,
+        597370283560908566157182323201634670427569232512841032093661786741446029956_U256,
+        2555293729184710830192185086401791592642322262706908045769376312223301572726_U256,
+        1796363063193323024759394118253924744760848590322776935367365263386784510776_U256,
+        452688970729075500725927207598429931556103419749480026068898905012361197943_U256,
+        3413820034609393390954740078794891902621058901864148907829092002636719717165_U256,
+        1260882543405595953041884629724442129994597939330041899538473580590812585138_U256,
+        2532301144461930880583862058690241636256572339392574645281300583903714832630_U256,
+        2370975798932431658342798163009619376053099742564060749797730594575438534717_U256,
+        3567734778901181202858409493186555825405703879882368075671679903836250436619_U256,
+        241240104840449594847828753837590843508736168182168508122811420306977708346_U256,
+        519073655975310767851859053847951450083964101428688736357518763321278507988_U256,
+        1132798403281129182597321157228798764579989780941397329849794397852773825081_U256,
+        1524174841047369748028060997440059147352234348456108072016002330895958789093_U256,
+        1018924149700492895138876448762233322462238043499532742550716049774828137344_U256,
+        2958482039668152225179691270767050430831053132721266808593078435659704292096_U256,
+        3555166375372756272659807962884607962058626759960999598706839987922056215360_U256,
+        1643062166802484873654136425909431797627592712446907865632963206293653067252_U256,
+        3134001216546170307375107322366182628532145554551831883267835977380472622200_U256,
+        2376812451556445425857209670753507839560671840370342705748679802559651592499_U256,
+        3214134893011197060672705412129193977160815819609267923045915951916263146276_U256,
+        1389439582850647327619134682293465399424413879957137143907163634403499213105_U256,
+        1538017502634794106016089261192963357421092531313452348786752645610712324677_U256,
+        2874129822623489067765429976739901385617505996473070909443887826745489123333_U256,
+        1746371278398385403387078657651452246487459528692573418515204507216638660527_U256,
+        1171849679941398283768680123099232502710461345075610271056228132947102128433_U256,
+        1085410358462015510938524653991080084973229115658167094637657231792048226613_U256,
+        2469680530349574427344755268973857574010326837165941291577096932104442151347_U256,
+        3334208962508927486568719821771611994529030478640331201647431692687128174459_U256,
+        3579604353355039856826480464533008108617176573998029504914327854788720118918_U256,
+        2488643743987415043941777020258988334956215584433672202595132203635662561424_U256,
+        2207788882995666819634562362093722515093377274888679564430977556203307678171_U256,
+        484418745770697505840761877565073096429783401496877466497592320835275547377_U256,
+        2397040485363784788069267544921327285409012863399054535634278810317541324439_U256,
+        1627893393715320263623143193253728698363040604260723340000407599786457578039_U256,
+        2151677271308410472486587478889485650313499048083557416737935194562571877495_U256,
+        1834086094860827530076400596060925596707000051226498105638975669798118556773_U256,
+        2670802941599843768596466983394512389353480829556063221917383409735417865316_U256,
+        732470913111907939984430570448312400707370468339370913848670853564459395629_U256,
+        1293046092299508479882457282587976478870734775638384955713761962682044825782_U256,
+        3232384422412992118884524961647958373821712523145320305084927078601906397186_U256,
+        1572307745854373427831111616643169865406788752196138867514160812659314277552_U256,
+        1545390880604665330117460345165235994423361680752960017519966672535976431328_U256,
+        3230872850888057196869889591445125579801983310207335841043327704185241223541_U256,
+        708341241995550633189910454050295530343213664524589525643145680069391132628_U256,
+        1777725142792981279951327805603698678338455520795915785255472986363877999929_U256,
+        3101084272909378900240582677708921393614879280265040620337586052553289408820_U256,
+        3003770638006671696849624720967728363513022394280379307403522150691434368190_U256,
+        2596559698181470672945429070794283273963767341924609480918390790849457414313_U256,
+        2011247069853955292103207601160686013595167574389860555214765847250263519381_U256,
+        2233294971229173496544838798210815219540913298908787999308227537114590871603_U256,
+        2434389172247879911646445947965317281724765068886469127047176809532888174211_U256,
+        1668280499587576236286068347111941991056027602104946659753157614162988243526_U256,
+        3158459747739307705145232087746395587859365172846073754255582664949330943200_U256,
+        3344188643271516170298211655618582697010029100561569729728510469762615459320_U256,
+        1470214422469217677173795262601500322114389506210310046929466163781530537660_U256,
+        1030302078817169503219890155271799903415953416681429345760898307884001228819_U256,
+        1391719899976077537221937336104579176437551264874796457721814256330005191958_U256,
+        3102500862694571766708448975324444434583781722814893829486554669915786191978_U256,
+        3286609619012424332342492854243720104457745035157472934144246349076332007501_U256,
+        3100709333675821939448998675035187548373405184242458956840970643661539411649_U256,
+        371735088219497714556124308939890158770933282701407813857960369438030719762_U256,
+        3444970939740297372080795825603297290841038189227064261302122409008803313412_U256,
+        1949775378578552132622290556995383806159758815434741931369095902102478508442_U256,
+        1830792286437712700726110032589561281158554172725014405816539716278681935703_U256,
+        3388891442425516334573970012858486208463215448038681579939058836699044621455_U256,
+        710458566225216569640427113319956973635579384074308408443037734857671003990_U256,
+        3197443124955510698429005805368371754326302162822407540366855504896896238158_U256,
+        3383580094636398253873911374014028142545720418491804119343080134087949535419_U256,
+        891934542936952106596061104986266862521670422585189733804305292717185041300_U256,
+        2472692337317368703919221552307635252464713703396674007536087063306676483572_U256,
+        2709159136999583004633504553402930912814133891564192641338367458809618091575_U256,
+        2595999557197796756059876559537966876631324753641109238686040450488202039451_U256,
+        572552722469564040483940976502247604048728837991429175181816415027965766955_U256,
+        2371504136863198736777398394109792955665173681677252694595071354781373671264_U256,
+        2809577411861419444330837558371801056089426306981603116151489512396486137781_U256,
+        2393084341798153709882396953223992645566719277306131633322108854558528322792_U256,
+        2742132388370266724347843387000455741101773707415811616757126668096159760945_U256,
+        320089319954820680249702050791990105538414729737677509306045712977922662320_U256,
+        179440123606919023758092649942562371039305590965908341991686854117231336283_U256,
+        738780511320515327899080928355188808603604003956539285475853397459600871291_U256,
+        842537753178138365583378219140121387665622755890100077015145469224913338992_U256,
+        2988727435646041442848362778166737463844696404364050840183967696512050962106_U256,
+        1581261850623569192183662763998961989440105829827981758204862951552034658202_U256,
+        690815202178555675115253322945339519478776742556392245397098799370896489172_U256,
+        1286235257878716537920126474517031184989083890787626374508378309052232876662_U256,
+        597018207316317941314944282139716412182117260432373480913817380233283730409_U256,
+        3174866016992557058630114207264302129943951117899042104172880048663767480865_U256,
+        3461044316418924389254650466601316653216121851737450906621801139118123883072_U256,
+        2242762984033934606604126313446716152472936612920846681702390112023099112653_U256,
+        343194370784570178651616445531537751484323568663594538911986159245446722544_U256,
+        3114514756528342799903314389818273357093620586535006950369290792540950548143_U256,
+        569242944739796150918426912257580406071448459266274524088767552419480262972_U256,
+        1677928280920497693377344551447219667078080329896206951373147606477956715082_U256,
+        1097553908737844235958265535229990634373619429696340335643709796581905948501_U256,
+        71753133816012216552151957263957537780576526340822665968218481955373855612_U256,
+        668448966567009813665930630128422594881062430219808075067925437508935461926_U256,
+        1142896963587097415563002434763485629376449530740559310573083045915427970187_U256,
+        2250953667617754253636005804681162689925106966092150920392847573530769396955_U256,
+        2688891173757189649874840314021341449258451859364058919704532252639282751381_U256,
+        297693106461372273964869913019966964211821636064091716312674327935637675150_U256,
+        2934923053138658188352660386872102908167929835170925336496733362921632935664_U256,
+        573889303836263419307272747179571860855773394888947177628733523101781228798_U256,
+        3383887534802903227858461257902827930633698086431745675620891944845452063290_U256,
+        712315317297844722398331859405579956907019493024841445088614154186771828731_U256,
+        2999113923284555557200150660400880655969324192246671856782514093552185920004_U256,
+        551546943422742706372236855331644934653045949978220266995634752933901041009_U256,
+        1682820470671012779219320684091910680588763047194595686541005787462931212978_U256,
+        3581294608019330446925034318980325163476009915859582708981217306755177509438_U256,
+        3281933114708456787014989553038815260096076028714671054992921478477615959761_U256,
+        319514273700468202267250790420643971286985905708984998021862581087860431222_U256,
+        2062909821625636486905927434206114595555563742313963571490680668850619676401_U256,
+        1447782810861503369062260394078186954969701150693447567702402796034671341791_U256,
+        3221034869092996441797538972523343133900341162371725324820073649375220177112_U256,
+        180867420191418562136411784074887515170905951820963266105736060291146310899_U256,
+        2788374655302418113585024178009439824842563810793314157723973751707237522668_U256,
+        3314194284931002732679820984092036020987055021747614288019868449063725788109_U256,
+        1958783439574616109688986072767393880029621254070015729351640716307563937817_U256,
+        3070246608957055827633359046080079218060466106198487172381996423903033988637_U256,
+        3485599191857694730144834166320388231106816296431767398597464906822612146253_U256,
+        1249555633635858441198665032505020431956178801943440968726053680371899275096_U256,
+        3587918108247143832821857823753991977242206394459809346175457364955534941611_U256,
+        408939662152384349633428993688527350785846996826489230232602377266194349821_U256,
+        2952286793243984502915589332501836056500829095058359527747482763751338855112_U256,
+        641896870533400632866446788215895439946128379536009442597537808140929489682_U256,
+        3549560739866705580537709212424147475050122442185618040570936650145877778577_U256,
+        470222308938950125564845849693981976766059782763965025909427449727185417687_U256,
+        2982776114393869443525593346656770517201138087342618042264141388078686931184_U256,
+        622286433460238992871881937322023478383591955587788501209656125633355138884_U256,
+        1345017447048094352210336532806763170996511110662187401840311565518507816002_U256,
+        1961022276588450518366892124976605706588110121205176979914605236923501028114_U256,
+        2789969169788467031174364283144011153401236175060319829180907079067557148207_U256,
+        1059997491149255007302769637293138727607644330585149988518142646766531603627_U256,
+        1164564625460938529639873108057976580910167174481490596783940923492916125480_U256,
+        3318231128427335660331205828728564058511762742014498826738501382497940356599_U256,
+        2179142988059935292560527514271166853968495273906541179929491927552033016320_U256,
+        3348540602972911541324607646853459935030513507255498982979599591918551224492_U256,
+        1215845598196444259589870297546764569156303212315841425953999309985296271319_U256,
+        625926027566991021166407910308947748732063388572294360897754563570710763877_U256,
+        3528747945909041293553406163803198282144956593165851129259420778479268241649_U256,
+        2078296468363712946244529473822160276513132624289658685276023071052970979033_U256,
+        2265841752577135216740319619340515657082426678851656087227296868381763992900_U256,
+        3113729264107256405999770390833195172072716273120558521401597311428485132206_U256,
+        2373990457524173642275361436408267971818016964254869880567416268155265478302_U256,
+        2548042243077683189292026136750295587502420743137872561777006844185943785636_U256,
+        229522433827837458336564167724944438566053651678284502420008158008120659068_U256,
+        3614783399024623285399678178760194920442552457095348437544157960179601758793_U256,
+        2683428528570995078013948497508717927320486268089136957061643350064627042185_U256,
+        1081945080751485247459846086182103799169133283264418412358853150304803521917_U256,
+        2402475689338677837178905490204574427688861794967796204649173717179374544700_U256,
+        1262719540804724347562802433769870460594601917366900349845369590118858510990_U256,
+        1024470548825095444314900825482812935259445571029936030719714035198225218528_U256,
+        2712637171807500777157690178165709272212517797222918537672965369136342325822_U256,
+        298022565983366342386284162872234656393132686146443131039902084468671808161_U256,
+        768098647453190024118793328378667755652621408302129219831194926032503156467_U256,
+        2558513919665068582037432841574583922263057364018740108937241209712054881482_U256,
+        1465165370596896163594607556513245410258195053073071717017847749219236715497_U256,
+        1092114385495423518909539601011404175251562228088636350296667438919819701483_U256,
+        367716430503603174773800563469260405893771214446605444756395151748712025686_U256,
+        2391050828147133402029092946284045804098569684163547165380495247185726597292_U256,
+        1941204103942950728733474353451226259221238956847971821391676363813973958210_U256,
+        3304022907827788733985033669457563097595018241377893580495182950205772923396_U256,
+        3161376743887307777376451465147759751920808015967411180576282445205357738347_U256,
+        308757484368306688300123289299469520308628795012985964913728958973282704535_U256,
+        64445308105527231138646063220271571160778340166022078219854042771269305739_U256,
+        2725043393400599935631152080065907686621716985659301935204230888843633491233_U256,
+        452186835729351111712493769217087935068246060542037280260765328090552131544_U256,
+        102928421711577544358541207160707373077450162400424041943739220424250459656_U256,
+        340667884311767546000179794806389748454728830341371942365845768107224498930_U256,
+        2141260259544355212430184822552339363743407386612165137428410066608848718531_U256,
+        258414489795427975764051223330474363085351890640550706752076739154246962193_U256,
+        31261616660659864220898193384773793919172503399555841317631091464402870312_U256,
+        3169360495530549417947612899951600133444952099160522136794516696633155768434_U256,
+        3416465990777363602406877322809679852635738560230378264293957698239665621152_U256,
+        969240715599996690243510332911402422168045876542112837799672468889338251684_U256,
+        1735785546031984962040085891796305852448643823190068519326362425489963964380_U256,
+        78164896071361404644022231001652830029948542508056777717198440371096093829_U256,
+        235874877112554545494146486260718076358822332620749701859521710434136829000_U256,
+        454539116445683453743117625206655727744800245634474497280951547827156214403_U256,
+        2214658486653917571775943192804335446826073268265648065673624805479403786487_U256,
+        3043953351365758540506454812150259031533708330293977821678324553772154806323_U256,
+        3568157297156496539287599428331771424893707587933362951528716279345200261433_U256,
+        1799171624506232141062062619206987698616463973333369477793426660125186861841_U256,
+        69539031179808695109581136986287106010331377144388467736803054763115754595_U256,
+        3262855132089091187882712783239783051822643744663901757905871780128468898651_U256,
+        2955645881989905728140656034512926014454778583116140987563942751308205500823_U256,
+        3297523474491753737426778684527843680374893293652216250708240184127969061015_U256,
+        1020723280376495135357899781150221086126133488330059271279509669121583132877_U256,
+        1478629503482762108627890855586076964654649458411277776067163008456093466610_U256,
+        1550418840291458217561595871970780406768210117369197591723267540341541554886_U256,
+        361379977312320809159368944286635412894864463944072555952493272247234625122_U256,
+        130593261474910510138527138744095562819248570658455917233253205580006977021_U256,
+        2365444037999124960219941643994859210443822876756506878667636134179831266683_U256,
+        1718324333950950709124768528595914421948637683492587738158485944459876703833_U256,
+        1102526170386887395874793115267541379087194978290284941008527519847075637764_U256,
+        1149077320189010558089664568117799664456894793970620759927294464831445308002_U256,
+        867581655336839068552773965271624381409836020035181445396260438607755018717_U256,
+        2198679417057355234670620553509083956706843533454988727853683481486883934312_U256,
+        374229303637718520995800492195889944052882055655703609339988438156835534852_U256,
+        1204812257478654388028720333120329502928583304334669222969357593351838515612_U256,
+        732340111448601697311314415114907873132943752848271396941239129309122253132_U256,
+        3288426433880308753500246019073376775642398627768972187723732987884440918100_U256,
+        3086396873360304733541922791302880497778006428721266089023807888320970569217_U256,
+        947588403061842026623389666024587098188550951493735938878820032623843508771_U256,
+        2426479424793293693107172374563749444605012171872938588874207913343285406466_U256,
+        2715807552930989062498809047326857652549824996876357289072808851921372445913_U256,
+        1888550733913341152327709941569813703586276464863310147890354391219687607329_U256,
+        1909461028812757268330789023490261461859912109383441722063920197801771692362_U256,
+        423224641817107806843843022602965358118076008616167211993182017290677821262_U256,
+        1233608870271607518191277729366261382379697181580445500115349234354739750768_U256,
+        1757047100168123310657600409879606281916551736747214998472972121190585721856_U256,
+        3248111798343770292718572796260900697373054244759908898285480190334925591905_U256,
+        2043700719612139740006818997222516678462331547690875871049686961566278294984_U256,
+        2416716401688111023101949168905870573992344636190337159384216544295769121255_U256,
+        1280620108334172211576272508959590645416977469847215163147803389022163486847_U256,
+        2909586570359488987779023326134434581658507999240760880285352995264742969849_U256,
+        2238871745832999997174160027118333677422330029530745054497042139152981667775_U256,
+        3582795010722429918252989966712947248002078301846545617861956314033952862890_U256,
+        1548858789195772359952678460609188414469320544565914312212887020117174947372_U256,
+        396238089712585146745426585883666004921366832442804091083540512858749903820_U256,
+        2018046599314629552982953602112407911563717431116934244939888638016894976730_U256,
+        3531958542164768787977692579733087881861737787906421493279401840931328415547_U256,
+        3547162924848871076101487399537708078155500006025053676247600860486958732404_U256,
+        1088445957370710950820643331010329280412448579654862528056569201597957832029_U256,
+        2817677335168118103788194630267477658349473601750774268974210513626459488465_U256,
+        103691534074411205656404199510799659049882747827486991998122850603683499375_U256,
+        2850938479158322483826758434483746396770694849555000459292567586307239237206_U256,
+        1752231503040158542719446949768893204698582059230647226059082891227929793491_U256,
+        3588632540553119455102108162584277480012754180322976543995511763016528492209_U256,
+        3009896923466389384910793101985306539131711698065321270168681710512167322971_U256,
+        356293651607812207202988340533318720766822274322409901453534978274982789829_U256,
+        1425296886876126637917341138500515349562571296833360326490992753338364133667_U256,
+        1639655353170795389282272424527750113175770989477048321380454887601146646431_U256,
+        3521082390729123191367996189155902846143055045074521751416345328286808016753_U256,
+        1186428636422089922143280287002044391486325285131730650726912795120426120570_U256,
+        3280986721405623795032467720717656572393225909589009178339156211856488416756_U256,
+        1233905501594553757908132444884687444062632822977492634271714087078865293698_U256,
+        1427040582410597328482873962395687552988594898187281382114068865082324482867_U256,
+        3500927061826323996974373077907400381340940779865062684298790616340190877512_U256,
+        1911169745202936053271462929964765818398866469509025111574602485728866500666_U256,
+        696186403706135773837691558975270420780339752074360357313481151124139731770_U256,
+        2118314627368943167742729959961291183825134387521655202559681716863614523151_U256,
+        3401336670928973930687575779767392022541969303526157554738096881283804696394_U256,
+        79943124873027832493975043742534352658399000751868424787951641529982258407_U256,
+        314179497869457837476619377554956571644381213714685418516588851267565239344_U256,
+        942238212666568880473647879647966868392314833470103906914848205278269637864_U256,
+        1385953176985269330470933419112175804265299972458005168469374075633334618514_U256,
+        2545904359869533923452899816525918162878467665233692619929997614932536438514_U256,
+        3139602486109593906825696415948356465267696342227565863389986073325719313193_U256,
+        1591016497096800074608806060914550074462720173987361523141119889725555701732_U256,
+        2570341459154861525277143186724583134902499378182416025930271362488960969784_U256,
+        3184357739831326752985277971769998693490930834831668135350947070708960694680_U256,
+        2954324424952311748490082309723245926034093896829031385937750810102218200041_U256,
+        730550415711782401024246897095466611770844906639156284428287547305645743021_U256,
+        3329935275520553772345708231397836539264448908427799850710825996180576241424_U256,
+        1325342460142521606162801462442718305204816100544348302619589537429775710941_U256,
+        3115591146362382830718800075140382670989572994894497238820253697965795995798_U256,
+        1805612371722807946140298278124791492322692900220495430940629908624753256231_U256,
+        1732346559505712312529594775167470649719337882975796443296567962377402935748_U256,
+        3350656276988513321294405107688000587871060741848713479962691548413879743460_U256,
+        1699113871757844908939294255080609190543733923710579387533471123129091867105_U256,
+        2316096950209759256839948798251896464750331790696379002425212036689124824131_U256,
+        3465440521011778849942955126959528139240015309129282321399446213298771902767_U256,
+        937064503827741105053325202108297380510014848611400130649038978515294996453_U256,
+        966169227022905949199720958220184464742978742594984797382297003876395768136_U256,
+        2439123600800043965985523720658653326806032113192867578075652848451201640653_U256,
+        22050341547725998928543015018566582199303955466741982316456298200756469722_U256,
+        426105854642772289953535620900802820672626650226830035944229037131928399242_U256,
+        724050587665820524632885068394171218401433322322767918107506347716274892950_U256,
+        2762219866721015738903363297150161683392891517094522454653113930831720066297_U256,
+        2963998488410735854682154394867402192943593058494867588984176578881077306546_U256,
+        268417941587156127092040311463036391429611694048883167732046157995814671868_U256,
+        3439250066880975584332877688918137507017886388332451319412004010604559284592_U256,
+        3386133349886631209354128043015913651576475307592964852245793396320738390983_U256,
+        2632106051850956268916630683156508230665799263990395644185979839233720473724_U256,
+        2857431979436906657296976417179528140466673368967186646145537433311406493377_U256,
+        1997952090362708340416735305238196941337406102916272849600217374198419218873_U256,
+        887628624150826234438336082901062437658753368750288209296257116620405629122_U256,
+        3594327265469666237337522456487578483110565335568092608326902054837303652743_U256,
+        2577261212327866878955890579088839250086614373728809850994732726482644032465_U256,
+        2393232482077963070247532824841421398287483780095666700352470668276328156436_U256,
+        36543498637989835307636891603662627035719397321273177320657439574964656932_U256,
+        37531434543468799824445966106233093950555686700674875052550566549547996359_U256,
+        1642171270264103811612173449263128289404167428505926641382393913588553611704_U256,
+        2634974144349455134776449591292077511921003770002746718237473645451814624283_U256,
+        941433402039350225891046938067472607399883081750052397117205468016934189880_U256,
+        38197284164934159669183475221229425582700485279321863077650075621560248_U256,
+        234950389807742050173505660381592459819725060951070939681274035336699704238_U256,
+        619700527433323073377409704738550645313724707282300930119709105068958036720_U256,
+        937774721425840179822842684879245855372904234568830579736733818514549519374_U256,
+        419147827394675739947351819494743469714096431965356553706705719184852246971_U256,
+        3079080052080815207930741423624558407850857333228583646656715004013101179552_U256,
+        3253397736927520581758357833219252042666961460718784226543080167502396024741_U256,
+        2622148129117230128093060919175616221926176959461038125753522201779327653133_U256,
+        463825281936123139276330153264250285544054495073477821750754441375902917020_U256,
+        1091615280610377566672301972302909436499598356669384540248152618345262439265_U256,
+        2439037234219392431323204437749039249008410393367406347272349917286345914924_U256,
+        2125967039263726144360141455253762280302554215334352839986682740983479444427_U256,
+        1488223693085083291374065780949932294778085009459027774022859302083128865410_U256,
+        1140780579776175438229126398994722943165785664943477487816760856908696492249_U256,
+        45217899557982962999129088242624387679874430533258488429265712593688019699_U256,
+        2078804774014329792822871168924377893284443612074434031654788649358484236125_U256,
+        1576653019471747624238765881205142260725155263336579253326762393600257398610_U256,
+        1091274458405361294735906845756522176554921763285978928769206389453999209449_U256,
+        2451901175150601449700727549481712680632502821077531014026726867038612005718_U256,
+        2408503693243336107694980138930365052572287799494673928505803710945601679534_U256,
+        2281505433483437005779262749104263683982356662169617516218354071302733834586_U256,
+        1932821351188292352390854603457156334836100896757268441669142623138843431467_U256,
+        2618761567408947771839079145650532628790755417583058324560040789168195809696_U256,
+        1626491873864517534463734176385525002423762047168806642671042833914390551594_U256,
+        3404450586396359298449884765940017437813854356694672362052658652308220460148_U256,
+        3006564467842628614269669614528689777846772755623368229398602975918037753269_U256,
+        1595806975649343002981826081431089142497016744354639629648025402351982567884_U256,
+        265492135271805027589224086218233027967030368711766758245054388395498103757_U256,
+        2456510074981495285392124672292700392265863285522110423038584820172531325146_U256,
+        2432710028595999731962623565094759914059881323708964050220971622090670661733_U256,
+        914964974625237427144804917873557119568837243301847476352295413473043897208_U256,
+        1244622543165948906500965643687394290804262688032814853744683066170511066582_U256,
+        2593733322789805639867999451548553376390445965287484550918806548661255909731_U256,
+        2181634598785720996609674264377534559182862667375760777490567823435323985364_U256,
+        1891704856775174745422438793954961321195279977133479222098496378391535623195_U256,
+        3220329787022993047973225947037252619757411971051976029994230414670254682136_U256,
+        511962620497485978861532457407080458744330435173449116590621308206731537686_U256,
+        432417965443533848817474942068220288155054410072508231572716322203855991326_U256,
+        1423457634329872635862241739932075619424576926033435609652660906808781224162_U256,
+        194836015803988736403640767282843590685570999735519633513895994569971423466_U256,
+        433010711802163113560138173734694769770238479667472675234821568962048251617_U256,
+        849272313988090303766911488606628791092672145752760522755949631226169379267_U256,
+        2377150495795132594962562159409601973372969272561242793993644480730110468158_U256,
+        2574946347443204298197174802372565212395833948990273392760508305790286972531_U256,
+        1271041113453556443973306814649468659501054656066470053160201643876319183149_U256,
+        1260897423969842063765659190060974787238266567323443418250595573162047901194_U256,
+        3093798807357910606082050231321050628872470370440349784692732362213154682679_U256,
+        1114302781093251286679082517199412563179725133127340115146640853764826025029_U256,
+        1479228008834137757524835802527239085011547583830626180504535310938067543866_U256,
+        3274353958610532529957729021748867201042883754943085441699023756598379412895_U256,
+        687721990528699140141176116718078507048196619166780867085897350176567076024_U256,
+        693589434965241608131522417966110048102480710055894348466338542513773347267_U256,
+        2076924825935900454849852433384549107951558625520426108397075369787375489423_U256,
+        185916817443712552121933390961347767750133355903354635685745031724842768528_U256,
+        2407894501236017369755345398631907905827256278803574778624900404594549071587_U256,
+        262102970588655786382775634218338013141541829229589022352783738766956378140_U256,
+        2550089797195442899319432917444082699561898596385247171175882948251277822789_U256,
+        3194626147301180999516896388512150738697974534992922798666483471670011589938_U256,
+        3330868706624032239980707088087490775284453133156922921136612682301874615797_U256,
+        1580151435093577338176405153559743682300434160225344117936332341824904679911_U256,
+        1431565183699297399799224619534295179558274226069672907213348403651828783214_U256,
+        2279864190906848373496717650749282199264502639816750990896091046142769298090_U256,
+        212265911865724241291617421588455241423581805754739788804588878893990791815_U256,
+        3145777160273558089477701623240352647551069069877450586879149603381944320609_U256,
+        3434357968285654313246798107818508182030713694499265418528268894047396916577_U256,
+        1491271123337882063835538912878093820260330120422408862144929987451634240302_U256,
+        2550591409474342098869898267785028263451168636204769978189436316947152301409_U256,
+        1501605738071909356198417728610373218110019921783467412247602987479095871852_U256,
+        539666650919221316019258152571411094316059538427443892175022533693840122437_U256,
+        3221802024659286364241827036718616625063076844030721897794343320410879917023_U256,
+        2539917207880770731722009065094410321630553770684552128111381427776964208918_U256,
+        3350721663839253781576138652512855652798178856415995417835101238861501366021_U256,
+        619846680856894015120947822262399092394418341971471462983111910484394072822_U256,
+        3387004419814351642301141311105457890294444882297857179604286211855744032936_U256,
+        906040030414392365511619187598269260228447162426599643433209742258009867825_U256,
+        855498635809204900114699421246401016099688093330753991598548618098099520162_U256,
+        218955965966743890203820233922163975612772904571887124970740279962313494075_U256,
+        3207476609401521401305208591938461433387018547148013943611776300638233694640_U256,
+        161108638869030774185873110155280119546704070487002003695129632774861005781_U256,
+        1264373949868901015378341662716007900408439807999892796831510595161573914802_U256,
+        2166163173877348810732621505618320021569141987369149145299584319709537775792_U256,
+        464968108491088148961921441680373194312140398968850027602675815045413059290_U256,
+        3038243903768794622790783126090210921322480754476473424193897969945763415413_U256,
+        2771424374342878253868254535553648802677134962879785407008401315848950527233_U256,
+        1817259384192516295986988749192148451601582558106858516380371384517580628801_U256,
+        2304733169680543102424866256951161069803996027811885558195672815524162069579_U256,
+        1735992762438588662683841212367136680959598689830010272556113016583974783378_U256,
+        1728310890277347155363227222540928835519151303223243932448280997619542921276_U256,
+        545458405442221601824349346023988026306836487246165140754800253726264770061_U256,
+        3053247374449676180540979857602434988476569536935567185324493403264577550985_U256,
+        1401915983942945847641673058726165411735115513297064762436813194551273708697_U256,
+        2995725871763425995900375108110528188012629316692300258281554094629620145291_U256,
+        714663211987634461832543698859905381915156106554018876804803267855427922805_U256,
+        3592177934385498253421914526929155356645956982386755471300767071171472824935_U256,
+        1638138940664989931245106183193348845310381043506373939684875162719818610790_U256,
+        1377514406221139640668899376399217259310858830176889360145374087813644894104_U256,
+        1006359992772672391286713100072045098062870658791701704716944592528991498653_U256,
+        1019688328604752274145397498209895382004899037660955461639945712177351742981_U256,
+        2088473704823460674842711713491561746624440415087341171834038423087405303773_U256,
+        3487790860533494394824195590140178842337473968817776442570958995404850470078_U256,
+        3480236003491407916218868047093607967902485842320155095999603101964904865976_U256,
+        1166418490008441724195786612512191450116859582758667759313121580907440294251_U256,
+        1182515092192325657369378392628532888017062769307646933407626367747200108760_U256,
+        2514936836499684299747104209234209913162361964621979191149721673360695373368_U256,
+        2571703348532308430301273845542911633305279537009052678048727700830116959323_U256,
+        570946637864331796087798401841069399314794720427135032181145720034848483018_U256,
+        2217718686212346871878433076949162346254045153699512903613464690695062672541_U256,
+        321750049757800847548324555495094948602679480533661253792898587293329839207_U256,
+        416337931441982770760436488318227097315877612041548049762712519401309392056_U256,
+        1548889438855781703074539168422746991831607083190389850249053207674292595920_U256,
+        2878723523208742093195942218717998259570025876393310346045076822493465606884_U256,
+        2976008895301157548621742826857285677536261791951637494303479507529433695511_U256,
+        1418735752640762293389779561149146249286487010840402107881671299117132652189_U256,
+        708382108904492867052618920431408541101321021679271534588820355725838238199_U256,
+        3493837935089303213670106655368039937238721407342829273957474831081808732417_U256,
+        1136635456792835621908099640508837084923746811856491235096829320512972515907_U256,
+        2733874512373268628684669181418877464177094443688532366343384552637793456486_U256,
+        3100048418888171721724723158320896783160929853270127838941407932029973854423_U256,
+        732187419478085895364950334454340315194970311990260137414334704114383057377_U256,
+        1856402474163104669371508987852853443286068872075948287992842794321293893021_U256,
+        1554130736248642499421778870955858486541661232394996954835134363264265324630_U256,
+        143441912120572412147876982810512420568829302464899028200918796347648071596_U256,
+        3060919940522040057115438831927883884263655355061354541873038111986175057697_U256,
+        486039838701981868518954048219863112749535291768985175731631089012738981682_U256,
+        2383808314357674229113273965033824167453013440491093197037660177995851909954_U256,
+        2521035284505276740601918767156965811702569307571556912810734739309584809453_U256,
+        3087544874392119508220646599722345522171569553433075421427032901911106421237_U256,
+        1844150091337018939687059264382752857266763050789973104279183524821025004187_U256,
+        2776835646290962727553955502386531992814066191086433489544750832443765397536_U256,
+        1234055738138932595876659071716753886896077833401941604831739852424939751338_U256,
+        3828646945413251553688099093494408765651595654434134741930746242853382500295_U256,
+        1905137927966370364397286134079964499006699167721413497231709398472160256868_U256,
+        4408162404333749977502556012289866222350760692579248587638148147838442905307_U256,
+        3709370623220101674755180148782076034209577358299773544312321498811044231017_U256,
+        1416324679964145074096209779756868321180760711689019030671161898420902195742_U256,
+        3601729254124576822435854239290113840563723067940616685142193773120317468749_U256,
+        3151710628889997545364845393936353911068989572196175152824871369909696865038_U256,
+        3963500129653084048045414710283163433021512484687636335074828059731230191947_U256,
+        2430765206195726209336676572436119089890083014048790010173391980785123273627_U256,
+        1890808113258307389220454244186058515690679981016056831147323553124370289295_U256,
+        2619539117225106731490575043899175471249005338405084832521670352583489496809_U256,
+        1828630263978912460934119242605033086241592706949416173088891640845951710292_U256,
+        1230506402231787086008493796832460475905711965242850184365923423966302522064_U256,
+        3638449028068230703570545995089350923071764923184723270871248684188286527737_U256,
+        2272552870525428652145969038247950266129719664166982715459413193076564216619_U256,
+        1215180508728573337802886362962004269970525616563306313941865025848001371376_U256,
+        3254015255831969203229579121203484378236282673601039115774457988549475775835_U256,
+        3064237934621323167663501579521705385833036814389229824921167607353907650471_U256,
+        4578248969969184882954632128891269776219339329555241484071996859368826766929_U256,
+        3258571800946558343488155726886637465771607248609490078234113545199240300698_U256,
+        2341377511327393627608907683036016699182465537687714204671599700098442099564_U256,
+        4080498074185346844285398671457557130923899469638100295635693774342639883009_U256,
+        1827808744792933277965510277994569116010652070903425909946371132914476536330_U256,
+        3789734404458527200339619160927216938391685408817634549440917733739511434973_U256,
+        3785494631466339770908122251086457666980833739208386297401555632945802119244_U256,
+        2969468915358495909152058707266689778249072668363010080444660831101241497884_U256,
+        3409085420271087646658656784469408943357821530983468962953144734217826648392_U256,
+        2586285389959938369592859741393372033929114136965066701925624708497990451267_U256,
+        4405947986548749204891861496625723433048701506874931344888353546556930176585_U256,
+        2084389729349716100429724489925547246884071720867307021018039940962149978691_U256,
+        1914035890779615198112804328511878388724962856782786489869270145378913912626_U256,
+        4211736585625534410493930358537562713721342051828972339527038479608542500105_U256,
+        3110754458849728265555371274007748221701304560860895520621724101132480660935_U256,
+        2096150137320820514053581496386037317728053460933588498095320007664354529968_U256,
+        3281425601034739287970010328956721300788845457257625486522301948151547028784_U256,
+        3247017112667368247118978442129387549593833341548450466001553414741975135116_U256,
+        2531292182462734796364692584618645302869027942830703595612533771646418373470_U256,
+        1665543098877400781394338377778355288534373360555928150257269841941457109514_U256,
+        1987314473187124565230225884938339875182513870147982521891316368320148961194_U256,
+        2534609819937713274856416996414599803788970266717461963996841788079660969545_U256,
+        2884881813318325882929107823313567380646701536073924719706150736059557973815_U256,
+        4586183628785955845332202785043799270821580385969467919138071227185684023133_U256,
+        3514245094969883726045362035541213929082679951902061682079844652676637639109_U256,
+        1857270269664759618410842389205852841526372807932210027574710810240996384794_U256,
+        1499580180446731865164083980029107192783368677211205573649332343706032332227_U256,
+        1813115039859136997739167787206849320343779508356803391935951024604463237009_U256,
+        3007674085069823525905079267359999743191724604196205464426516279951530945917_U256,
+        4013507648161941348485696681146463081094728040645406998379905065581623586620_U256,
+        3932291340853830978826806176364561845099202302350197926847573466336948237738_U256,
+        4422812204067685601749733662321993593625504256456286653960917351078085463113_U256,
+        3435276337743672641005349214148997053283194914228664507454062859863818408270_U256,
+        1315589132337186697532185652053847359729222852971326546743592349573250734711_U256,
+        3685068798007452816106341261672769011265790680808483244039788319300558512018_U256,
+        2538207352461976153113080744834460746924938354864398063891490615812477514809_U256,
+        1687829896429309720513726227821334563412634735056638079371400581338295386054_U256,
+        1131766078694357724546946872977810289554337897427039857720205848300164963934_U256,
+        2853884129483213177763537385076740634167588085407950373784740812096184388579_U256,
+        2938865267068019312437152262901417099745474642929380153115932445351020448318_U256,
+        3863222202515099457658701377022131461153084770792160992075559718661037417681_U256,
+        1575067015468740165658496607553231191351850599913176095628194853017159781513_U256,
+        3441919892926985597893466949159172670427028852584618092926043022496788940456_U256,
+        3887668234676868047871990179844711435785408186165671601785549561123245802905_U256,
+        1549572654103029283644900131441750506754372799983272551748315899918924905661_U256,
+        3309904020493434573117345584799214911769659570275378664693122852298217231567_U256,
+        2864266555587475898050824542456324114739235545396414534843684207672071819200_U256,
+        2747691250083418048463351424776762897304753836461004118881974234596657333253_U256,
+        1893129146583460885588286860161371788512325355401228981168941602178792377892_U256,
+        2861947989650011047235540259894090835610430881925633203539029790447602673109_U256,
+        1719369519570928815759035111803817523656058093895497680297429356877475223183_U256,
+        1683197204370424184872684502284156642321294040542276703906576677243426675365_U256,
+        2193475146231193481162066139654067999893530455962705719743320852337543710054_U256,
+        4430461324293551315617564855453804735154928409928316684595340106837084050730_U256,
+        2689493489144896242715685243069172661329972019560787094141129716319164850404_U256,
+        2397305235088947471189534217666757175633168300657080272434998029991803928012_U256,
+        1151486220312041186831560051347759792355800642059308281963455863139286563336_U256,
+        3510828949712633851008502762685988816103368320654045411216653940214460694827_U256,
+        4291114249674478801176992299022970499743071910160706067766614670769349040607_U256,
+        3134104890119631904032450151515704523629773100527383193864133120991179962808_U256,
+        1106594886570236375842917022324281088229165645543836150761634760461204304642_U256,
+        1294631519669977626406498875565330361448257383252256219061346625648827271285_U256,
+        1323304625822640590549330914666832479451553530030927820720640065906764181394_U256,
+        1799998960612702287626897752663997883430370542560379957032755922927275491095_U256,
+        3769127248811776698348693007944976405238043211550539194523353133037588569998_U256,
+        4385883600737301170620074172318600517081319245647661162296260342599473214914_U256,
+        1147191913102289047682407107857478774787048681830118768996909129777768764841_U256,
+        1439900782409001644156846276459099394104418129326802804195576057034044712733_U256,
+        2295698443123685723534472876783115706956828939975710703132432711826800691912_U256,
+        4000558848709887735425831740329511933945067899617380555216780935957251875782_U256,
+        3091694535661199822946704214640677640923454408373108477660705725099232556672_U256,
+        2167301203292212019471386358038570236510071326872068342276022599658060341850_U256,
+        1575149453058159143608430966742350743475299386156758502670136275399808486385_U256,
+        1884924379287300206566730070086349037094722161372188657000402783539267283982_U256,
+        2515891858674787850483119504689812386425705843347011665652185717985920052949_U256,
+        1641226878071313027916575718614007199959463456114159301012073638457593545507_U256,
+        1556645918843703830899276220740643110166255572465133793473596129904722171102_U256,
+        3867816555022886271196202094937139215710867497153586321688181918838508473951_U256,
+        4434326144909729038814929927502518926179867743015104830304480081440030085735_U256,
+        3190931361854628470281342592162926261275061240372002513156630704349948668685_U256,
+        4123616916808572258148238830166705396822364380668462898422198011972689062034_U256,
+        2580837008656542126470942399496927290904376022983971013709187031953863415836_U256,
+        5175428215930861084282382421274582169659893844016164150808193422382306164793_U256,
+        3251919198483979894991569461860137903014997357303442906109156578001083921366_U256,
+        5754943674851359508096839340070039626359058882161277996515595327367366569805_U256,
+        5056151893737711205349463476562249438217875547881802953189768678339967895515_U256,
+        2763105950481754604690493107537041725189058901271048439548609077949825860240_U256,
+        4948510524642186353030137567070287244572021257522646094019640952649241133247_U256,
+        4498491899407607075959128721716527315077287761778204561702318549438620529536_U256,
+        5310281400170693578639698038063336837029810674269665743952275239260153856445_U256,
+        3777546476713335739930959900216292493898381203630819419050839160314046938125_U256,
+        3237589383775916919814737571966231919698978170598086240024770732653293953793_U256,
+        3966320387742716262084858371679348875257303527987114241399117532112413161307_U256,
+        3175411534496521991528402570385206490249890896531445581966338820374875374790_U256,
+        2577287672749396616602777124612633879914010154824879593243370603495226186562_U256,
+        4985230298585840234164829322869524327080063112766752679748695863717210192235_U256,
+        3619334141043038182740252366028123670138017853749012124336860372605487881117_U256,
+        2561961779246182868397169690742177673978823806145335722819312205376925035874_U256,
+        4600796526349578733823862448983657782244580863183068524651905168078399440333_U256,
+        4411019205138932698257784907301878789841335003971259233798614786882831314969_U256,
+        5925030240486794413548915456671443180227637519137270892949444038897750431427_U256,
+        4605353071464167874082439054666810869779905438191519487111560724728163965196_U256,
+        3688158781845003158203191010816190103190763727269743613549046879627365764062_U256,
+        5427279344702956374879681999237730534932197659220129704513140953871563547507_U256,
+        3174590015310542808559793605774742520018950260485455318823818312443400200828_U256,
+        5136515674976136730933902488707390342399983598399663958318364913268435099471_U256,
+        5132275901983949301502405578866631070989131928790415706279002812474725783742_U256,
+        4316250185876105439746342035046863182257370857945039489322108010630165162382_U256,
+        4755866690788697177252940112249582347366119720565498371830591913746750312890_U256,
+        3933066660477547900187143069173545437937412326547096110803071888026914115765_U256,
+        5752729257066358735486144824405896837056999696456960753765800726085853841083_U256,
+        3431170999867325631024007817705720650892369910449336429895487120491073643189_U256,
+        3260817161297224728707087656292051792733261046364815898746717324907837577124_U256,
+        5558517856143143941088213686317736117729640241411001748404485659137466164603_U256,
+        4457535729367337796149654601787921625709602750442924929499171280661404325433_U256,
+        3442931407838430044647864824166210721736351650515617906972767187193278194466_U256,
+        4628206871552348818564293656736894704797143646839654895399749127680470693282_U256,
+        4593798383184977777713261769909560953602131531130479874879000594270898799614_U256,
+        3878073452980344326958975912398818706877326132412733004489980951175342037968_U256,
+        3012324369395010311988621705558528692542671550137957559134717021470380774012_U256,
+        3334095743704734095824509212718513279190812059730011930768763547849072625692_U256,
+        3881391090455322805450700324194773207797268456299491372874288967608584634043_U256,
+        4231663083835935413523391151093740784654999725655954128583597915588481638313_U256,
+        5932964899303565375926486112823972674829878575551497328015518406714607687631_U256,
+        4861026365487493256639645363321387333090978141484091090957291832205561303607_U256,
+        3204051540182369149005125716986026245534670997514239436452157989769920049292_U256,
+        2846361450964341395758367307809280596791666866793234982526779523234955996725_U256,
+        3159896310376746528333451114987022724352077697938832800813398204133386901507_U256,
+        4354455355587433056499362595140173147200022793778234873303963459480454610415_U256,
+        5360288918679550879079980008926636485103026230227436407257352245110547251118_U256,
+        5279072611371440509421089504144735249107500491932227335725020645865871902236_U256,
+        5769593474585295132344016990102166997633802446038316062838364530607009127611_U256,
+        4782057608261282171599632541929170457291493103810693916331510039392742072768_U256,
+        2662370402854796228126468979834020763737521042553355955621039529102174399209_U256,
+        5031850068525062346700624589452942415274088870390512652917235498829482176516_U256,
+        3884988622979585683707364072614634150933236544446427472768937795341401179307_U256,
+        3034611166946919251108009555601507967420932924638667488248847760867219050552_U256,
+        2478547349211967255141230200757983693562636087009069266597653027829088628432_U256,
+        4200665400000822708357820712856914038175886274989979782662187991625108053077_U256,
+        4285646537585628843031435590681590503753772832511409561993379624879944112816_U256,
+        5210003473032708988252984704802304865161382960374190400953006898189961082179_U256,
+        2921848285986349696252779935333404595360148789495205504505642032546083446011_U256,
+        4788701163444595128487750276939346074435327042166647501803490202025712604954_U256,
+        5234449505194477578466273507624884839793706375747701010662996740652169467403_U256,
+        2896353924620638814239183459221923910762670989565301960625763079447848570159_U256,
+        4656685291011044103711628912579388315777957759857408073570570031827140896065_U256,
+        4211047826105085428645107870236497518747533734978443943721131387200995483698_U256,
+        4094472520601027579057634752556936301313052026043033527759421414125580997751_U256,
+        3239910417101070416182570187941545192520623544983258390046388781707716042390_U256,
+        4208729260167620577829823587674264239618729071507662612416476969976526337607_U256,
+        3066150790088538346353318439583990927664356283477527089174876536406398887681_U256,
+        3029978474888033715466967830064330046329592230124306112784023856772350339863_U256,
+        3540256416748803011756349467434241403901828645544735128620768031866467374552_U256,
+        5777242594811160846211848183233978139163226599510346093472787286366007715228_U256,
+        4036274759662505773309968570849346065338270209142816503018576895848088514902_U256,
+        3744086505606557001783817545446930579641466490239109681312445209520727592510_U256,
+        2498267490829650717425843379127933196364098831641337690840903042668210227834_U256,
+        4857610220230243381602786090466162220111666510236074820094101119743384359325_U256,
+        5637895520192088331771275626803143903751370099742735476644061850298272705105_U256,
+        4480886160637241434626733479295877927638071290109412602741580300520103627306_U256,
+        2453376157087845906437200350104454492237463835125865559639081939990127969140_U256,
+        2641412790187587157000782203345503765456555572834285627938793805177750935783_U256,
+        2670085896340250121143614242447005883459851719612957229598087245435687845892_U256,
+        3146780231130311818221181080444171287438668732142409365910203102456199155593_U256,
+        5115908519329386228942976335725149809246341401132568603400800312566512234496_U256,
+        5732664871254910701214357500098773921089617435229690571173707522128396879412_U256,
+        2493973183619898578276690435637652178795346871412148177874356309306692429339_U256,
+        2786682052926611174751129604239272798112716318908832213073023236562968377231_U256,
+        3642479713641295254128756204563289110965127129557740112009879891355724356410_U256,
+        5347340119227497266020115068109685337953366089199409964094228115486175540280_U256,
+        4438475806178809353540987542420851044931752597955137886538152904628156221170_U256,
+        3514082473809821550065669685818743640518369516454097751153469779186984006348_U256,
+        2921930723575768674202714294522524147483597575738787911547583454928732150883_U256,
+        3231705649804909737161013397866522441103020350954218065877849963068190948480_U256,
+        3862673129192397381077402832469985790434004032929041074529632897514843717447_U256,
+        2988008148588922558510859046394180603967761645696188709889520817986517210005_U256,
+        3211740605238284078996478257699057613376724040769675829757109208459654066095_U256,
+        2720930602693491125470299279135854816357815996065726531018977784795890450953_U256,
+        3287440192580333893089027111701234526826816241927245039635275947397412062737_U256,
+        2044045409525233324555439776361641861922009739284142722487426570307330645687_U256,
+        2976730964479177112422336014365420997469312879580603107752993877930071039036_U256,
+        1433951056327146980745039583695642891551324521896111223039982897911245392838_U256,
+        4028542263601465938556479605473297770306842342928304360138989288339688141795_U256,
+        2105033246154584749265666646058853503661945856215583115439952443958465898368_U256,
+        4608057722521964362370936524268755227006007381073418205846391193324748546807_U256,
+        3909265941408316059623560660760965038864824046793943162520564544297349872517_U256,
+        1616219998152359458964590291735757325836007400183188648879404943907207837242_U256,
+        3801624572312791207304234751269002845218969756434786303350436818606623110249_U256,
+        3351605947078211930233225905915242915724236260690344771033114415396002506538_U256,
+        4163395447841298432913795222262052437676759173181805953283071105217535833447_U256,
+        2630660524383940594205057084415008094545329702542959628381635026271428915127_U256,
+        2090703431446521774088834756164947520345926669510226449355566598610675930795_U256,
+        2819434435413321116358955555878064475904252026899254450729913398069795138309_U256,
+        2028525582167126845802499754583922090896839395443585791297134686332257351792_U256,
+        1430401720420001470876874308811349480560958653737019802574166469452608163564_U256,
+        3838344346256445088438926507068239927727011611678892889079491729674592169237_U256,
+        2472448188713643037014349550226839270784966352661152333667656238562869858119_U256,
+        1415075826916787722671266874940893274625772305057475932150108071334307012876_U256,
+        3453910574020183588097959633182373382891529362095208733982701034035781417335_U256,
+        3264133252809537552531882091500594390488283502883399443129410652840213291971_U256,
+        4778144288157399267823012640870158780874586018049411102280239904855132408429_U256,
+        3458467119134772728356536238865526470426853937103659696442356590685545942198_U256,
+        2541272829515608012477288195014905703837712226181883822879842745584747741064_U256,
+        4280393392373561229153779183436446135579146158132269913843936819828945524509_U256,
+        2027704062981147662833890789973458120665898759397595528154614178400782177830_U256,
+        3989629722646741585207999672906105943046932097311804167649160779225817076473_U256,
+        3985389949654554155776502763065346671636080427702555915609798678432107760744_U256,
+        3169364233546710294020439219245578782904319356857179698652903876587547139384_U256,
+        3608980738459302031527037296448297948013068219477638581161387779704132289892_U256,
+        2786180708148152754461240253372261038584360825459236320133867753984296092767_U256,
+        4605843304736963589760242008604612437703948195369100963096596592043235818085_U256,
+        2284285047537930485298105001904436251539318409361476639226282986448455620191_U256,
+        2113931208967829582981184840490767393380209545276956108077513190865219554126_U256,
+        4411631903813748795362310870516451718376588740323141957735281525094848141605_U256,
+        3310649777037942650423751785986637226356551249355065138829967146618786302435_U256,
+        2296045455509034898921962008364926322383300149427758116303563053150660171468_U256,
+        3481320919222953672838390840935610305444092145751795104730544993637852670284_U256,
+        3446912430855582631987358954108276554249080030042620084209796460228280776616_U256,
+        2731187500650949181233073096597534307524274631324873213820776817132724014970_U256,
+        1865438417065615166262718889757244293189620049050097768465512887427762751014_U256,
+        2187209791375338950098606396917228879837760558642152140099559413806454602694_U256,
+        2734505138125927659724797508393488808444216955211631582205084833565966611045_U256,
+        3084777131506540267797488335292456385301948224568094337914393781545863615315_U256,
+        4786078946974170230200583297022688275476827074463637537346314272671989664633_U256,
+        3714140413158098110913742547520102933737926640396231300288087698162943280609_U256,
+        2057165587852974003279222901184741846181619496426379645782953855727302026294_U256,
+        1699475498634946250032464492007996197438615365705375191857575389192337973727_U256,
+        2013010358047351382607548299185738324999026196850973010144194070090768878509_U256,
+        3207569403258037910773459779338888747846971292690375082634759325437836587417_U256,
+        4213402966350155733354077193125352085749974729139576616588148111067929228120_U256,
+        4132186659042045363695186688343450849754448990844367545055816511823253879238_U256,
+        4622707522255899986618114174300882598280750944950456272169160396564391104613_U256,
+        3635171655931887025873729726127886057938441602722834125662305905350124049770_U256,
+        1515484450525401082400566164032736364384469541465496164951835395059556376211_U256,
+        3884964116195667200974721773651658015921037369302652862248031364786864153518_U256,
+        2738102670650190537981461256813349751580185043358567682099733661298783156309_U256,
+        1887725214617524105382106739800223568067881423550807697579643626824601027554_U256,
+        1331661396882572109415327384956699294209584585921209475928448893786470605434_U256,
+        3053779447671427562631917897055629638822834773902119991992983857582490030079_U256,
+        3138760585256233697305532774880306104400721331423549771324175490837326089818_U256,
+        4063117520703313842527081889001020465808331459286330610283802764147343059181_U256,
+        1774962333656954550526877119532120196007097288407345713836437898503465423013_U256,
+        3641815211115199982761847461138061675082275541078787711134286067983094581956_U256,
+        4087563552865082432740370691823600440440654874659841219993792606609551444405_U256,
+        1749467972291243668513280643420639511409619488477442169956558945405230547161_U256,
+        3509799338681648957985726096778103916424906258769548282901365897784522873067_U256,
+        3064161873775690282919205054435213119394482233890584153051927253158377460700_U256,
+        2947586568271632433331731936755651901960000524955173737090217280082962974753_U256,
+        2093024464771675270456667372140260793167572043895398599377184647665098019392_U256,
+        3061843307838225432103920771872979840265677570419802821747272835933908314609_U256,
+        1919264837759143200627415623782706528311304782389667298505672402363780864683_U256,
+        1883092522558638569741065014263045646976540729036446322114819722729732316865_U256,
+        2393370464419407866030446651632957004548777144456875337951563897823849351554_U256,
+        4630356642481765700485945367432693739810175098422486302803583152323389692230_U256,
+        2889388807333110627584065755048061665985218708054956712349372761805470491904_U256,
+        2597200553277161856057914729645646180288414989151249890643241075478109569512_U256,
+        1351381538500255571699940563326648797011047330553477900171698908625592204836_U256,
+        3710724267900848235876883274664877820758615009148215029424896985700766336327_U256,
+        4491009567862693186045372811001859504398318598654875685974857716255654682107_U256,
+        3334000208307846288900830663494593528285019789021552812072376166477485604308_U256,
+        1306490204758450760711297534303170092884412334038005768969877805947509946142_U256,
+        1494526837858192011274879387544219366103504071746425837269589671135132912785_U256,
+        1523199944010854975417711426645721484106800218525097438928883111393069822894_U256,
+        1999894278800916672495278264642886888085617231054549575240998968413581132595_U256,
+        3969022566999991083217073519923865409893289900044708812731596178523894211498_U256,
+        4585778918925515555488454684297489521736565934141830780504503388085778856414_U256,
+        1347087231290503432550787619836367779442295370324288387205152175264074406341_U256,
+        1639796100597216029025226788437988398759664817820972422403819102520350354233_U256,
+        2495593761311900108402853388762004711612075628469880321340675757313106333412_U256,
+        4200454166898102120294212252308400938600314588111550173425023981443557517282_U256,
+        3291589853849414207815084726619566645578701096867278095868948770585538198172_U256,
+        2367196521480426404339766870017459241165318015366237960484265645144365983350_U256,
+        1775044771246373528476811478721239748130546074650928120878379320886114127885_U256,
+        2084819697475514591435110582065238041749968849866358275208645829025572925482_U256,
+        2715787176863002235351500016668701391080952531841181283860428763472225694449_U256,
+        1841122196259527412784956230592896204614710144608328919220316683943899187007_U256,
+        417110095351064887956295463701916076510302063519324766218646459688200044775_U256,
+        2267051608473005582213588856325606232685652869299670782391545050560531925267_U256,
+        2833561198359848349832316688890985943154653115161189291007843213162053537051_U256,
+        1590166415304747781298729353551393278249846612518086973859993836071972120001_U256,
+        2522851970258691569165625591555172413797149752814547359125561143694712513350_U256,
+        980072062106661437488329160885394307879161395130055474412550163675886867152_U256,
+        3574663269380980395299769182663049186634679216162248611511556554104329616109_U256,
+        1651154251934099206008956223248604919989782729449527366812519709723107372682_U256,
+        4154178728301478819114226101458506643333844254307362457218958459089390021121_U256,
+        3455386947187830516366850237950716455192660920027887413893131810061991346831_U256,
+        1162341003931873915707879868925508742163844273417132900251972209671849311556_U256,
+        3347745578092305664047524328458754261546806629668730554723004084371264584563_U256,
+        2897726952857726386976515483104994332052073133924289022405681681160643980852_U256,
+        3709516453620812889657084799451803854004596046415750204655638370982177307761_U256,
+        2176781530163455050948346661604759510873166575776903879754202292036070389441_U256,
+        1636824437226036230832124333354698936673763542744170700728133864375317405109_U256,
+        2365555441192835573102245133067815892232088900133198702102480663834436612623_U256,
+        1574646587946641302545789331773673507224676268677530042669701952096898826106_U256,
+        976522726199515927620163886001100896888795526970964053946733735217249637878_U256,
+        3384465352035959545182216084257991344054848484912837140452058995439233643551_U256,
+        2018569194493157493757639127416590687112803225895096585040223504327511332433_U256,
+        961196832696302179414556452130644690953609178291420183522675337098948487190_U256,
+        3000031579799698044841249210372124799219366235329152985355268299800422891649_U256,
+        2810254258589052009275171668690345806816120376117343694501977918604854766285_U256,
+        4324265293936913724566302218059910197202422891283355353652807170619773882743_U256,
+        3004588124914287185099825816055277886754690810337603947814923856450187416512_U256,
+        2087393835295122469220577772204657120165549099415828074252410011349389215378_U256,
+        3826514398153075685897068760626197551906983031366214165216504085593586998823_U256,
+        1573825068760662119577180367163209536993735632631539779527181444165423652144_U256,
+        3535750728426256041951289250095857359374768970545748419021728044990458550787_U256,
+        3531510955434068612519792340255098087963917300936500166982365944196749235058_U256,
+        2715485239326224750763728796435330199232156230091123950025471142352188613698_U256,
+        3155101744238816488270326873638049364340905092711582832533955045468773764206_U256,
+        2332301713927667211204529830562012454912197698693180571506435019748937567081_U256,
+        4151964310516478046503531585794363854031785068603045214469163857807877292399_U256,
+        1830406053317444942041394579094187667867155282595420890598850252213097094505_U256,
+        1660052214747344039724474417680518809708046418510900359450080456629861028440_U256,
+        3957752909593263252105600447706203134704425613557086209107848790859489615919_U256,
+        2856770782817457107167041363176388642684388122589009390202534412383427776749_U256,
+        1842166461288549355665251585554677738711137022661702367676130318915301645782_U256,
+        3027441925002468129581680418125361721771929018985739356103112259402494144598_U256,
+        2993033436635097088730648531298027970576916903276564335582363725992922250930_U256,
+        2277308506430463637976362673787285723852111504558817465193344082897365489284_U256,
+        1411559422845129623006008466946995709517456922284042019838080153192404225328_U256,
+        1733330797154853406841895974106980296165597431876096391472126679571096077008_U256,
+        2280626143905442116468087085583240224772053828445575833577652099330608085359_U256,
+        2630898137286054724540777912482207801629785097802038589286961047310505089629_U256,
+        4332199952753684686943872874212439691804663947697581788718881538436631138947_U256,
+        3260261418937612567657032124709854350065763513630175551660654963927584754923_U256,
+        1603286593632488460022512478374493262509456369660323897155521121491943500608_U256,
+        1245596504414460706775754069197747613766452238939319443230142654956979448041_U256,
+        1559131363826865839350837876375489741326863070084917261516761335855410352823_U256,
+        2753690409037552367516749356528640164174808165924319334007326591202478061731_U256,
+        3759523972129670190097366770315103502077811602373520867960715376832570702434_U256,
+        3678307664821559820438476265533202266082285864078311796428383777587895353552_U256,
+        4168828528035414443361403751490634014608587818184400523541727662329032578927_U256,
+        3181292661711401482617019303317637474266278475956778377034873171114765524084_U256,
+        1061605456304915539143855741222487780712306414699440416324402660824197850525_U256,
+        3431085121975181657718011350841409432248874242536597113620598630551505627832_U256,
+        2284223676429704994724750834003101167908021916592511933472300927063424630623_U256,
+        1433846220397038562125396316989974984395718296784751948952210892589242501868_U256,
+        877782402662086566158616962146450710537421459155153727301016159551112079748_U256,
+        2599900453450942019375207474245381055150671647136064243365551123347131504393_U256,
+        2684881591035748154048822352070057520728558204657494022696742756601967564132_U256,
+        3609238526482828299270371466190771882136168332520274861656370029911984533495_U256,
+        1321083339436469007270166696721871612334934161641289965209005164268106897327_U256,
+        3187936216894714439505137038327813091410112414312731962506853333747736056270_U256,
+        3633684558644596889483660269013351856768491747893785471366359872374192918719_U256,
+        1295588978070758125256570220610390927737456361711386421329126211169872021475_U256,
+        3055920344461163414729015673967855332752743132003492534273933163549164347381_U256,
+        2610282879555204739662494631624964535722319107124528404424494518923018935014_U256,
+        2493707574051146890075021513945403318287837398189117988462784545847604449067_U256,
+        1639145470551189727199956949330012209495408917129342850749751913429739493706_U256,
+        2607964313617739888847210349062731256593514443653747073119840101698549788923_U256,
+        1465385843538657657370705200972457944639141655623611549878239668128422338997_U256,
+        1429213528338153026484354591452797063304377602270390573487386988494373791179_U256,
+        1939491470198922322773736228822708420876614017690819589324131163588490825868_U256,
+        4176477648261280157229234944622445156138011971656430554176150418088031166544_U256,
+        2435509813112625084327355332237813082313055581288900963721940027570111966218_U256,
+        2143321559056676312801204306835397596616251862385194142015808341242751043826_U256,
+        897502544279770028443230140516400213338884203787422151544266174390233679150_U256,
+        3256845273680362692620172851854629237086451882382159280797464251465407810641_U256,
+        4037130573642207642788662388191610920726155471888819937347424982020296156421_U256,
+        2880121214087360745644120240684344944612856662255497063444943432242127078622_U256,
+        852611210537965217454587111492921509212249207271950020342445071712151420456_U256,
+        1040647843637706468018168964733970782431340944980370088642156936899774387099_U256,
+        1069320949790369432161001003835472900434637091759041690301450377157711297208_U256,
+        1546015284580431129238567841832638304413454104288493826613566234178222606909_U256,
+        3515143572779505539960363097113616826221126773278653064104163444288535685812_U256,
+        4131899924705030012231744261487240938064402807375775031877070653850420330728_U256,
+        893208237070017889294077197026119195770132243558232638577719441028715880655_U256,
+        1185917106376730485768516365627739815087501691054916673776386368284991828547_U256,
+        2041714767091414565146142965951756127939912501703824572713243023077747807726_U256,
+        3746575172677616577037501829498152354928151461345494424797591247208198991596_U256,
+        2837710859628928664558374303809318061906537970101222347241516036350179672486_U256,
+        1913317527259940861083056447207210657493154888600182211856832910909007457664_U256,
+        1321165777025887985220101055910991164458382947884872372250946586650755602199_U256,
+        1630940703255029048178400159254989458077805723100302526581213094790214399796_U256,
+        2261908182642516692094789593858452807408789405075125535232996029236867168763_U256,
+        1387243202039041869528245807782647620942547017842273170592883949708540661321_U256,
+        265817097277569706870725322765166548619581021263972850009502214943080536213_U256,
+        4046431190499565081022375943798159464416760859412908805224170739340084988045_U256,
+        4612940780386407848641103776363539174885761105274427313840468901941606599829_U256,
+        3369545997331307280107516441023946509980954602631324996692619524851525182779_U256,
+        4302231552285251067974412679027725645528257742927785381958186832474265576128_U256,
+        2759451644133220936297116248357947539610269385243293497245175852455439929930_U256,
+        5354042851407539894108556270135602418365787206275486634344182242883882678887_U256,
+        3430533833960658704817743310721158151720890719562765389645145398502660435460_U256,
+        5933558310328038317923013188931059875064952244420600480051584147868943083899_U256,
+        5234766529214390015175637325423269686923768910141125436725757498841544409609_U256,
+        2941720585958433414516666956398061973894952263530370923084597898451402374334_U256,
+        5127125160118865162856311415931307493277914619781968577555629773150817647341_U256,
+        4677106534884285885785302570577547563783181124037527045238307369940197043630_U256,
+        5488896035647372388465871886924357085735704036528988227488264059761730370539_U256,
+        3956161112190014549757133749077312742604274565890141902586827980815623452219_U256,
+        3416204019252595729640911420827252168404871532857408723560759553154870467887_U256,
+        4144935023219395071911032220540369123963196890246436724935106352613989675401_U256,
+        3354026169973200801354576419246226738955784258790768065502327640876451888884_U256,
+        2755902308226075426428950973473654128619903517084202076779359423996802700656_U256,
+        5163844934062519043991003171730544575785956475026075163284684684218786706329_U256,
+        3797948776519716992566426214889143918843911216008334607872849193107064395211_U256,
+        2740576414722861678223343539603197922684717168404658206355301025878501549968_U256,
+        4779411161826257543650036297844678030950474225442391008187893988579975954427_U256,
+        4589633840615611508083958756162899038547228366230581717334603607384407829063_U256,
+        6103644875963473223375089305532463428933530881396593376485432859399326945521_U256,
+        4783967706940846683908612903527831118485798800450841970647549545229740479290_U256,
+        3866773417321681968029364859677210351896657089529066097085035700128942278156_U256,
+        5605893980179635184705855848098750783638091021479452188049129774373140061601_U256,
+        3353204650787221618385967454635762768724843622744777802359807132944976714922_U256,
+        5315130310452815540760076337568410591105876960658986441854353733770011613565_U256,
+        5310890537460628111328579427727651319695025291049738189814991632976302297836_U256,
+        4494864821352784249572515883907883430963264220204361972858096831131741676476_U256,
+        4934481326265375987079113961110602596072013082824820855366580734248326826984_U256,
+        4111681295954226710013316918034565686643305688806418594339060708528490629859_U256,
+        5931343892543037545312318673266917085762893058716283237301789546587430355177_U256,
+        3609785635344004440850181666566740899598263272708658913431475940992650157283_U256,
+        3439431796773903538533261505153072041439154408624138382282706145409414091218_U256,
+        5737132491619822750914387535178756366435533603670324231940474479639042678697_U256,
+        4636150364844016605975828450648941874415496112702247413035160101162980839527_U256,
+        3621546043315108854474038673027230970442245012774940390508756007694854708560_U256,
+        4806821507029027628390467505597914953503037009098977378935737948182047207376_U256,
+        4772413018661656587539435618770581202308024893389802358414989414772475313708_U256,
+        4056688088457023136785149761259838955583219494672055488025969771676918552062_U256,
+        3190939004871689121814795554419548941248564912397280042670705841971957288106_U256,
+        3512710379181412905650683061579533527896705421989334414304752368350649139786_U256,
+        4060005725932001615276874173055793456503161818558813856410277788110161148137_U256,
+        4410277719312614223349564999954761033360893087915276612119586736090058152407_U256,
+        6111579534780244185752659961684992923535771937810819811551507227216184201725_U256,
+        5039641000964172066465819212182407581796871503743413574493280652707137817701_U256,
+        3382666175659047958831299565847046494240564359773561919988146810271496563386_U256,
+        3024976086441020205584541156670300845497560229052557466062768343736532510819_U256,
+        3338510945853425338159624963848042973057971060198155284349387024634963415601_U256,
+        4533069991064111866325536444001193395905916156037557356839952279982031124509_U256,
+        5538903554156229688906153857787656733808919592486758890793341065612123765212_U256,
+        5457687246848119319247263353005755497813393854191549819261009466367448416330_U256,
+        5948208110061973942170190838963187246339695808297638546374353351108585641705_U256,
+        4960672243737960981425806390790190705997386466070016399867498859894318586862_U256,
+        2840985038331475037952642828695041012443414404812678439157028349603750913303_U256,
+        5210464704001741156526798438313962663979982232649835136453224319331058690610_U256,
+        4063603258456264493533537921475654399639129906705749956304926615842977693401_U256,
+        3213225802423598060934183404462528216126826286897989971784836581368795564646_U256,
+        2657161984688646064967404049619003942268529449268391750133641848330665142526_U256,
+        4379280035477501518183994561717934286881779637249302266198176812126684567171_U256,
+        4464261173062307652857609439542610752459666194770732045529368445381520626910_U256,
+        5388618108509387798079158553663325113867276322633512884488995718691537596273_U256,
+        3100462921463028506078953784194424844066042151754527988041630853047659960105_U256,
+        4967315798921273938313924125800366323141220404425969985339479022527289119048_U256,
+        5413064140671156388292447356485905088499599738007023494198985561153745981497_U256,
+        3074968560097317624065357308082944159468564351824624444161751899949425084253_U256,
+        4835299926487722913537802761440408564483851122116730557106558852328717410159_U256,
+        4389662461581764238471281719097517767453427097237766427257120207702571997792_U256,
+        4273087156077706388883808601417956550018945388302356011295410234627157511845_U256,
+        3418525052577749226008744036802565441226516907242580873582377602209292556484_U256,
+        4387343895644299387655997436535284488324622433766985095952465790478102851701_U256,
+        3244765425565217156179492288445011176370249645736849572710865356907975401775_U256,
+        3208593110364712525293141678925350295035485592383628596320012677273926853957_U256,
+        3718871052225481821582523316295261652607722007804057612156756852368043888646_U256,
+        5955857230287839656038022032094998387869119961769668577008776106867584229322_U256,
+        4214889395139184583136142419710366314044163571402138986554565716349665028996_U256,
+        3922701141083235811609991394307950828347359852498432164848434030022304106604_U256,
+        2676882126306329527252017227988953445069992193900660174376891863169786741928_U256,
+        5036224855706922191428959939327182468817559872495397303630089940244960873419_U256,
+        5816510155668767141597449475664164152457263462002057960180050670799849219199_U256,
+        4659500796113920244452907328156898176343964652368735086277569121021680141400_U256,
+        2631990792564524716263374198965474740943357197385188043175070760491704483234_U256,
+        2820027425664265966826956052206524014162448935093608111474782625679327449877_U256,
+        2848700531816928930969788091308026132165745081872279713134076065937264359986_U256,
+        3325394866606990628047354929305191536144562094401731849446191922957775669687_U256,
+        5294523154806065038769150184586170057952234763391891086936789133068088748590_U256,
+        5911279506731589511040531348959794169795510797489013054709696342629973393506_U256,
+        2672587819096577388102864284498672427501240233671470661410345129808268943433_U256,
+        2965296688403289984577303453100293046818609681168154696609012057064544891325_U256,
+        3821094349117974063954930053424309359671020491817062595545868711857300870504_U256,
+        5525954754704176075846288916970705586659259451458732447630216935987752054374_U256,
+        4617090441655488163367161391281871293637645960214460370074141725129732735264_U256,
+        3692697109286500359891843534679763889224262878713420234689458599688560520442_U256,
+        3100545359052447484028888143383544396189490937998110395083572275430308664977_U256,
+        3410320285281588546987187246727542689808913713213540549413838783569767462574_U256,
+        4041287764669076190903576681331006039139897395188363558065621718016420231541_U256,
+        3166622784065601368337032895255200852673655007955511193425509638488093724099_U256,
+        2065111220841799944372761946287707661070986089745584424278074796581555563966_U256,
+        1727415587528873811363220915033241508479601588925220371398380224635978358841_U256,
+        2293925177415716578981948747598621218948601834786738880014678387237499970625_U256,
+        1050530394360616010448361412259028554043795332143636562866829010147418553575_U256,
+        1983215949314559798315257650262807689591098472440096948132396317770158946924_U256,
+        440436041162529666637961219593029583673110114755605063419385337751333300726_U256,
+        3035027248436848624449401241370684462428627935787798200518391728179776049683_U256,
+        1111518230989967435158588281956240195783731449075076955819354883798553806256_U256,
+        3614542707357347048263858160166141919127792973932912046225793633164836454695_U256,
+        2915750926243698745516482296658351730986609639653437002899966984137437780405_U256,
+        622704982987742144857511927633144017957792993042682489258807383747295745130_U256,
+        2808109557148173893197156387166389537340755349294280143729839258446711018137_U256,
+        2358090931913594616126147541812629607846021853549838611412516855236090414426_U256,
+        3169880432676681118806716858159439129798544766041299793662473545057623741335_U256,
+        1637145509219323280097978720312394786667115295402453468761037466111516823015_U256,
+        1097188416281904459981756392062334212467712262369720289734969038450763838683_U256,
+        1825919420248703802251877191775451168026037619758748291109315837909883046197_U256,
+        1035010567002509531695421390481308783018624988303079631676537126172345259680_U256,
+        436886705255384156769795944708736172682744246596513642953568909292696071452_U256,
+        2844829331091827774331848142965626619848797204538386729458894169514680077125_U256,
+        1478933173549025722907271186124225962906751945520646174047058678402957766007_U256,
+        421560811752170408564188510838279966747557897916969772529510511174394920764_U256,
+        2460395558855566273990881269079760075013314954954702574362103473875869325223_U256,
+        2270618237644920238424803727397981082610069095742893283508813092680301199859_U256,
+        3784629272992781953715934276767545472996371610908904942659642344695220316317_U256,
+        2464952103970155414249457874762913162548639529963153536821759030525633850086_U256,
+        1547757814350990698370209830912292395959497819041377663259245185424835648952_U256,
+        3286878377208943915046700819333832827700931750991763754223339259669033432397_U256,
+        1034189047816530348726812425870844812787684352257089368534016618240870085718_U256,
+        2996114707482124271100921308803492635168717690171298008028563219065904984361_U256,
+        2991874934489936841669424398962733363757866020562049755989201118272195668632_U256,
+        2175849218382092979913360855142965475026104949716673539032306316427635047272_U256,
+        2615465723294684717419958932345684640134853812337132421540790219544220197780_U256,
+        1792665692983535440354161889269647730706146418318730160513270193824384000655_U256,
+        3612328289572346275653163644501999129825733788228594803475999031883323725973_U256,
+        1290770032373313171191026637801822943661104002220970479605685426288543528079_U256,
+        1120416193803212268874106476388154085501995138136449948456915630705307462014_U256,
+        3418116888649131481255232506413838410498374333182635798114683964934936049493_U256,
+        2317134761873325336316673421884023918478336842214558979209369586458874210323_U256,
+        1302530440344417584814883644262313014505085742287251956682965492990748079356_U256,
+        2487805904058336358731312476832996997565877738611288945109947433477940578172_U256,
+        2453397415690965317880280590005663246370865622902113924589198900068368684504_U256,
+        1737672485486331867125994732494920999646060224184367054200179256972811922858_U256,
+        871923401900997852155640525654630985311405641909591608844915327267850658902_U256,
+        1193694776210721635991528032814615571959546151501645980478961853646542510582_U256,
+        1740990122961310345617719144290875500566002548071125422584487273406054518933_U256,
+        2091262116341922953690409971189843077423733817427588178293796221385951523203_U256,
+        3792563931809552916093504932920074967598612667323131377725716712512077572521_U256,
+        2720625397993480796806664183417489625859712233255725140667490138003031188497_U256,
+        1063650572688356689172144537082128538303405089285873486162356295567389934182_U256,
+        705960483470328935925386127905382889560400958564869032236977829032425881615_U256,
+        1019495342882734068500469935083125017120811789710466850523596509930856786397_U256,
+        2214054388093420596666381415236275439968756885549868923014161765277924495305_U256,
+        3219887951185538419246998829022738777871760321999070456967550550908017136008_U256,
+        3138671643877428049588108324240837541876234583703861385435218951663341787126_U256,
+        3629192507091282672511035810198269290402536537809950112548562836404479012501_U256,
+        2641656640767269711766651362025272750060227195582327966041708345190211957658_U256,
+        521969435360783768293487799930123056506255134324990005331237834899644284099_U256,
+        2891449101031049886867643409549044708042822962162146702627433804626952061406_U256,
+        1744587655485573223874382892710736443701970636218061522479136101138871064197_U256,
+        894210199452906791275028375697610260189667016410301537959046066664688935442_U256,
+        338146381717954795308249020854085986331370178780703316307851333626558513322_U256,
+        2060264432506810248524839532953016330944620366761613832372386297422577937967_U256,
+        2145245570091616383198454410777692796522506924283043611703577930677413997706_U256,
+        3069602505538696528420003524898407157930117052145824450663205203987430967069_U256,
+        781447318492337236419798755429506888128882881266839554215840338343553330901_U256,
+        2648300195950582668654769097035448367204061133938281551513688507823182489844_U256,
+        3094048537700465118633292327720987132562440467519335060373195046449639352293_U256,
+        755952957126626354406202279318026203531405081336936010335961385245318455049_U256,
+        2516284323517031643878647732675490608546691851629042123280768337624610780955_U256,
+        2070646858611072968812126690332599811516267826750077993431329692998465368588_U256,
+        1954071553107015119224653572653038594081786117814667577469619719923050882641_U256,
+        1099509449607057956349589008037647485289357636754892439756587087505185927280_U256,
+        2068328292673608117996842407770366532387463163279296662126675275773996222497_U256,
+        925749822594525886520337259680093220433090375249161138885074842203868772571_U256,
+        889577507394021255633986650160432339098326321895940162494222162569820224753_U256,
+        1399855449254790551923368287530343696670562737316369178330966337663937259442_U256,
+        3636841627317148386378867003330080431931960691281980143182985592163477600118_U256,
+        1895873792168493313476987390945448358107004300914450552728775201645558399792_U256,
+        1603685538112544541950836365543032872410200582010743731022643515318197477400_U256,
+        357866523335638257592862199224035489132832923412971740551101348465680112724_U256,
+        2717209252736230921769804910562264512880400602007708869804299425540854244215_U256,
+        3497494552698075871938294446899246196520104191514369526354260156095742589995_U256,
+        2340485193143228974793752299391980220406805381881046652451778606317573512196_U256,
+        312975189593833446604219170200556785006197926897499609349280245787597854030_U256,
+        501011822693574697167801023441606058225289664605919677648992110975220820673_U256,
+        529684928846237661310633062543108176228585811384591279308285551233157730782_U256,
+        1006379263636299358388199900540273580207402823914043415620401408253669040483_U256,
+        2975507551835373769109995155821252102015075492904202653110998618363982119386_U256,
+        3592263903760898241381376320194876213858351527001324620883905827925866764302_U256,
+        353572216125886118443709255733754471564080963183782227584554615104162314229_U256,
+        646281085432598714918148424335375090881450410680466262783221542360438262121_U256,
+        1502078746147282794295775024659391403733861221329374161720078197153194241300_U256,
+        3206939151733484806187133888205787630722100180971044013804426421283645425170_U256,
+        2298074838684796893708006362516953337700486689726771936248351210425626106060_U256,
+        1373681506315809090232688505914845933287103608225731800863668084984453891238_U256,
+        781529756081756214369733114618626440252331667510421961257781760726202035773_U256,
+        1091304682310897277328032217962624733871754442725852115588048268865660833370_U256,
+        1722272161698384921244421652566088083202738124700675124239831203312313602337_U256,
+        847607181094910098677877866490282896736495737467822759599719123783987094895_U256,
+        1292106019851569521153043603366068342425266332916354946336144625013520020898_U256,
+        1723041643174011947754188350945036771731608746119891135300658851468068641865_U256,
+        2289551233060854715372916183510416482200608991981409643916957014069590253649_U256,
+        1046156450005754146839328848170823817295802489338307326769107636979508836599_U256,
+        1978842004959697934706225086174602952843105629634767712034674944602249229948_U256,
+        436062096807667803028928655504824846925117271950275827321663964583423583750_U256,
+        3030653304081986760840368677282479725680635092982468964420670355011866332707_U256,
+        1107144286635105571549555717868035459035738606269747719721633510630644089280_U256,
+        3610168763002485184654825596077937182379800131127582810128072259996926737719_U256,
+        2911376981888836881907449732570146994238616796848107766802245610969528063429_U256,
+        618331038632880281248479363544939281209800150237353253161086010579386028154_U256,
+        2803735612793312029588123823078184800592762506488950907632117885278801301161_U256,
+        2353716987558732752517114977724424871098029010744509375314795482068180697450_U256,
+        3165506488321819255197684294071234393050551923235970557564752171889714024359_U256,
+        1632771564864461416488946156224190049919122452597124232663316092943607106039_U256,
+        1092814471927042596372723827974129475719719419564391053637247665282854121707_U256,
+        1821545475893841938642844627687246431278044776953419055011594464741973329221_U256,
+        1030636622647647668086388826393104046270632145497750395578815753004435542704_U256,
+        432512760900522293160763380620531435934751403791184406855847536124786354476_U256,
+        2840455386736965910722815578877421883100804361733057493361172796346770360149_U256,
+        1474559229194163859298238622036021226158759102715316937949337305235048049031_U256,
+        417186867397308544955155946750075229999565055111640536431789138006485203788_U256,
+        2456021614500704410381848704991555338265322112149373338264382100707959608247_U256,
+        2266244293290058374815771163309776345862076252937564047411091719512391482883_U256,
+        3780255328637920090106901712679340736248378768103575706561920971527310599341_U256,
+        2460578159615293550640425310674708425800646687157824300724037657357724133110_U256,
+        1543383869996128834761177266824087659211504976236048427161523812256925931976_U256,
+        3282504432854082051437668255245628090952938908186434518125617886501123715421_U256,
+        1029815103461668485117779861782640076039691509451760132436295245072960368742_U256,
+        2991740763127262407491888744715287898420724847365968771930841845897995267385_U256,
+        2987500990135074978060391834874528627009873177756720519891479745104285951656_U256,
+        2171475274027231116304328291054760738278112106911344302934584943259725330296_U256,
+        2611091778939822853810926368257479903386860969531803185443068846376310480804_U256,
+        1788291748628673576745129325181442993958153575513400924415548820656474283679_U256,
+        3607954345217484412044131080413794393077740945423265567378277658715414008997_U256,
+        1286396088018451307581994073713618206913111159415641243507964053120633811103_U256,
+        1116042249448350405265073912299949348754002295331120712359194257537397745038_U256,
+        3413742944294269617646199942325633673750381490377306562016962591767026332517_U256,
+        2312760817518463472707640857795819181730343999409229743111648213290964493347_U256,
+        1298156495989555721205851080174108277757092899481922720585244119822838362380_U256,
+        2483431959703474495122279912744792260817884895805959709012226060310030861196_U256,
+        2449023471336103454271248025917458509622872780096784688491477526900458967528_U256,
+        1733298541131470003516962168406716262898067381379037818102457883804902205882_U256,
+        867549457546135988546607961566426248563412799104262372747193954099940941926_U256,
+        1189320831855859772382495468726410835211553308696316744381240480478632793606_U256,
+        1736616178606448482008686580202670763818009705265796186486765900238144801957_U256,
+        2086888171987061090081377407101638340675740974622258942196074848218041806227_U256,
+        3788189987454691052484472368831870230850619824517802141627995339344167855545_U256,
+        2716251453638618933197631619329284889111719390450395904569768764835121471521_U256,
+        1059276628333494825563111972993923801555412246480544250064634922399480217206_U256,
+        701586539115467072316353563817178152812408115759539796139256455864516164639_U256,
+        1015121398527872204891437370994920280372818946905137614425875136762947069421_U256,
+        2209680443738558733057348851148070703220764042744539686916440392110014778329_U256,
+        3215514006830676555637966264934534041123767479193741220869829177740107419032_U256,
+        3134297699522566185979075760152632805128241740898532149337497578495432070150_U256,
+        3624818562736420808902003246110064553654543695004620876450841463236569295525_U256,
+        2637282696412407848157618797937068013312234352776998729943986972022302240682_U256,
+        517595491005921904684455235841918319758262291519660769233516461731734567123_U256,
+        2887075156676188023258610845460839971294830119356817466529712431459042344430_U256,
+        1740213711130711360265350328622531706953977793412732286381414727970961347221_U256,
+        889836255098044927665995811609405523441674173604972301861324693496779218466_U256,
+        333772437363092931699216456765881249583377335975374080210129960458648796346_U256,
+        2055890488151948384915806968864811594196627523956284596274664924254668220991_U256,
+        2140871625736754519589421846689488059774514081477714375605856557509504280730_U256,
+        3065228561183834664810970960810202421182124209340495214565483830819521250093_U256,
+        777073374137475372810766191341302151380890038461510318118118965175643613925_U256,
+        2643926251595720805045736532947243630456068291132952315415967134655272772868_U256,
+        3089674593345603255024259763632782395814447624714005824275473673281729635317_U256,
+        751579012771764490797169715229821466783412238531606774238240012077408738073_U256,
+        2511910379162169780269615168587285871798699008823712887183046964456701063979_U256,
+        2066272914256211105203094126244395074768274983944748757333608319830555651612_U256,
+        1949697608752153255615621008564833857333793275009338341371898346755141165665_U256,
+        1095135505252196092740556443949442748541364793949563203658865714337276210304_U256,
+        2063954348318746254387809843682161795639470320473967426028953902606086505521_U256,
+        921375878239664022911304695591888483685097532443831902787353469035959055595_U256,
+        885203563039159392024954086072227602350333479090610926396500789401910507777_U256,
+        1395481504899928688314335723442138959922569894511039942233244964496027542466_U256,
+        3632467682962286522769834439241875695183967848476650907085264218995567883142_U256,
+        1891499847813631449867954826857243621359011458109121316631053828477648682816_U256,
+        1599311593757682678341803801454828135662207739205414494924922142150287760424_U256,
+        353492578980776393983829635135830752384840080607642504453379975297770395748_U256,
+        2712835308381369058160772346474059776132407759202379633706578052372944527239_U256,
+        3493120608343214008329261882811041459772111348709040290256538782927832873019_U256,
+        2336111248788367111184719735303775483658812539075717416354057233149663795220_U256,
+        308601245238971582995186606112352048258205084092170373251558872619688137054_U256,
+        496637878338712833558768459353401321477296821800590441551270737807311103697_U256,
+        525310984491375797701600498454903439480592968579262043210564178065248013806_U256,
+        1002005319281437494779167336452068843459409981108714179522680035085759323507_U256,
+        2971133607480511905500962591733047365267082650098873417013277245196072402410_U256,
+        3587889959406036377772343756106671477110358684195995384786184454757957047326_U256,
+        349198271771024254834676691645549734816088120378452991486833241936252597253_U256,
+        641907141077736851309115860247170354133457567875137026685500169192528545145_U256,
+        1497704801792420930686742460571186666985868378524044925622356823985284524324_U256,
+        3202565207378622942578101324117582893974107338165714777706705048115735708194_U256,
+        2293700894329935030098973798428748600952493846921442700150629837257716389084_U256,
+        1369307561960947226623655941826641196539110765420402564765946711816544174262_U256,
+        777155811726894350760700550530421703504338824705092725160060387558292318797_U256,
+        1086930737956035413718999653874419997123761599920522879490326895697751116394_U256,
+        1717898217343523057635389088477883346454745281895345888142109830144403885361_U256,
+        843233236740048235068845302402078159988502894662493523501997750616077377919_U256,
+        1290648038399948899950032748670000096842602051981245200970237500624216781906_U256,
+        3393423643740618284075160074130567943843202538714435523455209064829603135706_U256,
+        3959933233627461051693887906695947654312202784575954032071507227431124747490_U256,
+        2716538450572360483160300571356354989407396281932851714923657850341043330440_U256,
+        3649224005526304271027196809360134124954699422229312100189225157963783723789_U256,
+        2106444097374274139349900378690356019036711064544820215476214177944958077591_U256,
+        4701035304648593097161340400468010897792228885577013352575220568373400826548_U256,
+        2777526287201711907870527441053566631147332398864292107876183723992178583121_U256,
+        5280550763569091520975797319263468354491393923722127198282622473358461231560_U256,
+        4581758982455443218228421455755678166350210589442652154956795824331062557270_U256,
+        2288713039199486617569451086730470453321393942831897641315636223940920521995_U256,
+        4474117613359918365909095546263715972704356299083495295786668098640335795002_U256,
+        4024098988125339088838086700909956043209622803339053763469345695429715191291_U256,
+        4835888488888425591518656017256765565162145715830514945719302385251248518200_U256,
+        3303153565431067752809917879409721222030716245191668620817866306305141599880_U256,
+        2763196472493648932693695551159660647831313212158935441791797878644388615548_U256,
+        3491927476460448274963816350872777603389638569547963443166144678103507823062_U256,
+        2701018623214254004407360549578635218382225938092294783733365966365970036545_U256,
+        2102894761467128629481735103806062608046345196385728795010397749486320848317_U256,
+        4510837387303572247043787302062953055212398154327601881515723009708304853990_U256,
+        3144941229760770195619210345221552398270352895309861326103887518596582542872_U256,
+        2087568867963914881276127669935606402111158847706184924586339351368019697629_U256,
+        4126403615067310746702820428177086510376915904743917726418932314069494102088_U256,
+        3936626293856664711136742886495307517973670045532108435565641932873925976724_U256,
+        5450637329204526426427873435864871908359972560698120094716471184888845093182_U256,
+        4130960160181899886961397033860239597912240479752368688878587870719258626951_U256,
+        3213765870562735171082148990009618831323098768830592815316074025618460425817_U256,
+        4952886433420688387758639978431159263064532700780978906280168099862658209262_U256,
+        2700197104028274821438751584968171248151285302046304520590845458434494862583_U256,
+        4662122763693868743812860467900819070532318639960513160085392059259529761226_U256,
+        4657882990701681314381363558060059799121466970351264908046029958465820445497_U256,
+        3841857274593837452625300014240291910389705899505888691089135156621259824137_U256,
+        4281473779506429190131898091443011075498454762126347573597619059737844974645_U256,
+        3458673749195279913066101048366974166069747368107945312570099034018008777520_U256,
+        5278336345784090748365102803599325565189334738017809955532827872076948502838_U256,
+        2956778088585057643902965796899149379024704952010185631662514266482168304944_U256,
+        2786424250014956741586045635485480520865596087925665100513744470898932238879_U256,
+        5084124944860875953967171665511164845861975282971850950171512805128560826358_U256,
+        3983142818085069809028612580981350353841937792003774131266198426652498987188_U256,
+        2968538496556162057526822803359639449868686692076467108739794333184372856221_U256,
+        4153813960270080831443251635930323432929478688400504097166776273671565355037_U256,
+        4119405471902709790592219749102989681734466572691329076646027740261993461369_U256,
+        3403680541698076339837933891592247435009661173973582206257008097166436699723_U256,
+        2537931458112742324867579684751957420675006591698806760901744167461475435767_U256,
+        2859702832422466108703467191911942007323147101290861132535790693840167287447_U256,
+        3406998179173054818329658303388201935929603497860340574641316113599679295798_U256,
+        3757270172553667426402349130287169512787334767216803330350625061579576300068_U256,
+        5458571988021297388805444092017401402962213617112346529782545552705702349386_U256,
+        4386633454205225269518603342514816061223313183044940292724318978196655965362_U256,
+        2729658628900101161884083696179454973667006039075088638219185135761014711047_U256,
+        2371968539682073408637325287002709324924001908354084184293806669226050658480_U256,
+        2685503399094478541212409094180451452484412739499682002580425350124481563262_U256,
+        3880062444305165069378320574333601875332357835339084075070990605471549272170_U256,
+        4885896007397282891958937988120065213235361271788285609024379391101641912873_U256,
+        4804679700089172522300047483338163977239835533493076537492047791856966563991_U256,
+        5295200563303027145222974969295595725766137487599165264605391676598103789366_U256,
+        4307664696979014184478590521122599185423828145371543118098537185383836734523_U256,
+        2187977491572528241005426959027449491869856084114205157388066675093269060964_U256,
+        4557457157242794359579582568646371143406423911951361854684262644820576838271_U256,
+        3410595711697317696586322051808062879065571586007276674535964941332495841062_U256,
+        2560218255664651263986967534794936695553267966199516690015874906858313712307_U256,
+        2004154437929699268020188179951412421694971128569918468364680173820183290187_U256,
+        3726272488718554721236778692050342766308221316550828984429215137616202714832_U256,
+        3811253626303360855910393569875019231886107874072258763760406770871038774571_U256,
+        4735610561750441001131942683995733593293718001935039602720034044181055743934_U256,
+        2447455374704081709131737914526833323492483831056054706272669178537178107766_U256,
+        4314308252162327141366708256132774802567662083727496703570517348016807266709_U256,
+        4760056593912209591345231486818313567926041417308550212430023886643264129158_U256,
+        2421961013338370827118141438415352638895006031126151162392790225438943231914_U256,
+        4182292379728776116590586891772817043910292801418257275337597177818235557820_U256,
+        3736654914822817441524065849429926246879868776539293145488158533192090145453_U256,
+        3620079609318759591936592731750365029445387067603882729526448560116675659506_U256,
+        2765517505818802429061528167134973920652958586544107591813415927698810704145_U256,
+        3734336348885352590708781566867692967751064113068511814183504115967620999362_U256,
+        2591757878806270359232276418777419655796691325038376290941903682397493549436_U256,
+        2555585563605765728345925809257758774461927271685155314551051002763445001618_U256,
+        3065863505466535024635307446627670132034163687105584330387795177857562036307_U256,
+        5302849683528892859090806162427406867295561641071195295239814432357102376983_U256,
+        3561881848380237786188926550042774793470605250703665704785604041839183176657_U256,
+        3269693594324289014662775524640359307773801531799958883079472355511822254265_U256,
+        2023874579547382730304801358321361924496433873202186892607930188659304889589_U256,
+        4383217308947975394481744069659590948244001551796924021861128265734479021080_U256,
+        5163502608909820344650233605996572631883705141303584678411088996289367366860_U256,
+        4006493249354973447505691458489306655770406331670261804508607446511198289061_U256,
+        1978983245805577919316158329297883220369798876686714761406109085981222630895_U256,
+        2167019878905319169879740182538932493588890614395134829705820951168845597538_U256,
+        2195692985057982134022572221640434611592186761173806431365114391426782507647_U256,
+        2672387319848043831100139059637600015571003773703258567677230248447293817348_U256,
+        4641515608047118241821934314918578537378676442693417805167827458557606896251_U256,
+        5258271959972642714093315479292202649221952476790539772940734668119491541167_U256,
+        2019580272337630591155648414831080906927681912972997379641383455297787091094_U256,
+        2312289141644343187630087583432701526245051360469681414840050382554063038986_U256,
+        3168086802359027267007714183756717839097462171118589313776907037346819018165_U256,
+        4872947207945229278899073047303114066085701130760259165861255261477270202035_U256,
+        3964082894896541366419945521614279773064087639515987088305180050619250882925_U256,
+        3039689562527553562944627665012172368650704558014946952920496925178078668103_U256,
+        2447537812293500687081672273715952875615932617299637113314610600919826812638_U256,
+        2757312738522641750039971377059951169235355392515067267644877109059285610235_U256,
+        3388280217910129393956360811663414518566339074489890276296660043505938379202_U256,
+        2513615237306654571389817025587609332100096687257037911656547963977611871760_U256,
+        1847442038588817678723689989731843820879799982846093330355087571744728279853_U256,
+        1776710975269589081483197631058253978029109078602138926451517865594716234521_U256,
+        2343220565156431849101925463623633688498109324463657435067816028196237846305_U256,
+        1099825782101331280568338128284041023593302821820555117919966651106156429255_U256,
+        2032511337055275068435234366287820159140605962117015503185533958728896822604_U256,
+        489731428903244936757937935618042053222617604432523618472522978710071176406_U256,
+        3084322636177563894569377957395696931978135425464716755571529369138513925363_U256,
+        1160813618730682705278564997981252665333238938751995510872492524757291681936_U256,
+        3663838095098062318383834876191154388677300463609830601278931274123574330375_U256,
+        2965046313984414015636459012683364200536117129330355557953104625096175656085_U256,
+        672000370728457414977488643658156487507300482719601044311945024706033620810_U256,
+        2857404944888889163317133103191402006890262838971198698782976899405448893817_U256,
+        2407386319654309886246124257837642077395529343226757166465654496194828290106_U256,
+        3219175820417396388926693574184451599348052255718218348715611186016361617015_U256,
+        1686440896960038550217955436337407256216622785079372023814175107070254698695_U256,
+        1146483804022619730101733108087346682017219752046638844788106679409501714363_U256,
+        1875214807989419072371853907800463637575545109435666846162453478868620921877_U256,
+        1084305954743224801815398106506321252568132477979998186729674767131083135360_U256,
+        486182092996099426889772660733748642232251736273432198006706550251433947132_U256,
+        2894124718832543044451824858990639089398304694215305284512031810473417952805_U256,
+        1528228561289740993027247902149238432456259435197564729100196319361695641687_U256,
+        470856199492885678684165226863292436297065387593888327582648152133132796444_U256,
+        2509690946596281544110857985104772544562822444631621129415241114834607200903_U256,
+        2319913625385635508544780443422993552159576585419811838561950733639039075539_U256,
+        3833924660733497223835910992792557942545879100585823497712779985653958191997_U256,
+        2514247491710870684369434590787925632098147019640072091874896671484371725766_U256,
+        1597053202091705968490186546937304865509005308718296218312382826383573524632_U256,
+        3336173764949659185166677535358845297250439240668682309276476900627771308077_U256,
+        1083484435557245618846789141895857282337191841934007923587154259199607961398_U256,
+        3045410095222839541220898024828505104718225179848216563081700860024642860041_U256,
+        3041170322230652111789401114987745833307373510238968311042338759230933544312_U256,
+        2225144606122808250033337571167977944575612439393592094085443957386372922952_U256,
+        2664761111035399987539935648370697109684361302014050976593927860502958073460_U256,
+        1841961080724250710474138605294660200255653907995648715566407834783121876335_U256,
+        3661623677313061545773140360527011599375241277905513358529136672842061601653_U256,
+        1340065420114028441311003353826835413210611491897889034658823067247281403759_U256,
+        1169711581543927538994083192413166555051502627813368503510053271664045337694_U256,
+        3467412276389846751375209222438850880047881822859554353167821605893673925173_U256,
+        2366430149614040606436650137909036388027844331891477534262507227417612086003_U256,
+        1351825828085132854934860360287325484054593231964170511736103133949485955036_U256,
+        2537101291799051628851289192858009467115385228288207500163085074436678453852_U256,
+        2502692803431680588000257306030675715920373112579032479642336541027106560184_U256,
+        1786967873227047137245971448519933469195567713861285609253316897931549798538_U256,
+        921218789641713122275617241679643454860913131586510163898052968226588534582_U256,
+        1242990163951436906111504748839628041509053641178564535532099494605280386262_U256,
+        1790285510702025615737695860315887970115510037748043977637624914364792394613_U256,
+        2140557504082638223810386687214855546973241307104506733346933862344689398883_U256,
+        3841859319550268186213481648945087437148120157000049932778854353470815448201_U256,
+        2769920785734196066926640899442502095409219722932643695720627778961769064177_U256,
+        1112945960429071959292121253107141007852912578962792041215493936526127809862_U256,
+        755255871211044206045362843930395359109908448241787587290115469991163757295_U256,
+        1068790730623449338620446651108137486670319279387385405576734150889594662077_U256,
+        2263349775834135866786358131261287909518264375226787478067299406236662370985_U256,
+        3269183338926253689366975545047751247421267811675989012020688191866755011688_U256,
+        3187967031618143319708085040265850011425742073380779940488356592622079662806_U256,
+        3678487894831997942631012526223281759952044027486868667601700477363216888181_U256,
+        2690952028507984981886628078050285219609734685259246521094845986148949833338_U256,
+        571264823101499038413464515955135526055762624001908560384375475858382159779_U256,
+        2940744488771765156987620125574057177592330451839065257680571445585689937086_U256,
+        1793883043226288493994359608735748913251478125894980077532273742097608939877_U256,
+        943505587193622061395005091722622729739174506087220093012183707623426811122_U256,
+        387441769458670065428225736879098455880877668457621871360988974585296389002_U256,
+        2109559820247525518644816248978028800494127856438532387425523938381315813647_U256,
+        2194540957832331653318431126802705266072014413959962166756715571636151873386_U256,
+        3118897893279411798539980240923419627479624541822743005716342844946168842749_U256,
+        830742706233052506539775471454519357678390370943758109268977979302291206581_U256,
+        2697595583691297938774745813060460836753568623615200106566826148781920365524_U256,
+        3143343925441180388753269043745999602111947957196253615426332687408377227973_U256,
+        805248344867341624526178995343038673080912571013854565389099026204056330729_U256,
+        2565579711257746913998624448700503078096199341305960678333905978583348656635_U256,
+        2119942246351788238932103406357612281065775316426996548484467333957203244268_U256,
+        2003366940847730389344630288678051063631293607491586132522757360881788758321_U256,
+        1148804837347773226469565724062659954838865126431810994809724728463923802960_U256,
+        2117623680414323388116819123795379001936970652956215217179812916732734098177_U256,
+        975045210335241156640313975705105689982597864926079693938212483162606648251_U256,
+        938872895134736525753963366185444808647833811572858717547359803528558100433_U256,
+        1449150836995505822043345003555356166220070226993287733384103978622675135122_U256,
+        3686137015057863656498843719355092901481468180958898698236123233122215475798_U256,
+        1945169179909208583596964106970460827656511790591369107781912842604296275472_U256,
+        1652980925853259812070813081568045341959708071687662286075781156276935353080_U256,
+        407161911076353527712838915249047958682340413089890295604238989424417988404_U256,
+        2766504640476946191889781626587276982429908091684627424857437066499592119895_U256,
+        3546789940438791142058271162924258666069611681191288081407397797054480465675_U256,
+        2389780580883944244913729015416992689956312871557965207504916247276311387876_U256,
+        362270577334548716724195886225569254555705416574418164402417886746335729710_U256,
+        550307210434289967287777739466618527774797154282838232702129751933958696353_U256,
+        578980316586952931430609778568120645778093301061509834361423192191895606462_U256,
+        1055674651377014628508176616565286049756910313590961970673539049212406916163_U256,
+        3024802939576089039229971871846264571564582982581121208164136259322719995066_U256,
+        3641559291501613511501353036219888683407859016678243175937043468884604639982_U256,
+        402867603866601388563685971758766941113588452860700782637692256062900189909_U256,
+        695576473173313985038125140360387560430957900357384817836359183319176137801_U256,
+        1551374133887998064415751740684403873283368711006292716773215838111932116980_U256,
+        3256234539474200076307110604230800100271607670647962568857564062242383300850_U256,
+        2347370226425512163827983078541965807249994179403690491301488851384363981740_U256,
+        1422976894056524360352665221939858402836611097902650355916805725943191766918_U256,
+        830825143822471484489709830643638909801839157187340516310919401684939911453_U256,
+        1140600070051612547448008933987637203421261932402770670641185909824398709050_U256,
+        1771567549439100191364398368591100552752245614377593679292968844271051478017_U256,
+        896902568835625368797854582515295366286003227144741314652856764742724970575_U256,
+        2514705411987185015758810103072762534149471234585860031344887857378389986285_U256,
+        1775246028693859414664370498676115693264986101373671735589529437482543575288_U256,
+        2341755618580702182283098331241495403733986347235190244205827600084065187072_U256,
+        1098360835525601613749510995901902738829179844592087927057978222993983770022_U256,
+        2031046390479545401616407233905681874376482984888548312323545530616724163371_U256,
+        488266482327515269939110803235903768458494627204056427610534550597898517173_U256,
+        3082857689601834227750550825013558647214012448236249564709540941026341266130_U256,
+        1159348672154953038459737865599114380569115961523528320010504096645119022703_U256,
+        3662373148522332651565007743809016103913177486381363410416942846011401671142_U256,
+        2963581367408684348817631880301225915771994152101888367091116196984002996852_U256,
+        670535424152727748158661511276018202743177505491133853449956596593860961577_U256,
+        2855939998313159496498305970809263722126139861742731507920988471293276234584_U256,
+        2405921373078580219427297125455503792631406365998289975603666068082655630873_U256,
+        3217710873841666722107866441802313314583929278489751157853622757904188957782_U256,
+        1684975950384308883399128303955268971452499807850904832952186678958082039462_U256,
+        1145018857446890063282905975705208397253096774818171653926118251297329055130_U256,
+        1873749861413689405553026775418325352811422132207199655300465050756448262644_U256,
+        1082841008167495134996570974124182967804009500751530995867686339018910476127_U256,
+        484717146420369760070945528351610357468128759044965007144718122139261287899_U256,
+        2892659772256813377632997726608500804634181716986838093650043382361245293572_U256,
+        1526763614714011326208420769767100147692136457969097538238207891249522982454_U256,
+        469391252917156011865338094481154151532942410365421136720659724020960137211_U256,
+        2508226000020551877292030852722634259798699467403153938553252686722434541670_U256,
+        2318448678809905841725953311040855267395453608191344647699962305526866416306_U256,
+        3832459714157767557017083860410419657781756123357356306850791557541785532764_U256,
+        2512782545135141017550607458405787347334024042411604901012908243372199066533_U256,
+        1595588255515976301671359414555166580744882331489829027450394398271400865399_U256,
+        3334708818373929518347850402976707012486316263440215118414488472515598648844_U256,
+        1082019488981515952027962009513718997573068864705540732725165831087435302165_U256,
+        3043945148647109874402070892446366819954102202619749372219712431912470200808_U256,
+        3039705375654922444970573982605607548543250533010501120180350331118760885079_U256,
+        2223679659547078583214510438785839659811489462165124903223455529274200263719_U256,
+        2663296164459670320721108515988558824920238324785583785731939432390785414227_U256,
+        1840496134148521043655311472912521915491530930767181524704419406670949217102_U256,
+        3660158730737331878954313228144873314611118300677046167667148244729888942420_U256,
+        1338600473538298774492176221444697128446488514669421843796834639135108744526_U256,
+        1168246634968197872175256060031028270287379650584901312648064843551872678461_U256,
+        3465947329814117084556382090056712595283758845631087162305833177781501265940_U256,
+        2364965203038310939617823005526898103263721354663010343400518799305439426770_U256,
+        1350360881509403188116033227905187199290470254735703320874114705837313295803_U256,
+        2535636345223321962032462060475871182351262251059740309301096646324505794619_U256,
+        2501227856855950921181430173648537431156250135350565288780348112914933900951_U256,
+        1785502926651317470427144316137795184431444736632818418391328469819377139305_U256,
+        919753843065983455456790109297505170096790154358042973036064540114415875349_U256,
+        1241525217375707239292677616457489756744930663950097344670111066493107727029_U256,
+        1788820564126295948918868727933749685351387060519576786775636486252619735380_U256,
+        2139092557506908556991559554832717262209118329876039542484945434232516739650_U256,
+        3840394372974538519394654516562949152383997179771582741916865925358642788968_U256,
+        2768455839158466400107813767060363810645096745704176504858639350849596404944_U256,
+        1111481013853342292473294120725002723088789601734324850353505508413955150629_U256,
+        753790924635314539226535711548257074345785471013320396428127041878991098062_U256,
+        1067325784047719671801619518725999201906196302158918214714745722777422002844_U256,
+        2261884829258406199967530998879149624754141397998320287205310978124489711752_U256,
+        3267718392350524022548148412665612962657144834447521821158699763754582352455_U256,
+        3186502085042413652889257907883711726661619096152312749626368164509907003573_U256,
+        3677022948256268275812185393841143475187921050258401476739712049251044228948_U256,
+        2689487081932255315067800945668146934845611708030779330232857558036777174105_U256,
+        569799876525769371594637383572997241291639646773441369522387047746209500546_U256,
+        2939279542196035490168792993191918892828207474610598066818583017473517277853_U256,
+        1792418096650558827175532476353610628487355148666512886670285313985436280644_U256,
+        942040640617892394576177959340484444975051528858752902150195279511254151889_U256,
+        385976822882940398609398604496960171116754691229154680499000546473123729769_U256,
+        2108094873671795851825989116595890515730004879210065196563535510269143154414_U256,
+        2193076011256601986499603994420566981307891436731494975894727143523979214153_U256,
+        3117432946703682131721153108541281342715501564594275814854354416833996183516_U256,
+        829277759657322839720948339072381072914267393715290918406989551190118547348_U256,
+        2696130637115568271955918680678322551989445646386732915704837720669747706291_U256,
+        3141878978865450721934441911363861317347824979967786424564344259296204568740_U256,
+        803783398291611957707351862960900388316789593785387374527110598091883671496_U256,
+        2564114764682017247179797316318364793332076364077493487471917550471175997402_U256,
+        2118477299776058572113276273975473996301652339198529357622478905845030585035_U256,
+        2001901994272000722525803156295912778867170630263118941660768932769616099088_U256,
+        1147339890772043559650738591680521670074742149203343803947736300351751143727_U256,
+        2116158733838593721297991991413240717172847675727748026317824488620561438944_U256,
+        973580263759511489821486843322967405218474887697612503076224055050433989018_U256,
+        937407948559006858935136233803306523883710834344391526685371375416385441200_U256,
+        1447685890419776155224517871173217881455947249764820542522115550510502475889_U256,
+        3684672068482133989680016586972954616717345203730431507374134805010042816565_U256,
+        1943704233333478916778136974588322542892388813362901916919924414492123616239_U256,
+        1651515979277530145251985949185907057195585094459195095213792728164762693847_U256,
+        405696964500623860894011782866909673918217435861423104742250561312245329171_U256,
+        2765039693901216525070954494205138697665785114456160233995448638387419460662_U256,
+        3545324993863061475239444030542120381305488703962820890545409368942307806442_U256,
+        2388315634308214578094901883034854405192189894329498016642927819164138728643_U256,
+        360805630758819049905368753843430969791582439345950973540429458634163070477_U256,
+        548842263858560300468950607084480243010674177054371041840141323821786037120_U256,
+        577515370011223264611782646185982361013970323833042643499434764079722947229_U256,
+        1054209704801284961689349484183147764992787336362494779811550621100234256930_U256,
+        3023337993000359372411144739464126286800460005352654017302147831210547335833_U256,
+        3640094344925883844682525903837750398643736039449775985075055040772431980749_U256,
+        401402657290871721744858839376628656349465475632233591775703827950727530676_U256,
+        694111526597584318219298007978249275666834923128917626974370755207003478568_U256,
+        1549909187312268397596924608302265588519245733777825525911227409999759457747_U256,
+        3254769592898470409488283471848661815507484693419495377995575634130210641617_U256,
+        2345905279849782497009155946159827522485871202175223300439500423272191322507_U256,
+        1421511947480794693533838089557720118072488120674183165054817297831019107685_U256,
+        829360197246741817670882698261500625037716179958873325448930973572767252220_U256,
+        1139135123475882880629181801605498918657138955174303479779197481712226049817_U256,
+        1770102602863370524545571236208962267988122637149126488430980416158878818784_U256,
+        895437622259895701979027450133157081521880249916274123790868336630552311342_U256,
+        1308049500239898055586760131247026404020394503732505401066527695962375093047_U256,
+        2794905152059256203360098968564961847535046335324945380886941435745679268253_U256,
+        3361414741946098970978826801130341558004046581186463889503239598347200880037_U256,
+        2118019958890998402445239465790748893099240078543361572355390221257119462987_U256,
+        3050705513844942190312135703794528028646543218839821957620957528879859856336_U256,
+        1507925605692912058634839273124749922728554861155330072907946548861034210138_U256,
+        4102516812967231016446279294902404801484072682187523210006952939289476959095_U256,
+        2179007795520349827155466335487960534839176195474801965307916094908254715668_U256,
+        4682032271887729440260736213697862258183237720332637055714354844274537364107_U256,
+        3983240490774081137513360350190072070042054386053162012388528195247138689817_U256,
+        1690194547518124536854389981164864357013237739442407498747368594856996654542_U256,
+        3875599121678556285194034440698109876396200095694005153218400469556411927549_U256,
+        3425580496443977008123025595344349946901466599949563620901078066345791323838_U256,
+        4237369997207063510803594911691159468853989512441024803151034756167324650747_U256,
+        2704635073749705672094856773844115125722560041802178478249598677221217732427_U256,
+        2164677980812286851978634445594054551523157008769445299223530249560464748095_U256,
+        2893408984779086194248755245307171507081482366158473300597877049019583955609_U256,
+        2102500131532891923692299444013029122074069734702804641165098337282046169092_U256,
+        1504376269785766548766673998240456511738188992996238652442130120402396980864_U256,
+        3912318895622210166328726196497346958904241950938111738947455380624380986537_U256,
+        2546422738079408114904149239655946301962196691920371183535619889512658675419_U256,
+        1489050376282552800561066564370000305803002644316694782018071722284095830176_U256,
+        3527885123385948665987759322611480414068759701354427583850664684985570234635_U256,
+        3338107802175302630421681780929701421665513842142618292997374303790002109271_U256,
+        4852118837523164345712812330299265812051816357308629952148203555804921225729_U256,
+        3532441668500537806246335928294633501604084276362878546310320241635334759498_U256,
+        2615247378881373090367087884444012735014942565441102672747806396534536558364_U256,
+        4354367941739326307043578872865553166756376497391488763711900470778734341809_U256,
+        2101678612346912740723690479402565151843129098656814378022577829350570995130_U256,
+        4063604272012506663097799362335212974224162436571023017517124430175605893773_U256,
+        4059364499020319233666302452494453702813310766961774765477762329381896578044_U256,
+        3243338782912475371910238908674685814081549696116398548520867527537335956684_U256,
+        3682955287825067109416836985877404979190298558736857431029351430653921107192_U256,
+        2860155257513917832351039942801368069761591164718455170001831404934084910067_U256,
+        4679817854102728667650041698033719468881178534628319812964560242993024635385_U256,
+        2358259596903695563187904691333543282716548748620695489094246637398244437491_U256,
+        2187905758333594660870984529919874424557439884536174957945476841815008371426_U256,
+        4485606453179513873252110559945558749553819079582360807603245176044636958905_U256,
+        3384624326403707728313551475415744257533781588614283988697930797568575119735_U256,
+        2370020004874799976811761697794033353560530488686976966171526704100448988768_U256,
+        3555295468588718750728190530364717336621322485011013954598508644587641487584_U256,
+        3520886980221347709877158643537383585426310369301838934077760111178069593916_U256,
+        2805162050016714259122872786026641338701504970584092063688740468082512832270_U256,
+        1939412966431380244152518579186351324366850388309316618333476538377551568314_U256,
+        2261184340741104027988406086346335911014990897901370989967523064756243419994_U256,
+        2808479687491692737614597197822595839621447294470850432073048484515755428345_U256,
+        3158751680872305345687288024721563416479178563827313187782357432495652432615_U256,
+        4860053496339935308090382986451795306654057413722856387214277923621778481933_U256,
+        3788114962523863188803542236949209964915156979655450150156051349112732097909_U256,
+        2131140137218739081169022590613848877358849835685598495650917506677090843594_U256,
+        1773450048000711327922264181437103228615845704964594041725539040142126791027_U256,
+        2086984907413116460497347988614845356176256536110191860012157721040557695809_U256,
+        3281543952623802988663259468767995779024201631949593932502722976387625404717_U256,
+        4287377515715920811243876882554459116927205068398795466456111762017718045420_U256,
+        4206161208407810441584986377772557880931679330103586394923780162773042696538_U256,
+        4696682071621665064507913863729989629457981284209675122037124047514179921913_U256,
+        3709146205297652103763529415556993089115671941982052975530269556299912867070_U256,
+        1589458999891166160290365853461843395561699880724715014819799046009345193511_U256,
+        3958938665561432278864521463080765047098267708561871712115995015736652970818_U256,
+        2812077220015955615871260946242456782757415382617786531967697312248571973609_U256,
+        1961699763983289183271906429229330599245111762810026547447607277774389844854_U256,
+        1405635946248337187305127074385806325386814925180428325796412544736259422734_U256,
+        3127753997037192640521717586484736670000065113161338841860947508532278847379_U256,
+        3212735134621998775195332464309413135577951670682768621192139141787114907118_U256,
+        4137092070069078920416881578430127496985561798545549460151766415097131876481_U256,
+        1848936883022719628416676808961227227184327627666564563704401549453254240313_U256,
+        3715789760480965060651647150567168706259505880338006561002249718932883399256_U256,
+        4161538102230847510630170381252707471617885213919060069861756257559340261705_U256,
+        1823442521657008746403080332849746542586849827736661019824522596355019364461_U256,
+        3583773888047414035875525786207210947602136598028767132769329548734311690367_U256,
+        3138136423141455360809004743864320150571712573149803002919890904108166278000_U256,
+        3021561117637397511221531626184758933137230864214392586958180931032751792053_U256,
+        2166999014137440348346467061569367824344802383154617449245148298614886836692_U256,
+        3135817857203990509993720461302086871442907909679021671615236486883697131909_U256,
+        1993239387124908278517215313211813559488535121648886148373636053313569681983_U256,
+        1957067071924403647630864703692152678153771068295665171982783373679521134165_U256,
+        2467345013785172943920246341062064035726007483716094187819527548773638168854_U256,
+        4704331191847530778375745056861800770987405437681705152671546803273178509530_U256,
+        2963363356698875705473865444477168697162449047314175562217336412755259309204_U256,
+        2671175102642926933947714419074753211465645328410468740511204726427898386812_U256,
+        1425356087866020649589740252755755828188277669812696750039662559575381022136_U256,
+        3784698817266613313766682964093984851935845348407433879292860636650555153627_U256,
+        4564984117228458263935172500430966535575548937914094535842821367205443499407_U256,
+        3407974757673611366790630352923700559462250128280771661940339817427274421608_U256,
+        1380464754124215838601097223732277124061642673297224618837841456897298763442_U256,
+        1568501387223957089164679076973326397280734411005644687137553322084921730085_U256,
+        1597174493376620053307511116074828515284030557784316288796846762342858640194_U256,
+        2073868828166681750385077954071993919262847570313768425108962619363369949895_U256,
+        4042997116365756161106873209352972441070520239303927662599559829473683028798_U256,
+        4659753468291280633378254373726596552913796273401049630372467039035567673714_U256,
+        1421061780656268510440587309265474810619525709583507237073115826213863223641_U256,
+        1713770649962981106915026477867095429936895157080191272271782753470139171533_U256,
+        2569568310677665186292653078191111742789305967729099171208639408262895150712_U256,
+        4274428716263867198184011941737507969777544927370769023292987632393346334582_U256,
+        3365564403215179285704884416048673676755931436126496945736912421535327015472_U256,
+        2441171070846191482229566559446566272342548354625456810352229296094154800650_U256,
+        1849019320612138606366611168150346779307776413910146970746342971835902945185_U256,
+        2158794246841279669324910271494345072927199189125577125076609479975361742782_U256,
+        2789761726228767313241299706097808422258182871100400133728392414422014511749_U256,
+        1915096745625292490674755920022003235791940483867547769088280334893688004307_U256,
+        1647935874695030318485336287876641788777081248382929949498998362050086990702_U256,
+    ]);
+}
@@ -0,0 +1,168 @@
+use alloc::vec::Vec;
+
+use stylus_sdk::{
+    alloy_primitives::{FixedBytes, U256},
+    crypto::keccak,
+};
+use utils::felt252::K_MODULUS;
+
+#[path = "periodic-columns/pedersen-hp-x.rs"]
+pub mod pedersen_hp_x;
+#[path = "periodic-columns/pedersen-hp-y.rs"]
+pub mod pedersen_hp_y;
+#[path = "periodic-columns/poseidon-frk-0.rs"]
+pub mod poseidon_frk_0;
+#[path = "periodic-columns/poseidon-frk-1.rs"]
+pub mod poseidon_frk_1;
+#[path = "periodic-columns/poseidon-frk-2.rs"]
+pub mod poseidon_frk_2;
+#[path = "periodic-columns/poseidon-prk-0.rs"]
+pub mod poseidon_prk_0;
+#[path = "periodic-columns/poseidon-prk-1.rs"]
+pub mod poseidon_prk_1;
+
+// The periodic columns of layout 7. On-chain each one is deployed as its own contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PeriodicColumn {
+    PedersenPointsX,
+    PedersenPointsY,
+    PoseidonFullRoundKey0,
+    PoseidonFullRoundKey1,
+    PoseidonFullRoundKey2,
+    PoseidonPartialRoundKey0,
+    PoseidonPartialRoundKey1,
+}
+
+impl PeriodicColumn {
+    pub const ALL: [PeriodicColumn; 7] = [
+        PeriodicColumn::PedersenPointsX,
+        PeriodicColumn::PedersenPointsY,
+        PeriodicColumn::PoseidonFullRoundKey0,
+        PeriodicColumn::PoseidonFullRoundKey1,
+        PeriodicColumn::PoseidonFullRoundKey2,
+        PeriodicColumn::PoseidonPartialRoundKey0,
+        PeriodicColumn::PoseidonPartialRoundKey1,
+    ];
+
+    // The coefficients of the column polynomial, from the highest degree down to the constant term.
+    pub fn coefficients(self) -> &'static [U256] {
+        match self {
+            PeriodicColumn::PedersenPointsX => &pedersen_hp_x::COEFFS,
+            PeriodicColumn::PedersenPointsY => &pedersen_hp_y::COEFFS,
+            PeriodicColumn::PoseidonFullRoundKey0 => &poseidon_frk_0::COEFFS,
+            PeriodicColumn::PoseidonFullRoundKey1 => &poseidon_frk_1::COEFFS,
+            PeriodicColumn::PoseidonFullRoundKey2 => &poseidon_frk_2::COEFFS,
+            PeriodicColumn::PoseidonPartialRoundKey0 => &poseidon_prk_0::COEFFS,
+            PeriodicColumn::PoseidonPartialRoundKey1 => &poseidon_prk_1::COEFFS,
+        }
+    }
+}
+
+// The columns that are evaluated at the same point: the Pedersen columns at z^(n_steps / 128) and
+// the Poseidon columns at z^(n_steps / 8). A family can be deployed as a single contract that
+// evaluates all its columns in one call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PeriodicColumnFamily {
+    Pedersen,
+    Poseidon,
+}
+
+impl PeriodicColumnFamily {
+    pub fn columns(self) -> &'static [PeriodicColumn] {
+        match self {
+            PeriodicColumnFamily::Pedersen => &[PeriodicColumn::PedersenPointsX, PeriodicColumn::PedersenPointsY],
+            PeriodicColumnFamily::Poseidon => &[
+                PeriodicColumn::PoseidonFullRoundKey0,
+                PeriodicColumn::PoseidonFullRoundKey1,
+                PeriodicColumn::PoseidonFullRoundKey2,
+                PeriodicColumn::PoseidonPartialRoundKey0,
+                PeriodicColumn::PoseidonPartialRoundKey1,
+            ],
+        }
+    }
+}
+
+// Evaluates all the columns of the family at x, in the order of `PeriodicColumnFamily::columns`.
+// The columns share one Horner loop over the degree: each step updates every accumulator that
+// has a coefficient of that degree, so a shorter column starts at its own highest degree.
+pub fn compute_all(family: PeriodicColumnFamily, x: U256) -> Vec<U256> {
+    let coeffs: Vec<&[U256]> = family.columns().iter().map(|column| column.coefficients()).collect();
+    let n_coeffs = coeffs.iter().map(|coeffs| coeffs.len()).max().unwrap_or(0);
+    let mut results = vec![U256::ZERO; coeffs.len()];
+    for degree in (0..n_coeffs).rev() {
+        for (result, coeffs) in results.iter_mut().zip(&coeffs) {
+            if degree < coeffs.len() {
+                *result = result.mul_mod(x, K_MODULUS).wrapping_add(coeffs[coeffs.len() - 1 - degree]);
+            }
+        }
+    }
+    results.into_iter().map(|result| result % K_MODULUS).collect()
+}
+
+// Evaluates the polynomial with the given coefficients, from the highest degree down to the
+// constant term, at x. The generic periodic-column contract evaluates its stored table with it.
+pub fn evaluate(coeffs: impl IntoIterator<Item = U256>, x: U256) -> U256 {
+    coeffs
+        .into_iter()
+        .fold(U256::ZERO, |acc, coeff| acc.mul_mod(x, K_MODULUS).wrapping_add(coeff))
+        % K_MODULUS
+}
+
+// The keccak of the coefficients as 32-byte big-endian words. The generic periodic-column contract
+// checks an uploaded table against it before sealing.
+pub fn coefficients_hash(coeffs: &[U256]) -> FixedBytes<32> {
+    let bytes: Vec<u8> = coeffs.iter().flat_map(|coeff| coeff.to_be_bytes::<32>()).collect();
+    keccak(bytes)
+}
+
+pub fn compute(column: PeriodicColumn, x: U256) -> U256 {
+    match column {
+        PeriodicColumn::PedersenPointsX => pedersen_hp_x::compute(x),
+        PeriodicColumn::PedersenPointsY => pedersen_hp_y::compute(x),
+        PeriodicColumn::PoseidonFullRoundKey0 => poseidon_frk_0::compute(x),
+        PeriodicColumn::PoseidonFullRoundKey1 => poseidon_frk_1::compute(x),
+        PeriodicColumn::PoseidonFullRoundKey2 => poseidon_frk_2::compute(x),
+        PeriodicColumn::PoseidonPartialRoundKey0 => poseidon_prk_0::compute(x),
+        PeriodicColumn::PoseidonPartialRoundKey1 => poseidon_prk_1::compute(x),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::alloy_primitives::uint;
+
+    // The expected values of the test_compute of every column, from the Solidity periodic column
+    // tests (test/PeriodicColumnts.t.sol).
+    #[test]
+    fn test_compute_all() {
+        let x = uint!(2502371038239847331946845555940821891939660827069539886818086403686260021246_U256);
+        assert_eq!(
+            compute_all(PeriodicColumnFamily::Pedersen, x),
+            [
+                uint!(2476435194882991550378205418214791165604712474576866766823810310226558062065_U256),
+                uint!(1444533035788560090889078696321009507857064390212204404518903797387225515076_U256),
+            ]
+        );
+
+        let x = uint!(513761785516736576210258345954495650460389361631034617172115002511570125974_U256);
+        assert_eq!(
+            compute_all(PeriodicColumnFamily::Poseidon, x),
+            [
+                uint!(1747952454919021766681010400995206390562374609324430906386085649753967957996_U256),
+                uint!(1664257228653772301912891197477956780973260593455413394763471271235501957228_U256),
+                uint!(1938976483485279484363264204509611131731729867572976629648616677903267220493_U256),
+                uint!(1499007735260395255086346814066654016187033964386904667040298584658325794077_U256),
+                uint!(2486570557154671379335084513491649861794821253711847039152551529444239535533_U256),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_evaluate_coefficients() {
+        let x = U256::from(0x1234567);
+        for column in PeriodicColumn::ALL {
+            assert_eq!(evaluate(column.coefficients().iter().copied(), x), compute(column, x));
+        }
+    }
+}
@@ -0,0 +1,21 @@
+macros::periodic_column!("../../evm-verifier/solidity/contracts/cpu/periodic_columns/PedersenHashPointsXColumn.sol");
+
+#[cfg(test)]
+mod test {
+    use stylus_sdk::alloy_primitives::{uint, U256};
+
+    use super::*;
+
+    #[motsu::test]
+    fn test_compute() {
+        let x: U256 = uint!(
+            2502371038239847331946845555940821891939660827069539886818086403686260021246_U256
+        );
+
+        let expected: U256 = uint!(
+            2476435194882991550378205418214791165604712474576866766823810310226558062065_U256
+        );
+
+        assert_eq!(compute(x), expected);
+    }
+}
@@ -0,0 +1,21 @@
+macros::periodic_column!("../../evm-verifier/solidity/contracts/cpu/periodic_columns/PedersenHashPointsYColumn.sol");
+
+#[cfg(test)]
+mod test {
+    use stylus_sdk::alloy_primitives::{uint, U256};
+
+    use super::*;
+
+    #[motsu::test]
+    fn test_compute() {
+        let x: U256 = uint!(
+            2502371038239847331946845555940821891939660827069539886818086403686260021246_U256
+        );
+
+        let expected: U256 = uint!(
+            1444533035788560090889078696321009507857064390212204404518903797387225515076_U256
+        );
+
+        assert_eq!(compute(x), expected);
+    }
+}
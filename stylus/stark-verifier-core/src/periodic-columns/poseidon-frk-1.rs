@@ -0,0 +1,20 @@
+macros::periodic_column!("../../evm-verifier/solidity/contracts/cpu/periodic_columns/PoseidonPoseidonFullRoundKey1Column.sol");
+
+#[cfg(test)]
+mod test {
+    use stylus_sdk::alloy_primitives::{uint, U256};
+
+    use super::*;
+
+    #[motsu::test]
+    fn test_compute() {
+        let x: U256 =
+            uint!(513761785516736576210258345954495650460389361631034617172115002511570125974_U256);
+
+        let expected: U256 = uint!(
+            1664257228653772301912891197477956780973260593455413394763471271235501957228_U256
+        );
+
+        assert_eq!(compute(x), expected);
+    }
+}
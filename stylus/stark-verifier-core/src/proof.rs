@@ -0,0 +1,358 @@
+//! The layout of the proof array.
+//!
+//! Up to the proof of work nonce the proof is read word by word:
+//!
+//!     trace commitment 0, trace commitment 1, composition commitment,
+//!     OODS values (N_OODS_VALUES trace values, then CONSTRAINTS_DEGREE_BOUND composition values),
+//!     FRI layer 1..n_fri_steps-1 commitments,
+//!     last FRI layer (2^log_fri_last_layer_deg_bound coefficients),
+//!     proof of work nonce (the first 8 bytes of a word).
+//!
+//! The decommitment is packed right after the nonce, so its words are not aligned:
+//!
+//!     trace 0 / trace 1 / composition query responses (one row per unique query),
+//!     the hash of the output queue of FRI layers 1..n_fri_steps-2.
+//!
+//! The Merkle paths and the FRI layers themselves are not in the proof, they are proven by
+//! separate Merkle and FRI statements. Words after the decommitment are ignored, as by the
+//! Solidity verifier.
+//!
+//! The proof is either the `uint256[]` proof or the bytes of a compact proof (see `compact`),
+//! which are read in place.
+use alloc::{borrow::Cow, vec::Vec};
+use core::fmt;
+
+use stylus_sdk::alloy_primitives::U256;
+use utils::require::{InvalidProofParams, ProofTruncated};
+
+use crate::fri_statement_verifier::read_ptr;
+use crate::memory_map::*;
+
+const NONCE_BYTES: usize = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofSection {
+    TraceCommitment(usize),
+    CompositionCommitment,
+    OodsValues,
+    // The commitment of the given FRI layer, from 1.
+    FriCommitment(usize),
+    FriLastLayer,
+    ProofOfWorkNonce,
+    TraceDecommitment(usize),
+    CompositionDecommitment,
+    // The hash of the output queue of the given FRI layer, from 1.
+    FriLayerDecommitment(usize),
+}
+
+impl ProofSection {
+    // The section and index of the ProofTruncated error.
+    pub fn code(&self) -> (u8, usize) {
+        match *self {
+            ProofSection::TraceCommitment(i) => (0, i),
+            ProofSection::CompositionCommitment => (1, 0),
+            ProofSection::OodsValues => (2, 0),
+            ProofSection::FriCommitment(layer) => (3, layer),
+            ProofSection::FriLastLayer => (4, 0),
+            ProofSection::ProofOfWorkNonce => (5, 0),
+            ProofSection::TraceDecommitment(i) => (6, i),
+            ProofSection::CompositionDecommitment => (7, 0),
+            ProofSection::FriLayerDecommitment(layer) => (8, layer),
+        }
+    }
+}
+
+impl fmt::Display for ProofSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofSection::TraceCommitment(i) => write!(f, "trace {i} commitment"),
+            ProofSection::CompositionCommitment => f.write_str("composition commitment"),
+            ProofSection::OodsValues => f.write_str("OODS values"),
+            ProofSection::FriCommitment(layer) => write!(f, "FRI layer {layer} commitment"),
+            ProofSection::FriLastLayer => f.write_str("FRI last layer"),
+            ProofSection::ProofOfWorkNonce => f.write_str("proof of work nonce"),
+            ProofSection::TraceDecommitment(i) => write!(f, "trace {i} decommitment"),
+            ProofSection::CompositionDecommitment => f.write_str("composition decommitment"),
+            ProofSection::FriLayerDecommitment(layer) => write!(f, "FRI layer {layer} decommitment"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofError {
+    InvalidProofParams,
+    Truncated(ProofSection),
+}
+
+impl fmt::Display for ProofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofError::InvalidProofParams => f.write_str("Invalid proof params"),
+            ProofError::Truncated(section) => write!(f, "Proof truncated in {section}."),
+        }
+    }
+}
+
+impl From<ProofError> for Vec<u8> {
+    fn from(err: ProofError) -> Vec<u8> {
+        match err {
+            ProofError::InvalidProofParams => InvalidProofParams {}.into(),
+            ProofError::Truncated(section) => {
+                let (section, index) = section.code();
+                ProofTruncated { section, index: U256::from(index) }.into()
+            }
+        }
+    }
+}
+
+// The words of a proof. A compact proof holds the bytes of the words, without the zero padding
+// at the end of the last one, and its words are read from these bytes as they are needed.
+#[derive(Clone, Copy, Debug)]
+pub enum ProofWords<'a> {
+    Words(&'a [U256]),
+    Bytes(&'a [u8]),
+}
+
+impl<'a> ProofWords<'a> {
+    // The number of words, counting a partial last word of a compact proof.
+    pub fn len(&self) -> usize {
+        match self {
+            ProofWords::Words(words) => words.len(),
+            ProofWords::Bytes(bytes) => bytes.len().div_ceil(32),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // The n words from the given word index on, which must be in the proof.
+    pub fn words(&self, start: usize, n: usize) -> Cow<'a, [U256]> {
+        match *self {
+            ProofWords::Words(words) => Cow::Borrowed(&words[start..start + n]),
+            ProofWords::Bytes(_) => Cow::Owned((start..start + n).map(|i| self.word_at(32 * i)).collect()),
+        }
+    }
+
+    // The 32 bytes from the given byte offset on, which may not be aligned. The bytes must be in
+    // the proof, up to the padding dropped by a compact proof.
+    pub fn word_at(&self, byte_ptr: usize) -> U256 {
+        match *self {
+            ProofWords::Words(words) => read_ptr(words, byte_ptr, byte_ptr % 32),
+            ProofWords::Bytes(bytes) => {
+                let mut word = [0u8; 32];
+                let end = bytes.len().min(byte_ptr + 32);
+                word[..end - byte_ptr].copy_from_slice(&bytes[byte_ptr..end]);
+                U256::from_be_bytes(word)
+            }
+        }
+    }
+}
+
+impl<'a> From<&'a [U256]> for ProofWords<'a> {
+    fn from(words: &'a [U256]) -> Self {
+        ProofWords::Words(words)
+    }
+}
+
+impl<'a> From<&'a Vec<U256>> for ProofWords<'a> {
+    fn from(words: &'a Vec<U256>) -> Self {
+        ProofWords::Words(words)
+    }
+}
+
+// The sections of the proof that the StarkVerifier reads from the channel, see the module doc.
+pub struct StarkProof<'a> {
+    pub trace_commitments: Cow<'a, [U256]>,
+    pub composition_commitment: U256,
+    pub oods_values: Cow<'a, [U256]>,
+    pub fri_commitments: Cow<'a, [U256]>,
+    pub last_layer: Cow<'a, [U256]>,
+    // The index of the first coefficient of the last layer, kept in MM_FRI_LAST_LAYER_PTR.
+    pub last_layer_ptr: usize,
+    pub proof_of_work_nonce: [u8; NONCE_BYTES],
+    // The index of the word that holds the nonce. The decommitment starts right after the nonce.
+    pub nonce_ptr: usize,
+}
+
+impl<'a> StarkProof<'a> {
+    // Splits the proof into its sections, given proof params that verifier-init accepted. The
+    // decommitment is only checked by `Decommitment::decode`, once the number of unique queries
+    // is known.
+    pub fn decode(proof_params: &[U256], proof: ProofWords<'a>) -> Result<Self, ProofError> {
+        if proof_params.len() < 5 || proof_params[4] < U256::from(2) || proof_params[3] > U256::from(10) {
+            return Err(ProofError::InvalidProofParams);
+        }
+        let n_fri_steps = proof_params[4].to::<usize>();
+        let fri_last_layer_deg_bound = 1 << proof_params[3].to::<usize>();
+
+        let mut ptr = 0;
+        let trace_commitments = take(proof, &mut ptr, 2, ProofSection::TraceCommitment)?;
+        let composition_commitment = take(proof, &mut ptr, 1, |_| ProofSection::CompositionCommitment)?[0];
+        let oods_values = take(proof, &mut ptr, N_OODS_VALUES + CONSTRAINTS_DEGREE_BOUND, |_| {
+            ProofSection::OodsValues
+        })?;
+        let fri_commitments = take(proof, &mut ptr, n_fri_steps - 1, |i| ProofSection::FriCommitment(i + 1))?;
+        let last_layer_ptr = ptr;
+        let last_layer = take(proof, &mut ptr, fri_last_layer_deg_bound, |_| ProofSection::FriLastLayer)?;
+        let nonce_ptr = ptr;
+        let nonce_word = take(proof, &mut ptr, 1, |_| ProofSection::ProofOfWorkNonce)?[0];
+        let mut proof_of_work_nonce = [0; NONCE_BYTES];
+        proof_of_work_nonce.copy_from_slice(&nonce_word.to_be_bytes::<32>()[..NONCE_BYTES]);
+
+        Ok(StarkProof {
+            trace_commitments,
+            composition_commitment,
+            oods_values,
+            fri_commitments,
+            last_layer,
+            last_layer_ptr,
+            proof_of_work_nonce,
+            nonce_ptr,
+        })
+    }
+
+    // The byte offset of the decommitment, where the channel points after the proof of work.
+    pub fn decommitment_ptr(&self) -> usize {
+        self.nonce_ptr * 32 + NONCE_BYTES
+    }
+}
+
+// The sections of the proof that the FriVerifier reads, see the module doc.
+pub struct Decommitment<'a> {
+    // N_COLUMNS_IN_TRACE0 and N_COLUMNS_IN_TRACE1 values per query.
+    pub trace_query_responses: [Vec<U256>; 2],
+    // CONSTRAINTS_DEGREE_BOUND values per query.
+    pub composition_query_responses: Vec<U256>,
+    // The hash of the output queue of FRI layers 1..n_fri_steps-2.
+    pub fri_layer_hashes: Vec<U256>,
+    pub last_layer: Cow<'a, [U256]>,
+}
+
+impl<'a> Decommitment<'a> {
+    // Reads the decommitment at the channel pointer of a ctx prepared by the StarkVerifier.
+    pub fn decode(proof: ProofWords<'a>, ctx: &[U256], n_fri_steps: usize) -> Result<Self, ProofError> {
+        if n_fri_steps < 2 {
+            return Err(ProofError::InvalidProofParams);
+        }
+        let n_queries = ctx.n_unique_queries();
+        let last_layer_ptr = ctx.fri_last_layer_ptr();
+        let last_layer_deg_bound = ctx.fri_last_layer_deg_bound();
+        if proof.len() < last_layer_ptr + last_layer_deg_bound {
+            return Err(ProofError::Truncated(ProofSection::FriLastLayer));
+        }
+        let last_layer = proof.words(last_layer_ptr, last_layer_deg_bound);
+
+        let mut byte_ptr = ctx[MM_CHANNEL].to::<usize>();
+        let trace0 = take_packed(proof, &mut byte_ptr, n_queries * N_COLUMNS_IN_TRACE0, ProofSection::TraceDecommitment(0))?;
+        let trace1 = take_packed(proof, &mut byte_ptr, n_queries * N_COLUMNS_IN_TRACE1, ProofSection::TraceDecommitment(1))?;
+        let composition_query_responses = take_packed(
+            proof,
+            &mut byte_ptr,
+            n_queries * CONSTRAINTS_DEGREE_BOUND,
+            ProofSection::CompositionDecommitment,
+        )?;
+        let mut fri_layer_hashes = Vec::with_capacity(n_fri_steps - 2);
+        for layer in 1..n_fri_steps - 1 {
+            fri_layer_hashes.push(take_packed(proof, &mut byte_ptr, 1, ProofSection::FriLayerDecommitment(layer))?[0]);
+        }
+
+        Ok(Decommitment {
+            trace_query_responses: [trace0, trace1],
+            composition_query_responses,
+            fri_layer_hashes,
+            last_layer,
+        })
+    }
+}
+
+fn take<'a>(
+    proof: ProofWords<'a>,
+    ptr: &mut usize,
+    n: usize,
+    section: impl Fn(usize) -> ProofSection,
+) -> Result<Cow<'a, [U256]>, ProofError> {
+    if proof.len() < *ptr + n {
+        return Err(ProofError::Truncated(section(proof.len().saturating_sub(*ptr))));
+    }
+    let words = proof.words(*ptr, n);
+    *ptr += n;
+    Ok(words)
+}
+
+// Reads n words packed from the given byte offset on.
+fn take_packed(proof: ProofWords, byte_ptr: &mut usize, n: usize, section: ProofSection) -> Result<Vec<U256>, ProofError> {
+    let end = *byte_ptr + 32 * n;
+    if proof.len() < end.div_ceil(32) {
+        return Err(ProofError::Truncated(section));
+    }
+    let words = (0..n)
+        .map(|i| proof.word_at(*byte_ptr + 32 * i))
+        .collect();
+    *byte_ptr = end;
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_input(input: &str) -> Vec<U256> {
+        input
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(',')
+            .map(|val| U256::from_str_radix(val.trim(), 10).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_decode_proof() {
+        let proof_params = parse_input(include_str!("../../../inputs/proof_params.txt"));
+        let proof = parse_input(include_str!("../../../inputs/proof.txt"));
+
+        let stark_proof = StarkProof::decode(&proof_params, (&proof).into()).unwrap();
+        assert_eq!(stark_proof.oods_values.len(), 194);
+        // 8 FRI steps: 7 layer commitments, then a last layer of 2^6 coefficients.
+        assert_eq!(stark_proof.fri_commitments.len(), 7);
+        assert_eq!(stark_proof.last_layer_ptr, 204);
+        assert_eq!(stark_proof.last_layer.len(), 64);
+        assert_eq!(stark_proof.nonce_ptr, 268);
+    }
+
+    #[test]
+    fn test_decode_compact_proof() {
+        let proof_params = parse_input(include_str!("../../../inputs/proof_params.txt"));
+        let proof = parse_input(include_str!("../../../inputs/proof.txt"));
+        let bytes = crate::compact::proof_bytes(&proof);
+        assert!(bytes.len() < 32 * proof.len());
+
+        let words = ProofWords::Bytes(&bytes);
+        assert_eq!(words.len(), proof.len());
+        let stark_proof = StarkProof::decode(&proof_params, words).unwrap();
+        let expected = StarkProof::decode(&proof_params, (&proof).into()).unwrap();
+        assert_eq!(stark_proof.oods_values, expected.oods_values);
+        assert_eq!(stark_proof.last_layer, expected.last_layer);
+        assert_eq!(stark_proof.proof_of_work_nonce, expected.proof_of_work_nonce);
+        // Unaligned reads, up to the last word whose padding was dropped.
+        for byte_ptr in [8, 32 * 268 + 8, 32 * (proof.len() - 1)] {
+            assert_eq!(words.word_at(byte_ptr), ProofWords::Words(&proof).word_at(byte_ptr));
+        }
+    }
+
+    #[test]
+    fn test_decode_truncated_proof() {
+        let proof_params = parse_input(include_str!("../../../inputs/proof_params.txt"));
+        let proof = parse_input(include_str!("../../../inputs/proof.txt"));
+
+        let err = StarkProof::decode(&proof_params, proof[..100].into()).err().unwrap();
+        assert_eq!(err, ProofError::Truncated(ProofSection::OodsValues));
+        let err = StarkProof::decode(&proof_params, proof[..200].into()).err().unwrap();
+        assert_eq!(err, ProofError::Truncated(ProofSection::FriCommitment(4)));
+        assert_eq!(
+            Vec::<u8>::from(err),
+            Vec::<u8>::from(ProofTruncated { section: 3, index: U256::from(4) })
+        );
+    }
+}
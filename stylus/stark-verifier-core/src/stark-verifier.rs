@@ -0,0 +1,274 @@
+use alloc::vec::Vec;
+
+use stylus_sdk::{
+    alloy_primitives::{FixedBytes, U256, uint},
+    crypto::keccak,
+};
+
+use utils::{
+    require,
+    require::*,
+    felt252::{Felt252, K_MODULUS},
+    public_memory_offset::PublicMemoryOffset,
+};
+use crate::layout_specific::LayoutSpecific;
+use crate::memory_map::*;
+use crate::proof::{ProofWords, StarkProof};
+use crate::verifier_channel::VerifierChannel;
+use crate::verifier_init::safe_div;
+
+// The STARK verifier pipeline. The required methods are the stages that live in other contracts
+// on-chain; the contracts implement them with external calls and InProcessVerifier calls the
+// stages of this crate directly.
+pub trait StarkVerifier: LayoutSpecific {
+
+    fn init_verifier_params(
+        &mut self,
+        public_input: &[U256],
+        proof_params: &[U256],
+    ) -> Result<(Vec<U256>, Vec<U256>), Vec<u8>>;
+
+    fn is_memory_page_fact_valid(&mut self, fact: FixedBytes<32>) -> Result<bool, Vec<u8>>;
+
+    fn compute_constraint_poly(&mut self, calldata: &[U256]) -> Result<U256, Vec<u8>>;
+
+    // Runs the FRI statement verifier on the ctx after the queries were sent and returns the
+    // final ctx.
+    fn verify_fri(
+        &mut self,
+        proof: ProofWords,
+        ctx: &[U256],
+        fri_step_sizes: &[U256],
+    ) -> Result<Vec<U256>, Vec<u8>>;
+
+    fn verify_proof(
+        &mut self,
+        proof_params: &[U256],
+        proof: ProofWords,
+        public_input: &[U256],
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        let (mut ctx, fri_step_sizes) = self.init_verifier_params(public_input, proof_params)?;
+        let stark_proof = StarkProof::decode(proof_params, proof)?;
+
+        let channel_ptr = MM_CHANNEL;
+        VerifierChannel::init_channel(
+            &mut ctx,
+            channel_ptr,
+            &get_public_input_hash(public_input)
+        );
+
+        ctx[MM_TRACE_COMMITMENT] =
+            VerifierChannel::receive_word(&mut ctx, channel_ptr, stark_proof.trace_commitments[0], true);
+
+        VerifierChannel::send_field_elements(&mut ctx, channel_ptr, N_INTERACTION_ELEMENTS, MM_INTERACTION_ELEMENTS)?;
+        ctx[MM_TRACE_COMMITMENT + 1] =
+            VerifierChannel::receive_word(&mut ctx, channel_ptr, stark_proof.trace_commitments[1], true);
+
+        VerifierChannel::send_field_elements(&mut ctx, channel_ptr, 1, MM_COMPOSITION_ALPHA)?;
+
+        ctx[MM_OODS_COMMITMENT] =
+            VerifierChannel::receive_word(&mut ctx, channel_ptr, stark_proof.composition_commitment, true);
+
+        VerifierChannel::send_field_elements(&mut ctx, channel_ptr, 1, MM_OODS_POINT)?;
+
+        // The trace oods values are followed by the composition oods values.
+        for (i, &value) in stark_proof.oods_values.iter().enumerate() {
+            let value = VerifierChannel::receive_word(&mut ctx, channel_ptr, value, true);
+            ctx[MM_OODS_VALUES + i] = Felt252::from_montgomery(value).value();
+        }
+
+        self.oods_consistency_check(&mut ctx, public_input)?;
+
+        VerifierChannel::send_field_elements(&mut ctx, channel_ptr, 1, MM_OODS_ALPHA)?;
+
+        ctx[MM_FRI_COMMITMENTS] =
+            VerifierChannel::receive_word(&mut ctx, channel_ptr, stark_proof.fri_commitments[0], true);
+
+        let n_fri_steps = fri_step_sizes.len();
+        for i in 1..n_fri_steps - 1 {
+            VerifierChannel::send_field_elements(&mut ctx, channel_ptr, 1, MM_FRI_EVAL_POINTS + i)?;
+            ctx[MM_FRI_COMMITMENTS + i] =
+                VerifierChannel::receive_word(&mut ctx, channel_ptr, stark_proof.fri_commitments[i], true);
+        }
+
+        VerifierChannel::send_field_elements(&mut ctx, channel_ptr, 1, MM_FRI_EVAL_POINTS + n_fri_steps - 1)?;
+
+        read_last_fri_layer(&mut ctx, &stark_proof.last_layer)?;
+
+        let proof_of_work_bits = ctx.proof_of_work_bits();
+        VerifierChannel::verify_proof_of_work_nonce(
+            &mut ctx,
+            MM_CHANNEL,
+            &stark_proof.proof_of_work_nonce,
+            proof_of_work_bits,
+        )?;
+
+        let count = ctx.n_unique_queries();
+        let queries_ptr = ctx.eval_domain_size() - U256::from(1);
+        ctx[MM_N_UNIQUE_QUERIES] = VerifierChannel::send_random_queries(
+            &mut ctx,
+            MM_CHANNEL,
+            count,
+            queries_ptr,
+            U256::from(MM_FRI_QUEUE),
+            U256::from(FRI_QUEUE_SLOT_SIZE),
+        )?;
+
+        self.verify_fri(proof, &ctx, &fri_step_sizes)
+    }
+
+    fn oods_consistency_check(&mut self, ctx: &mut [U256], public_input: &[U256]) -> Result<(), Vec<u8>> {
+        oods_consistency_check(self, ctx, public_input)
+    }
+
+    fn verify_memory_page_facts(&mut self, ctx: &[U256], public_input: &[U256]) -> Result<(), Vec<u8>> {
+        let n_public_memory_pages = ctx.n_public_mem_pages();
+        for page in 0..n_public_memory_pages {
+            let fact_hash_output = get_memory_page_fact(ctx, public_input, page);
+            // Verify that a corresponding fact is registered attesting to the consistency of the page
+            // information with z and alpha.
+            require!(
+                self.is_memory_page_fact_valid(fact_hash_output)?,
+                MemoryPageNotRegistered { factHash: fact_hash_output }
+            );
+        }
+        Ok(())
+    }
+}
+
+// The default StarkVerifier::oods_consistency_check, for implementations that wrap it.
+pub fn oods_consistency_check<V: StarkVerifier + ?Sized>(
+    verifier: &mut V,
+    ctx: &mut [U256],
+    public_input: &[U256],
+) -> Result<(), Vec<u8>> {
+    verifier.verify_memory_page_facts(ctx, public_input)?;
+    ctx[MM_MEMORY__MULTI_COLUMN_PERM__PERM__INTERACTION_ELM] = ctx.interaction_elements()[0];
+    ctx[MM_MEMORY__MULTI_COLUMN_PERM__HASH_INTERACTION_ELM0] = ctx.interaction_elements()[1];
+    ctx[MM_RANGE_CHECK16__PERM__INTERACTION_ELM] = ctx.interaction_elements()[2];
+
+    let public_memory_prod = compute_public_memory_quotient(ctx, public_input)?;
+    ctx[MM_MEMORY__MULTI_COLUMN_PERM__PERM__PUBLIC_MEMORY_PROD] = public_memory_prod;
+
+    verifier.prepare_for_oods_check(ctx)?;
+
+    let composition_from_trace_value = verifier.compute_constraint_poly(ctx.constraint_poly_args())?;
+    let composition_oods_values = ctx.composition_oods_values();
+    let claimed_composition = Felt252::new(composition_oods_values[0])
+        + Felt252::new(ctx.oods_point()) * Felt252::new(composition_oods_values[1]);
+    require!(
+        composition_from_trace_value == claimed_composition.value(),
+        CompositionMismatch { fromTrace: composition_from_trace_value, claimed: claimed_composition.value() }
+    );
+
+    Ok(())
+}
+
+pub fn get_public_input_hash(public_input: &[U256]) -> FixedBytes<32> {
+    let n_pages = public_input[21].to::<usize>();
+    let offset_page_prod = PublicMemoryOffset::get_offset_page_prod(0, n_pages);
+    let mut input_data = Vec::new();
+    for value in &public_input[..offset_page_prod] {
+        input_data.extend_from_slice(&value.to_be_bytes::<32>());
+    }
+
+    keccak(&input_data)
+}
+
+// The fact the memory page fact registry holds for the given public memory page, see
+// MemoryPageFactRegistry.sol.
+pub fn get_memory_page_fact(ctx: &[U256], public_input: &[U256], page: usize) -> FixedBytes<32> {
+    let n_public_memory_pages = ctx.n_public_mem_pages();
+    let memory_hash_ptr = ctx.public_input_ptr() + PublicMemoryOffset::get_offset_page_hash(page);
+    let prod_ptr = ctx.public_input_ptr() + PublicMemoryOffset::get_offset_page_prod(page, n_public_memory_pages);
+    let page_size_ptr = ctx.public_input_ptr() + PublicMemoryOffset::get_offset_page_size(page);
+
+    let memory_hash = public_input[memory_hash_ptr];
+    let prod = public_input[prod_ptr];
+    let page_size = public_input[page_size_ptr];
+
+    let mut page_addr = U256::ZERO;
+    if page > 0 {
+        let page_addr_ptr = ctx.public_input_ptr() + PublicMemoryOffset::get_offset_page_addr(page);
+        page_addr = public_input[page_addr_ptr];
+    }
+
+    let mut page_type = U256::from(1);
+    if page == 0 {
+        page_type = U256::from(0);
+    }
+    let mut hash_buffer = Vec::new();
+    hash_buffer.extend_from_slice(&page_type.to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&K_MODULUS.to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&page_size.to_be_bytes::<32>());
+    // z and alpha.
+    hash_buffer.extend_from_slice(&ctx.interaction_elements()[0].to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&ctx.interaction_elements()[1].to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&prod.to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&memory_hash.to_be_bytes::<32>());
+    hash_buffer.extend_from_slice(&page_addr.to_be_bytes::<32>());
+
+    keccak(&hash_buffer)
+}
+
+pub fn compute_public_memory_quotient(ctx: &[U256], public_input: &[U256]) -> Result<U256, Vec<u8>> {
+    let n_values = ctx.n_public_mem_entries();
+    let z = Felt252::new(ctx[MM_MEMORY__MULTI_COLUMN_PERM__PERM__INTERACTION_ELM]);
+    let alpha = Felt252::new(ctx[MM_MEMORY__MULTI_COLUMN_PERM__HASH_INTERACTION_ELM0]);
+
+    let public_memory_size = safe_div(ctx.trace_length(), U256::from(16))?;
+    require!(n_values < uint!(16777216_U256), OverflowProtection {});
+    require!(n_values <= public_memory_size, TooManyPublicMemoryEntries { nEntries: n_values });
+
+    let n_public_memory_pages = ctx.n_public_mem_pages();
+    let cumulative_prods_ptr = ctx.public_input_ptr() + PublicMemoryOffset::get_offset_page_prod(0, n_public_memory_pages);
+    let denominator = Felt252::new(compute_public_memory_prod(public_input, cumulative_prods_ptr, n_public_memory_pages, K_MODULUS));
+
+    let padding_addr_ptr = ctx.public_input_ptr() + 19;
+    let padding_addr = Felt252::new(public_input[padding_addr_ptr]);
+    let padding_value = Felt252::new(public_input[padding_addr_ptr + 1]);
+
+    let hash_first_address_value = padding_addr + padding_value * alpha;
+    let denom_pad = (z - hash_first_address_value).pow(public_memory_size - n_values);
+    let numerator = z.pow(public_memory_size);
+    Ok((numerator * (denominator * denom_pad).inverse()).value())
+}
+
+pub fn compute_public_memory_prod(public_input: &[U256], cumulative_prods_ptr: usize, n_public_memory_pages: usize, prime: U256) -> U256 {
+    let mut res = U256::from(1);
+    let last_ptr = cumulative_prods_ptr + n_public_memory_pages;
+    for value in &public_input[cumulative_prods_ptr..last_ptr] {
+        res = res.mul_mod(*value, prime);
+    }
+    res
+}
+
+// Mixes the last FRI layer into the channel, which points at it. `last_layer` holds its
+// fri_last_layer_deg_bound coefficients.
+pub fn read_last_fri_layer(ctx: &mut [U256], last_layer: &[U256]) -> Result<(), Vec<u8>> {
+    let mut bad_input = U256::ZERO;
+
+    let channel_ptr = MM_CHANNEL;
+    let last_layer_ptr = ctx[channel_ptr].to::<usize>();
+    let last_layer_end = last_layer_ptr + last_layer.len();
+    for value in last_layer {
+        if *value >= K_MODULUS {
+            bad_input |= U256::from(1);
+        }
+    }
+
+    let digest_ptr = channel_ptr + 1;
+    let mut input_data = Vec::new();
+    input_data.extend_from_slice(&(ctx[digest_ptr] + U256::from(1)).to_be_bytes::<32>());
+    for value in last_layer {
+        input_data.extend_from_slice(&value.to_be_bytes::<32>());
+    }
+
+    ctx[digest_ptr] = uint!(keccak(&input_data).into());
+    ctx[channel_ptr + 2] = U256::ZERO;
+    ctx[channel_ptr] = U256::from(last_layer_end);
+
+    require!(bad_input == U256::ZERO, InvalidFieldElement {});
+    ctx[MM_FRI_LAST_LAYER_PTR] = U256::from(last_layer_ptr);
+    Ok(())
+}
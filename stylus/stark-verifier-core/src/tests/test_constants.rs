@@ -1,4 +1,6 @@
 #![cfg_attr(not(any(test)), no_main)]
+// The vectors are dumped one word per push from the Solidity test run.
+#![allow(clippy::vec_init_then_push)]
 extern crate alloc;
 use alloc::vec::Vec;
 
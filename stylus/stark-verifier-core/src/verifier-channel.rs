@@ -3,6 +3,8 @@ use alloc::vec::Vec;
 
 use utils::{
     require,
+    require::*,
+    felt252::Felt252,
     prime_field_element0::PrimeFieldElement0
 };
 
@@ -24,7 +26,7 @@ impl VerifierChannel {
         input_data.extend_from_slice(&ctx[prng_ptr].to_be_bytes::<32>());
         input_data.extend_from_slice(&ctx[prng_ptr + 1].to_be_bytes::<32>());
         let random_bytes = uint!(keccak(&input_data).into());
-        ctx[prng_ptr + 1] = ctx[prng_ptr + 1] + U256::from(1);
+        ctx[prng_ptr + 1] += U256::from(1);
         
         random_bytes
     }
@@ -46,8 +48,13 @@ impl VerifierChannel {
     }
 
     pub fn read_bytes(proof: &[U256], ctx: &mut [U256], channel_ptr: usize, mix: bool) -> U256 {
+        let val = proof[ctx[channel_ptr].to::<usize>()];
+        VerifierChannel::receive_word(ctx, channel_ptr, val, mix)
+    }
+
+    // Same as read_bytes, for a word the caller already took from a decoded proof.
+    pub fn receive_word(ctx: &mut [U256], channel_ptr: usize, val: U256, mix: bool) -> U256 {
         let proof_ptr = ctx[channel_ptr];
-        let val = proof[proof_ptr.to::<usize>()];
         ctx[channel_ptr] = proof_ptr + U256::from(1);
 
         if mix {
@@ -82,7 +89,7 @@ impl VerifierChannel {
     pub fn read_ptr(proof: &[U256], ptr: usize, offset: usize) -> U256 {
         let element_index = ptr / 32;
         
-        if ptr % 32 == 0 {
+        if ptr.is_multiple_of(32) {
             proof[element_index]
         } else {
             let bit_shift = offset * 8;
@@ -93,10 +100,7 @@ impl VerifierChannel {
     }
 
     pub fn send_field_elements(ctx: &mut [U256], channel_ptr: usize, n_elements: usize, target_ptr: usize) -> Result<(), Vec<u8>> {
-        require!(
-            n_elements < 16777216,
-            "Overflow protection failed."
-        );
+        require!(n_elements < 16777216, OverflowProtection {});
 
         let digest_ptr = channel_ptr + 1;
         let counter_ptr = digest_ptr + 1;
@@ -109,20 +113,26 @@ impl VerifierChannel {
                 input_data.extend_from_slice(&ctx[counter_ptr].to_be_bytes::<32>());
                 
                 field_element = uint!(keccak(&input_data).into());
-                ctx[counter_ptr] = ctx[counter_ptr] + U256::from(1);
+                ctx[counter_ptr] += U256::from(1);
             }
 
-            ctx[target_ptr + i] = field_element.mul_mod(PrimeFieldElement0::K_MONTGOMERY_R_INV, PrimeFieldElement0::K_MODULUS);
+            ctx[target_ptr + i] = Felt252::from_montgomery(field_element).value();
         }
         
         Ok(())
     }
 
     pub fn read_field_element(proof: &[U256], ctx: &mut [U256], channel_ptr: usize, mix: bool) -> U256 {
-        PrimeFieldElement0::from_montgomery(VerifierChannel::read_bytes(proof, ctx, channel_ptr, mix))
+        Felt252::from_montgomery(VerifierChannel::read_bytes(proof, ctx, channel_ptr, mix)).value()
     }
 
     pub fn verify_proof_of_work(proof: &[U256], ctx: &mut [U256], channel_ptr: usize, proof_of_work_bits: U256) -> Result<U256, Vec<u8>> {
+        let nonce_bytes = &proof[ctx[channel_ptr].to::<usize>()].to_be_bytes::<32>()[0..8];
+        VerifierChannel::verify_proof_of_work_nonce(ctx, channel_ptr, nonce_bytes, proof_of_work_bits)
+    }
+
+    // Same as verify_proof_of_work, for the nonce of a decoded proof.
+    pub fn verify_proof_of_work_nonce(ctx: &mut [U256], channel_ptr: usize, nonce_bytes: &[u8], proof_of_work_bits: U256) -> Result<U256, Vec<u8>> {
         if proof_of_work_bits == U256::ZERO {
             return Ok(U256::ZERO);
         }
@@ -135,16 +145,15 @@ impl VerifierChannel {
         let hash = keccak(&input_data);
 
         let proof_ptr = ctx[channel_ptr];
-        let nonce_bytes = &proof[proof_ptr.to::<usize>()].to_be_bytes::<32>()[0..8];
 
         let mut proof_data = Vec::new();
-        proof_data.extend_from_slice(&hash.as_slice());
-        proof_data.extend_from_slice(&nonce_bytes);
+        proof_data.extend_from_slice(hash.as_slice());
+        proof_data.extend_from_slice(nonce_bytes);
         let proof_of_work_digest = U256::from_be_bytes(keccak(&proof_data).into());
 
         let mut final_input_data = Vec::new();
         final_input_data.extend_from_slice(&(digest + U256::from(1)).to_be_bytes::<32>());
-        final_input_data.extend_from_slice(&nonce_bytes);
+        final_input_data.extend_from_slice(nonce_bytes);
         let final_hash = keccak(&final_input_data).into();
         
         ctx[channel_ptr + 1] = final_hash;
@@ -152,13 +161,16 @@ impl VerifierChannel {
         ctx[channel_ptr] = proof_ptr * U256::from(32) + U256::from(8); // 8 is the offset of the nonce
 
         let proof_of_work_threshold = U256::from(1) << U256::from(256 - proof_of_work_bits.to::<usize>());
-        require!(proof_of_work_digest < proof_of_work_threshold, "Proof of work check failed.");
+        require!(
+            proof_of_work_digest < proof_of_work_threshold,
+            ProofOfWorkFailed { proofOfWorkBits: proof_of_work_bits }
+        );
 
         Ok(proof_of_work_digest)
     }
 
     pub fn send_random_queries(ctx: &mut [U256], channel_ptr: usize, count: usize, mask: U256, queries_out_ptr: U256, stride: U256) -> Result<U256, Vec<u8>> {
-        require!(mask < U256::from(1) << U256::from(64), "mask must be < 2**64.");
+        require!(mask < U256::from(1) << U256::from(64), InvalidMask { mask });
 
         let mut val = U256::from(0);
         let mut shift = U256::from(0);
@@ -183,7 +195,7 @@ impl VerifierChannel {
                 }
                 
                 ctx[ptr.to::<usize>()] = curr;
-                ptr = ptr - stride;
+                ptr -= stride;
             }
 
             if query_idx != curr {
@@ -0,0 +1,238 @@
+use alloc::vec::Vec;
+
+use stylus_sdk::alloy_primitives::{uint, U256};
+
+use utils::{
+    require,
+    require::*,
+    felt252::{Felt252, K_MODULUS},
+};
+use crate::layouts::{self, *};
+use crate::memory_map::*;
+
+// Validates the proof params against num_security_bits and builds the initial ctx of the layout
+// selected by the public input. Returns the ctx and the FRI step sizes.
+pub fn init_verifier_params(
+    public_input: &[U256],
+    proof_params: &[U256],
+    num_security_bits: U256,
+) -> Result<(Vec<U256>, Vec<U256>), Vec<u8>> {
+    require!(
+        proof_params.len() >= 5,
+        InvalidProofParams {}
+    );
+
+    require!(
+        proof_params.len() == (5 + proof_params[4].to::<usize>()),
+        InvalidProofParams {}
+    );
+    let log_blowup_factor = proof_params[1];
+    require!(
+        log_blowup_factor >= U256::from(1) && log_blowup_factor <= U256::from(16),
+        InvalidBlowupFactor { logBlowupFactor: log_blowup_factor }
+    );
+
+    let proof_of_work_bits = proof_params[2];
+    // Proofs may not be purely based on PoW.
+    require!(
+        proof_of_work_bits >= U256::from(1)
+            && proof_of_work_bits <= U256::from(50)
+            && proof_of_work_bits < num_security_bits,
+        InvalidProofOfWorkBits { proofOfWorkBits: proof_of_work_bits }
+    );
+
+    let log_fri_last_layer_deg_bound = proof_params[3];
+    require!(
+        log_fri_last_layer_deg_bound <= U256::from(10),
+        InvalidLastLayerDegBound { logLastLayerDegBound: log_fri_last_layer_deg_bound }
+    );
+
+    let n_fri_steps = proof_params[4].to::<usize>();
+    require!(
+        n_fri_steps > 1 && n_fri_steps <= MAX_FRI_STEPS,
+        InvalidNumberOfFriSteps { nFriSteps: proof_params[4] }
+    );
+
+    let mut fri_step_sizes: Vec<U256> = Vec::new();
+    for i in 0..n_fri_steps {
+        fri_step_sizes.push(proof_params[5 + i]);
+    }
+
+    let (mut ctx, log_trace_length) = air_specific_init(public_input)?;
+    validate_fri_params(&fri_step_sizes, log_trace_length, log_fri_last_layer_deg_bound)?;
+
+    ctx[MM_FRI_LAST_LAYER_DEG_BOUND] = U256::from(1) << log_fri_last_layer_deg_bound;
+    ctx[MM_TRACE_LENGTH] = U256::from(1) << log_trace_length;
+    ctx[MM_BLOW_UP_FACTOR] = U256::from(1) << log_blowup_factor;
+    ctx[MM_PROOF_OF_WORK_BITS] = proof_of_work_bits;
+
+    let n_queries = proof_params[0];
+    require!(
+        n_queries > U256::ZERO && n_queries <= U256::from(MAX_N_QUERIES),
+        InvalidNumberOfQueries { nQueries: n_queries }
+    );
+    let security_bits = n_queries * log_blowup_factor + proof_of_work_bits;
+    require!(
+        security_bits >= num_security_bits,
+        InsufficientSecurity { securityBits: security_bits, numSecurityBits: num_security_bits }
+    );
+
+    ctx[MM_N_UNIQUE_QUERIES] = n_queries;
+    ctx[MM_LOG_EVAL_DOMAIN_SIZE] = log_trace_length + log_blowup_factor;
+    ctx[MM_EVAL_DOMAIN_SIZE] = U256::from(1) << ctx[MM_LOG_EVAL_DOMAIN_SIZE];
+
+    let gen_eval_domain = Felt252::GENERATOR.pow((K_MODULUS - U256::from(1)) / ctx.eval_domain_size());
+    ctx[MM_EVAL_DOMAIN_GENERATOR] = gen_eval_domain.value();
+    ctx[MM_TRACE_GENERATOR] = gen_eval_domain.pow(ctx.blow_up_factor()).value();
+
+    Ok((ctx, fri_step_sizes))
+}
+
+fn validate_fri_params(
+    fri_step_sizes: &[U256],
+    log_trace_length: U256,
+    log_fri_last_layer_deg_bound: U256,
+) -> Result<(), Vec<u8>> {
+    // Only eta0 == 0 is currently supported.
+    require!(fri_step_sizes[0] == U256::ZERO, InvalidFriStepSize { friStepSize: fri_step_sizes[0] });
+
+    let mut expected_log_deg_bound = log_fri_last_layer_deg_bound;
+    for &fri_step_size in &fri_step_sizes[1..] {
+        require!(
+            fri_step_size >= U256::from(2) && fri_step_size <= U256::from(4),
+            InvalidFriStepSize { friStepSize: fri_step_size }
+        );
+        expected_log_deg_bound += fri_step_size;
+    }
+
+    require!(
+        expected_log_deg_bound == log_trace_length,
+        FriParamsMismatch { logDegBound: expected_log_deg_bound, logTraceLength: log_trace_length }
+    );
+    Ok(())
+}
+
+fn air_specific_init(public_input: &[U256]) -> Result<(Vec<U256>, U256), Vec<u8>> {
+    require!(
+        public_input.len() > OFFSET_LAYOUT_CODE,
+        PublicInputTooShort { length: U256::from(public_input.len()) }
+    );
+    let layout = layouts::get_layout(public_input[OFFSET_LAYOUT_CODE])?;
+    require!(
+        public_input.len() > layout.public_memory_offset,
+        PublicInputTooShort { length: U256::from(public_input.len()) }
+    );
+    let mut ctx = vec![U256::ZERO; MM_CONTEXT_SIZE];
+    ctx[MM_OFFSET_SIZE] = U256::from(65536);
+    ctx[MM_HALF_OFFSET_SIZE] = U256::from(32768);
+
+    let log_n_steps = public_input[OFFSET_LOG_N_STEPS];
+    require!(log_n_steps < U256::from(50), TooManySteps { logNSteps: log_n_steps });
+    ctx[MM_LOG_N_STEPS] = log_n_steps;
+    let log_trace_length = log_n_steps + U256::from(layout.log_cpu_component_height);
+
+    ctx[MM_RANGE_CHECK_MIN] = public_input[OFFSET_RC_MIN];
+    ctx[MM_RANGE_CHECK_MAX] = public_input[OFFSET_RC_MAX];
+    require!(
+        ctx[MM_RANGE_CHECK_MIN] <= ctx[MM_RANGE_CHECK_MAX] && ctx[MM_RANGE_CHECK_MAX] < ctx[MM_OFFSET_SIZE],
+        InvalidRangeCheckBounds { rcMin: ctx[MM_RANGE_CHECK_MIN], rcMax: ctx[MM_RANGE_CHECK_MAX] }
+    );
+
+    ctx[MM_INITIAL_PC] = public_input[OFFSET_PROGRAM_BEGIN_ADDR];
+    ctx[MM_FINAL_PC] = public_input[OFFSET_PROGRAM_STOP_PTR];
+    require!(ctx[MM_INITIAL_PC] == U256::from(INITIAL_PC), InvalidInitialPc { pc: ctx[MM_INITIAL_PC] });
+    require!(ctx[MM_FINAL_PC] == U256::from(FINAL_PC), InvalidFinalPc { pc: ctx[MM_FINAL_PC] });
+
+    ctx[MM_INITIAL_AP] = public_input[OFFSET_EXECUTION_BEGIN_ADDR];
+    ctx[MM_FINAL_AP] = public_input[OFFSET_EXECUTION_STOP_PTR];
+    let n_pages = public_input[layout.public_memory_offset];
+    require!(
+        n_pages >= U256::from(1) && n_pages < U256::from(100000),
+        InvalidNumberOfMemoryPages { nPages: n_pages }
+    );
+
+    ctx[MM_N_PUBLIC_MEM_PAGES] = n_pages;
+
+    let mut n_public_memory_entries = U256::from(0);
+    for page in 0..n_pages.to::<usize>() {
+        let n_page_entries = public_input[layout.get_offset_page_size(page)];
+        require!(
+            n_page_entries < U256::from(1073741824),
+            TooManyPublicMemoryEntries { nEntries: n_page_entries }
+        );
+        n_public_memory_entries += n_page_entries;
+    }
+    ctx[MM_N_PUBLIC_MEM_ENTRIES] = n_public_memory_entries;
+
+    let expected_public_input_length = layout.get_public_input_length(n_pages.to::<usize>());
+    require!(
+        expected_public_input_length == public_input.len(),
+        PublicInputLengthMismatch {
+            expected: U256::from(expected_public_input_length),
+            actual: U256::from(public_input.len()),
+        }
+    );
+
+    layout_specific_init(&mut ctx, public_input, layout)?;
+
+    Ok((ctx, log_trace_length))
+}
+
+fn layout_specific_init(ctx: &mut [U256], public_input: &[U256], layout: &Layout) -> Result<(), Vec<u8>> {
+    let output_begin_addr = public_input[OFFSET_OUTPUT_BEGIN_ADDR];
+    let output_stop_ptr = public_input[OFFSET_OUTPUT_STOP_PTR];
+    require!(
+        output_begin_addr <= output_stop_ptr && output_stop_ptr < uint!(18446744073709551616_U256),
+        InvalidSegment { beginAddr: output_begin_addr, stopPtr: output_stop_ptr }
+    );
+
+    let n_steps = U256::from(1) << ctx[MM_LOG_N_STEPS];
+    for builtin in layout.builtins {
+        ctx[builtin.ctx_initial_addr] = public_input[builtin.begin_addr_offset];
+        validate_builtin_pointers(
+            ctx[builtin.ctx_initial_addr],
+            public_input[builtin.begin_addr_offset + 1],
+            U256::from(builtin.ratio),
+            U256::from(builtin.cells_per_instance),
+            n_steps,
+        )?;
+    }
+
+    ctx[MM_PEDERSEN__SHIFT_POINT_X] = PEDERSEN_SHIFT_POINT_X;
+    ctx[MM_PEDERSEN__SHIFT_POINT_Y] = PEDERSEN_SHIFT_POINT_Y;
+
+    ctx[MM_RANGE_CHECK16__PERM__PUBLIC_MEMORY_PROD] = U256::from(1);
+
+    ctx[MM_DILUTED_CHECK__PERMUTATION__PUBLIC_MEMORY_PROD] = U256::from(1);
+    ctx[MM_DILUTED_CHECK__FIRST_ELM] = U256::from(0);
+
+    Ok(())
+}
+
+fn validate_builtin_pointers(
+    initial_address: U256,
+    stop_address: U256,
+    builtin_ratio: U256,
+    cells_per_instance: U256,
+    n_steps: U256
+) -> Result<(), Vec<u8>> {
+    require!(
+        initial_address < uint!(18446744073709551616_U256),
+        InvalidSegment { beginAddr: initial_address, stopPtr: stop_address }
+    );
+    let max_stop_ptr = initial_address + cells_per_instance * safe_div(n_steps, builtin_ratio)?;
+    require!(
+        initial_address <= stop_address && stop_address <= max_stop_ptr,
+        InvalidSegment { beginAddr: initial_address, stopPtr: stop_address }
+    );
+    Ok(())
+}
+
+pub fn safe_div(
+    numerator: U256,
+    denominator: U256
+) -> Result<U256, Vec<u8>> {
+    require!(denominator != U256::ZERO, DivisionByZero {});
+    require!(numerator % denominator == U256::ZERO, NotDivisible { numerator, denominator });
+    Ok(numerator / denominator)
+}
@@ -0,0 +1,525 @@
+//! Runs the CPU verifier and the GPS statement verifier on proof files, in process.
+//!
+//! The stages are the ones of `stark-verifier-core` that the contracts run. The only difference
+//! is where the facts come from: the Merkle statements, the FRI statements and the continuous
+//! memory pages are registered on-chain by separate transactions, so here they are either looked
+//! up in a list of known facts or assumed registered and reported.
+use std::collections::BTreeSet;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use alloy_primitives::{FixedBytes, U256};
+use stark_verifier_core::{
+    fri_statement_verifier::FriVerifier,
+    gps::{self, consts::public_input_offsets, GpsFact, K_MODULUS},
+    layout_specific::{self, LayoutSpecific},
+    periodic_columns::PeriodicColumn,
+    proof::ProofWords,
+    stark_verifier::{self, StarkVerifier},
+    FactKind, FactOracle, InProcessVerifier,
+};
+use utils::require::{InvalidCairoAuxInput, StarkError};
+
+// Parses a list of words in the format of the files in inputs/, e.g. `[1,2,0x3]`.
+pub fn parse_words(input: &str) -> Result<Vec<U256>, String> {
+    let input = input.trim().trim_start_matches('[').trim_end_matches(']');
+    input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.parse::<U256>().map_err(|e| format!("invalid word {word}: {e}")))
+        .collect()
+}
+
+// Parses a list of facts, one bytes32 per line. Empty lines and lines starting with # are
+// skipped.
+pub fn parse_facts(input: &str) -> Result<BTreeSet<FixedBytes<32>>, String> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.parse().map_err(|e| format!("invalid fact {line}: {e}")))
+        .collect()
+}
+
+// The stages of a verification, in the order they run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    // GPS only: building the main page and checking it against the public input.
+    MainPage,
+    InitVerifierParams,
+    // Reading the commitments, the last FRI layer and the proof of work from the channel.
+    Channel,
+    MemoryPageFacts,
+    OodsConsistencyCheck,
+    Decommitment,
+    Oods,
+    FriLayers,
+    // GPS only: computing the facts of the tasks from the program output pages.
+    GpsFacts,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Stage::MainPage => "main page",
+            Stage::InitVerifierParams => "verifier init",
+            Stage::Channel => "channel",
+            Stage::MemoryPageFacts => "memory page facts",
+            Stage::OodsConsistencyCheck => "oods consistency check",
+            Stage::Decommitment => "trace decommitment",
+            Stage::Oods => "oods",
+            Stage::FriLayers => "fri layers",
+            Stage::GpsFacts => "gps facts",
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FactStatus {
+    // Known to be registered, or registered earlier in the same verification.
+    Registered,
+    // No list of known facts was given, the fact has to be registered before sending the proof.
+    Assumed,
+    // Not in the list of known facts.
+    Missing,
+}
+
+// A fact the verifier looked up in one of the fact registries.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CheckedFact {
+    pub kind: FactKind,
+    pub fact: FixedBytes<32>,
+    pub status: FactStatus,
+}
+
+// A fact the contracts would register if the proof were sent on-chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegisteredFact {
+    // The main page, registered in the memory page fact registry by the GPS statement verifier.
+    MainPage(FixedBytes<32>),
+    Gps(GpsFact),
+}
+
+// What a verification shows about the proof.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    // The proof passes and every fact it relies on is known to be registered.
+    Pass,
+    // The proof passes if the assumed facts are registered on-chain, which was not checked.
+    Unverified { assumed_facts: usize },
+    Fail,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    // The stage that failed and the revert message.
+    pub failure: Option<(Stage, String)>,
+    pub checked_facts: Vec<CheckedFact>,
+    pub registered_facts: Vec<RegisteredFact>,
+    // The ctx returned by verifyProofExternal, if the proof was verified.
+    pub ctx: Option<Vec<U256>>,
+}
+
+impl Report {
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+
+    pub fn outcome(&self) -> Outcome {
+        let assumed_facts =
+            self.checked_facts.iter().filter(|fact| fact.status == FactStatus::Assumed).count();
+        match (self.passed(), assumed_facts) {
+            (false, _) => Outcome::Fail,
+            (true, 0) => Outcome::Pass,
+            (true, assumed_facts) => Outcome::Unverified { assumed_facts },
+        }
+    }
+}
+
+// Answers the fact lookups from the known facts, or accepts every fact if there are none.
+struct Facts {
+    known: Option<BTreeSet<FixedBytes<32>>>,
+    registered: BTreeSet<FixedBytes<32>>,
+    checked: Vec<CheckedFact>,
+}
+
+impl FactOracle for Facts {
+    fn is_valid(&mut self, kind: FactKind, fact: FixedBytes<32>) -> bool {
+        let status = if self.registered.contains(&fact) {
+            FactStatus::Registered
+        } else {
+            match &self.known {
+                Some(known) if known.contains(&fact) => FactStatus::Registered,
+                Some(_) => FactStatus::Missing,
+                None => FactStatus::Assumed,
+            }
+        };
+        self.checked.push(CheckedFact { kind, fact, status });
+        status != FactStatus::Missing
+    }
+}
+
+// Keeps track of the stage the in-process verifier is in.
+struct StagedVerifier {
+    inner: InProcessVerifier<Facts>,
+    stage: Stage,
+}
+
+impl LayoutSpecific for StagedVerifier {
+    fn compute_periodic_column(&mut self, column: PeriodicColumn, x: U256) -> Result<U256, Vec<u8>> {
+        self.inner.compute_periodic_column(column, x)
+    }
+}
+
+impl StarkVerifier for StagedVerifier {
+    fn init_verifier_params(
+        &mut self,
+        public_input: &[U256],
+        proof_params: &[U256],
+    ) -> Result<(Vec<U256>, Vec<U256>), Vec<u8>> {
+        self.stage = Stage::InitVerifierParams;
+        let res = self.inner.init_verifier_params(public_input, proof_params)?;
+        self.stage = Stage::Channel;
+        Ok(res)
+    }
+
+    fn is_memory_page_fact_valid(&mut self, fact: FixedBytes<32>) -> Result<bool, Vec<u8>> {
+        self.inner.is_memory_page_fact_valid(fact)
+    }
+
+    fn compute_constraint_poly(&mut self, calldata: &[U256]) -> Result<U256, Vec<u8>> {
+        self.inner.compute_constraint_poly(calldata)
+    }
+
+    fn verify_fri(
+        &mut self,
+        proof: ProofWords,
+        ctx: &[U256],
+        fri_step_sizes: &[U256],
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        self.stage = Stage::Decommitment;
+        let mut ctx = ctx.to_vec();
+        FriVerifier::verify(self, proof, &mut ctx, fri_step_sizes)?;
+        Ok(ctx)
+    }
+
+    fn oods_consistency_check(&mut self, ctx: &mut [U256], public_input: &[U256]) -> Result<(), Vec<u8>> {
+        self.stage = Stage::OodsConsistencyCheck;
+        stark_verifier::oods_consistency_check(self, ctx, public_input)?;
+        self.stage = Stage::Channel;
+        Ok(())
+    }
+
+    fn verify_memory_page_facts(&mut self, ctx: &[U256], public_input: &[U256]) -> Result<(), Vec<u8>> {
+        let stage = self.stage;
+        self.stage = Stage::MemoryPageFacts;
+        self.inner.verify_memory_page_facts(ctx, public_input)?;
+        self.stage = stage;
+        Ok(())
+    }
+}
+
+impl FriVerifier for StagedVerifier {
+    fn compute_oods(&mut self, ctx: &[U256]) -> Result<Vec<U256>, Vec<u8>> {
+        self.stage = Stage::Oods;
+        let res = self.inner.compute_oods(ctx)?;
+        self.stage = Stage::FriLayers;
+        Ok(res)
+    }
+
+    fn is_merkle_statement_valid(&mut self, statement: FixedBytes<32>) -> Result<bool, Vec<u8>> {
+        self.inner.is_merkle_statement_valid(statement)
+    }
+
+    fn is_fri_statement_valid(&mut self, statement: FixedBytes<32>) -> Result<bool, Vec<u8>> {
+        self.inner.is_fri_statement_valid(statement)
+    }
+}
+
+pub struct OfflineVerifier {
+    pub num_security_bits: U256,
+    // The facts known to be registered on-chain. If None, every fact is assumed registered.
+    pub known_facts: Option<BTreeSet<FixedBytes<32>>>,
+}
+
+impl OfflineVerifier {
+    pub fn new(num_security_bits: U256, known_facts: Option<BTreeSet<FixedBytes<32>>>) -> Self {
+        Self { num_security_bits, known_facts }
+    }
+
+    // Runs what CpuVerifier::verifyProofExternal runs.
+    pub fn verify_proof(&self, proof_params: &[U256], proof: &[U256], public_input: &[U256]) -> Report {
+        let mut verifier = self.staged_verifier(BTreeSet::new());
+        let mut report = Report::default();
+        match verify_proof(&mut verifier, proof_params, proof, public_input) {
+            Ok(ctx) => report.ctx = Some(ctx),
+            Err(err) => report.failure = Some((verifier.stage, err)),
+        }
+        report.checked_facts = verifier.inner.into_facts().checked;
+        report
+    }
+
+    // Runs what GpsStatementVerifier::verifyProofAndRegister runs, with this layout as the
+    // cairo verifier.
+    pub fn verify_gps_statement(
+        &self,
+        proof_params: &[U256],
+        proof: &[U256],
+        task_metadata: &[U256],
+        cairo_aux_input: &[U256],
+    ) -> Report {
+        let mut report = Report::default();
+        let main_page = catch_panic(|| main_page_fact(task_metadata, cairo_aux_input));
+        let main_page_fact = match main_page {
+            Ok(fact) => fact,
+            Err(err) => {
+                report.failure = Some((Stage::MainPage, err));
+                return report;
+            }
+        };
+        report.registered_facts.push(RegisteredFact::MainPage(main_page_fact));
+
+        let cairo_public_input = &cairo_aux_input[..cairo_aux_input.len() - 2];
+        let mut verifier = self.staged_verifier(BTreeSet::from([main_page_fact]));
+        let verified = verify_proof(&mut verifier, proof_params, proof, cairo_public_input);
+        let stage = verifier.stage;
+        report.checked_facts = verifier.inner.into_facts().checked;
+        match verified {
+            Ok(ctx) => report.ctx = Some(ctx),
+            Err(err) => {
+                report.failure = Some((stage, err));
+                return report;
+            }
+        }
+
+        match catch_panic(|| gps_facts(task_metadata, cairo_aux_input)) {
+            Ok(facts) => report.registered_facts.extend(facts.into_iter().map(RegisteredFact::Gps)),
+            Err(err) => report.failure = Some((Stage::GpsFacts, err)),
+        }
+        report
+    }
+
+    fn staged_verifier(&self, registered: BTreeSet<FixedBytes<32>>) -> StagedVerifier {
+        let facts = Facts {
+            known: self.known_facts.clone(),
+            registered,
+            checked: Vec::new(),
+        };
+        StagedVerifier {
+            inner: InProcessVerifier::new(self.num_security_bits, facts),
+            stage: Stage::InitVerifierParams,
+        }
+    }
+}
+
+fn verify_proof(
+    verifier: &mut StagedVerifier,
+    proof_params: &[U256],
+    proof: &[U256],
+    public_input: &[U256],
+) -> Result<Vec<U256>, String> {
+    catch_panic(AssertUnwindSafe(|| {
+        StarkVerifier::verify_proof(verifier, proof_params, proof.into(), public_input)
+    }))
+}
+
+// Builds the main page and checks it against the public input, the way the GPS statement
+// verifier does before registering it. Returns the fact of the main page.
+fn main_page_fact(task_metadata: &[U256], cairo_aux_input: &[U256]) -> Result<FixedBytes<32>, Vec<u8>> {
+    if cairo_aux_input.len() < 2 {
+        return Err(InvalidCairoAuxInput {}.into());
+    }
+    let cairo_public_input = &cairo_aux_input[..cairo_aux_input.len() - 2];
+    let (public_memory_offset, mut selected_builtins) = layout_specific::get_layout_info();
+    let public_memory_pages = gps::get_public_memory_pages(cairo_public_input, public_memory_offset)?;
+    let n_pages: usize = public_memory_pages[0].to();
+
+    let public_memory =
+        gps::build_public_memory_main_page(task_metadata, cairo_aux_input, &mut selected_builtins)?;
+    let z = cairo_aux_input[cairo_aux_input.len() - 2];
+    let alpha = cairo_aux_input[cairo_aux_input.len() - 1];
+    let (fact, memory_hash, product) =
+        gps::compute_regular_memory_page_fact(&public_memory, z, alpha, K_MODULUS);
+
+    gps::verify_main_page_info(
+        public_memory_pages,
+        n_pages,
+        U256::from(public_memory.len() / 2),
+        memory_hash,
+        product,
+    )?;
+    Ok(fact)
+}
+
+fn gps_facts(task_metadata: &[U256], cairo_aux_input: &[U256]) -> Result<Vec<GpsFact>, Vec<u8>> {
+    let cairo_public_input = &cairo_aux_input[..cairo_aux_input.len() - 2];
+    let (public_memory_offset, _) = layout_specific::get_layout_info();
+    let public_memory_pages = gps::get_public_memory_pages(cairo_public_input, public_memory_offset)?;
+    gps::compute_gps_facts(
+        task_metadata,
+        public_memory_pages,
+        cairo_aux_input[public_input_offsets::OFFSET_OUTPUT_BEGIN_ADDR],
+    )
+}
+
+// Runs a stage and turns the error it reverts with, or the panic a malformed input can cause while
+// indexing, into an error message.
+fn catch_panic<T>(stage: impl FnOnce() -> Result<T, Vec<u8>> + panic::UnwindSafe) -> Result<T, String> {
+    match panic::catch_unwind(stage) {
+        Ok(res) => res.map_err(|err| match StarkError::decode(&err) {
+            Some(err) => format!("{err:?}"),
+            None => {
+                let data: String = err.iter().map(|b| format!("{b:02x}")).collect();
+                format!("unknown error 0x{data}")
+            }
+        }),
+        Err(panic) => {
+            let msg = panic
+                .downcast_ref::<&str>()
+                .map(|msg| msg.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Err(format!("panicked: {msg}"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::require::MemoryPageNotRegistered;
+
+    // The proofs in inputs/ have 96 bits of security, see deploy.toml.
+    const NUM_SECURITY_BITS: u64 = 96;
+
+    fn input(name: &str) -> Vec<U256> {
+        let path = format!("{}/../../inputs/{name}", env!("CARGO_MANIFEST_DIR"));
+        parse_words(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    // Decodes the abi encoded uint256[] returned by verifyProofExternal.
+    fn parse_abi_words(input: &str) -> Vec<U256> {
+        let bytes = hex::decode(input.trim().trim_start_matches("0x")).unwrap();
+        let words: Vec<U256> = bytes.chunks_exact(32).map(U256::from_be_slice).collect();
+        let len = words[1].to::<usize>();
+        words[2..2 + len].to_vec()
+    }
+
+    #[test]
+    fn test_verify_proof() {
+        let verifier = OfflineVerifier::new(U256::from(NUM_SECURITY_BITS), None);
+        let report = verifier.verify_proof(
+            &input("proof_params.txt"),
+            &input("proof.txt"),
+            &input("public_input.txt"),
+        );
+
+        assert_eq!(report.failure, None);
+        let expected_ctx = parse_abi_words(include_str!("../../../inputs/expected_out.txt"));
+        assert_eq!(report.ctx, Some(expected_ctx));
+        assert!(report.checked_facts.iter().all(|fact| fact.status == FactStatus::Assumed));
+        assert!(report.registered_facts.is_empty());
+        // Without the list of registered facts, the proof only passes on assumptions.
+        assert_eq!(
+            report.outcome(),
+            Outcome::Unverified { assumed_facts: report.checked_facts.len() }
+        );
+
+        let known_facts = report.checked_facts.iter().map(|checked| checked.fact).collect();
+        let verifier = OfflineVerifier::new(U256::from(NUM_SECURITY_BITS), Some(known_facts));
+        let report = verifier.verify_proof(
+            &input("proof_params.txt"),
+            &input("proof.txt"),
+            &input("public_input.txt"),
+        );
+        assert_eq!(report.outcome(), Outcome::Pass);
+    }
+
+    #[test]
+    fn test_verify_proof_reports_failing_stage() {
+        // Only the first memory page is known to be registered.
+        let mut verifier = OfflineVerifier::new(U256::from(NUM_SECURITY_BITS), None);
+        let report = verifier.verify_proof(
+            &input("proof_params.txt"),
+            &input("proof.txt"),
+            &input("public_input.txt"),
+        );
+        verifier.known_facts = Some(BTreeSet::from([report.checked_facts[0].fact]));
+
+        let report = verifier.verify_proof(
+            &input("proof_params.txt"),
+            &input("proof.txt"),
+            &input("public_input.txt"),
+        );
+        assert_eq!(
+            report.failure,
+            Some((
+                Stage::MemoryPageFacts,
+                format!(
+                    "{:?}",
+                    StarkError::MemoryPageNotRegistered(MemoryPageNotRegistered {
+                        factHash: report.checked_facts[1].fact
+                    })
+                )
+            ))
+        );
+        assert_eq!(report.checked_facts[1].status, FactStatus::Missing);
+        assert_eq!(report.ctx, None);
+        assert_eq!(report.outcome(), Outcome::Fail);
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_insufficient_security() {
+        let verifier = OfflineVerifier::new(U256::from(NUM_SECURITY_BITS + 1), None);
+        let report = verifier.verify_proof(
+            &input("proof_params.txt"),
+            &input("proof.txt"),
+            &input("public_input.txt"),
+        );
+        assert_eq!(report.failure.unwrap().0, Stage::InitVerifierParams);
+    }
+
+    #[test]
+    fn test_verify_gps_statement() {
+        let verifier = OfflineVerifier::new(U256::from(NUM_SECURITY_BITS), None);
+        let task_metadata = input("gps_input_task.txt");
+        let report = verifier.verify_gps_statement(
+            &input("gps_input_proof_params.txt"),
+            &input("gps_input_proof.txt"),
+            &task_metadata,
+            &input("gps_input_aux.txt"),
+        );
+
+        assert_eq!(report.failure, None);
+        // The main page is registered by the GPS statement verifier itself, before the proof is
+        // verified.
+        let main_page_fact = match &report.registered_facts[0] {
+            RegisteredFact::MainPage(fact) => *fact,
+            fact => panic!("unexpected fact {fact:?}"),
+        };
+        let main_page_check = &report.checked_facts[0];
+        assert_eq!(main_page_check.kind, FactKind::MemoryPage);
+        assert_eq!(main_page_check.fact, main_page_fact);
+        assert_eq!(main_page_check.status, FactStatus::Registered);
+        // One fact per task.
+        assert_eq!(report.registered_facts.len(), 1 + task_metadata[0].to::<usize>());
+    }
+
+    #[test]
+    fn test_verify_gps_statement_rejects_tampered_main_page() {
+        let verifier = OfflineVerifier::new(U256::from(NUM_SECURITY_BITS), None);
+        let mut task_metadata = input("gps_input_task.txt");
+        // The program hash of the first task is part of the main page.
+        task_metadata[2] += U256::from(1);
+        let report = verifier.verify_gps_statement(
+            &input("gps_input_proof_params.txt"),
+            &input("gps_input_proof.txt"),
+            &task_metadata,
+            &input("gps_input_aux.txt"),
+        );
+        let (stage, err) = report.failure.unwrap();
+        assert_eq!(stage, Stage::MainPage);
+        assert!(err.starts_with("InvalidMainPageHash("), "{err}");
+        assert!(report.registered_facts.is_empty());
+    }
+}
@@ -0,0 +1,156 @@
+//! Verifies proof files without a node.
+//!
+//!     stark-verify --proof-params FILE --proof FILE --public-input FILE
+//!     stark-verify --proof-params FILE --proof FILE --task-metadata FILE --aux-input FILE
+//!
+//! The first form runs what cpu-verifier's verifyProofExternal runs, the second what gps-sv's
+//! verifyProofAndRegister runs. The files have the format of the ones in inputs/.
+//!
+//! Options:
+//!     --security-bits N   the security level set on verifier-init (default 96)
+//!     --facts FILE        the facts registered on-chain, one bytes32 per line. Without it every
+//!                         Merkle statement, FRI statement and memory page is assumed registered.
+//!
+//! Exits with 0 if the proof passes, 1 if it fails and 2 on invalid arguments. Without --facts,
+//! a proof that passes is UNVERIFIED, since it relies on facts nobody checked are registered, and
+//! exits with 3.
+use std::collections::BTreeSet;
+use std::process::ExitCode;
+use std::{env, fs};
+
+use alloy_primitives::{FixedBytes, U256};
+use stark_verify::{
+    parse_facts, parse_words, FactStatus, OfflineVerifier, Outcome, RegisteredFact, Report,
+};
+use stark_verifier_core::FactKind;
+
+const DEFAULT_SECURITY_BITS: u64 = 96;
+const EXIT_UNVERIFIED: u8 = 3;
+
+#[derive(Default)]
+struct Args {
+    proof_params: Option<String>,
+    proof: Option<String>,
+    public_input: Option<String>,
+    task_metadata: Option<String>,
+    aux_input: Option<String>,
+    facts: Option<String>,
+    security_bits: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args::default();
+    let mut argv = env::args().skip(1);
+    while let Some(flag) = argv.next() {
+        let slot = match flag.as_str() {
+            "--proof-params" => &mut args.proof_params,
+            "--proof" => &mut args.proof,
+            "--public-input" => &mut args.public_input,
+            "--task-metadata" => &mut args.task_metadata,
+            "--aux-input" => &mut args.aux_input,
+            "--facts" => &mut args.facts,
+            "--security-bits" => &mut args.security_bits,
+            _ => return Err(format!("unknown argument {flag}")),
+        };
+        *slot = Some(argv.next().ok_or(format!("missing value for {flag}"))?);
+    }
+    Ok(args)
+}
+
+fn read_words(path: &str) -> Result<Vec<U256>, String> {
+    let input = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+    parse_words(&input).map_err(|e| format!("{path}: {e}"))
+}
+
+fn run(args: Args) -> Result<Report, String> {
+    let num_security_bits = match &args.security_bits {
+        Some(bits) => bits.parse().map_err(|_| format!("invalid security bits {bits}"))?,
+        None => U256::from(DEFAULT_SECURITY_BITS),
+    };
+    let known_facts: Option<BTreeSet<FixedBytes<32>>> = match &args.facts {
+        Some(path) => {
+            let input = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+            Some(parse_facts(&input).map_err(|e| format!("{path}: {e}"))?)
+        }
+        None => None,
+    };
+    let verifier = OfflineVerifier::new(num_security_bits, known_facts);
+
+    let proof_params = read_words(args.proof_params.as_deref().ok_or("missing --proof-params")?)?;
+    let proof = read_words(args.proof.as_deref().ok_or("missing --proof")?)?;
+    match (&args.public_input, &args.task_metadata, &args.aux_input) {
+        (Some(public_input), None, None) => {
+            Ok(verifier.verify_proof(&proof_params, &proof, &read_words(public_input)?))
+        }
+        (None, Some(task_metadata), Some(aux_input)) => Ok(verifier.verify_gps_statement(
+            &proof_params,
+            &proof,
+            &read_words(task_metadata)?,
+            &read_words(aux_input)?,
+        )),
+        _ => Err("expected either --public-input or --task-metadata and --aux-input".to_string()),
+    }
+}
+
+fn fact_kind_name(kind: FactKind) -> &'static str {
+    match kind {
+        FactKind::MemoryPage => "memory page",
+        FactKind::MerkleStatement => "merkle statement",
+        FactKind::FriStatement => "fri statement",
+    }
+}
+
+fn print_report(report: &Report) {
+    match (report.outcome(), &report.failure) {
+        (_, Some((stage, err))) => println!("result: FAIL at {stage}: {err}"),
+        (Outcome::Unverified { assumed_facts }, None) => {
+            println!("result: UNVERIFIED (passes with {assumed_facts} facts assumed registered)")
+        }
+        (_, None) => println!("result: PASS"),
+    }
+
+    println!("facts checked ({}):", report.checked_facts.len());
+    for checked in &report.checked_facts {
+        let status = match checked.status {
+            FactStatus::Registered => "registered",
+            FactStatus::Assumed => "assumed",
+            FactStatus::Missing => "MISSING",
+        };
+        println!("  {:<16} {} {status}", fact_kind_name(checked.kind), checked.fact);
+    }
+
+    println!("registered facts ({}):", report.registered_facts.len());
+    for registered in &report.registered_facts {
+        match registered {
+            RegisteredFact::MainPage(fact) => println!("  {:<16} {fact} (main page)", "memory page"),
+            RegisteredFact::Gps(gps_fact) => println!(
+                "  {:<16} {} (program output fact {}, {} pages)",
+                "gps",
+                gps_fact.fact,
+                gps_fact.program_output_fact,
+                gps_fact.pages_hashes.len()
+            ),
+        }
+    }
+
+    if let Some(ctx) = &report.ctx {
+        let words: Vec<String> = ctx.iter().map(U256::to_string).collect();
+        println!("final ctx: [{}]", words.join(","));
+    }
+}
+
+fn main() -> ExitCode {
+    let report = match parse_args().and_then(run) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("stark-verify: {err}");
+            return ExitCode::from(2);
+        }
+    };
+    print_report(&report);
+    match report.outcome() {
+        Outcome::Pass => ExitCode::SUCCESS,
+        Outcome::Unverified { .. } => ExitCode::from(EXIT_UNVERIFIED),
+        Outcome::Fail => ExitCode::FAILURE,
+    }
+}
@@ -0,0 +1,65 @@
+extern crate alloc;
+use alloc::{string::String, vec::Vec};
+
+use alloy_sol_types::sol;
+use stylus_sdk::{
+    alloy_primitives::{Address, FixedBytes},
+    prelude::*,
+    storage::StorageAddress,
+};
+
+use crate::{require, require::*};
+
+sol! {
+    // Emitted for every change of a contract address the contract calls into, with the code hash
+    // pinned for it.
+    event DependencySet(string name, address indexed previous, address indexed current, bytes32 codeHash);
+}
+
+// The code hashes (EXTCODEHASH) of the contracts a contract calls into, pinned when they are
+// wired, so a dependency that selfdestructs and is redeployed with other code, or a mock wired by
+// mistake, is caught instead of trusted. `check` costs an EXTCODEHASH and an SLOAD, so the
+// contracts only run it before the calls whose answer decides whether a proof is accepted: the
+// FRI and Merkle statements, the FRI statement verifier that reads them for the CPU verifier, and
+// the memory page fact registry. `check_all` (checkWiring) covers every dependency.
+sol_storage! {
+    pub struct Dependencies {
+        mapping(address => bytes32) code_hashes;
+    }
+}
+
+impl Dependencies {
+    // Sets one of the dependency addresses of the contract, pins its code hash and logs the change.
+    pub fn set(&mut self, dependency: &mut StorageAddress, name: &str, address: Address) {
+        let code_hash = self.vm().code_hash(address);
+        log(
+            self.vm(),
+            DependencySet { name: name.into(), previous: dependency.get(), current: address, codeHash: code_hash },
+        );
+        self.code_hashes.setter(address).set(code_hash);
+        dependency.set(address);
+    }
+
+    // Returns the address if its code is still the pinned one.
+    pub fn check(&self, address: Address) -> Result<Address, Vec<u8>> {
+        let expected = self.code_hashes.get(address);
+        let actual = self.vm().code_hash(address);
+        require!(actual == expected, DependencyCodeChanged { dependency: address, expected, actual });
+        Ok(address)
+    }
+
+    pub fn check_all(&self, dependencies: &[(&str, Address)]) -> Result<(), Vec<u8>> {
+        for &(_, address) in dependencies {
+            self.check(address)?;
+        }
+        Ok(())
+    }
+
+    // The names, addresses and pinned code hashes of the dependencies, for verifierConfig.
+    pub fn config(&self, dependencies: &[(&str, Address)]) -> (Vec<String>, Vec<Address>, Vec<FixedBytes<32>>) {
+        let names = dependencies.iter().map(|&(name, _)| name.into()).collect();
+        let addresses = dependencies.iter().map(|&(_, address)| address).collect();
+        let code_hashes = dependencies.iter().map(|&(_, address)| self.code_hashes.get(address)).collect();
+        (names, addresses, code_hashes)
+    }
+}
@@ -0,0 +1,36 @@
+extern crate alloc;
+use stylus_sdk::{
+    alloy_primitives::FixedBytes,
+    prelude::*,
+};
+
+sol_storage! {
+    pub struct FactRegistry {
+        mapping(bytes32 => bool) verified_fact;
+        bool any_fact_registered;
+    }
+}
+
+impl FactRegistry {
+    pub fn fact_check(&self, fact: FixedBytes<32>) -> bool {
+        self.verified_fact.get(fact)
+    }
+
+    pub fn register_fact(&mut self, fact: FixedBytes<32>) {
+        self.verified_fact.setter(fact).set(true);
+        if !self.any_fact_registered.get() {
+            self.any_fact_registered.set(true);
+        }
+    }
+}
+
+#[public]
+impl FactRegistry {
+    pub fn has_registered_fact(&self) -> bool {
+        self.any_fact_registered.get()
+    }
+
+    pub fn is_valid(&self, fact: FixedBytes<32>) -> bool {
+        self.fact_check(fact)
+    }
+}
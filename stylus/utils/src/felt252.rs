@@ -0,0 +1,249 @@
+extern crate alloc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use stylus_sdk::alloy_primitives::{uint, U256};
+
+// The STARK field prime, 2^251 + 17 * 2^192 + 1.
+pub const K_MODULUS: U256 = uint!(0x800000000000011000000000000000000000000000000000000000000000001_U256);
+// 2^256 mod K_MODULUS. The Solidity verifier keeps some values (e.g. the FRI queue and the
+// channel's field elements) in Montgomery form, x * K_MONTGOMERY_R.
+pub const K_MONTGOMERY_R: U256 = uint!(0x7fffffffffffdf0ffffffffffffffffffffffffffffffffffffffffffffffe1_U256);
+pub const K_MONTGOMERY_R_INV: U256 = uint!(0x40000000000001100000000000012100000000000000000000000000000000_U256);
+// The generator of the multiplicative group, a quadratic non-residue.
+pub const GENERATOR_VAL: U256 = uint!(3_U256);
+// K_MODULUS - 1 = 2^TWO_ADICITY * odd.
+const TWO_ADICITY: usize = 192;
+
+// An element of the STARK field in standard form. The value is always reduced, so two elements
+// are equal iff their values are. Use `from_montgomery`/`to_montgomery` at the boundaries where
+// values are exchanged in Montgomery form.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Felt252(U256);
+
+impl Felt252 {
+    pub const ZERO: Felt252 = Felt252(U256::ZERO);
+    pub const ONE: Felt252 = Felt252(uint!(1_U256));
+    pub const GENERATOR: Felt252 = Felt252(GENERATOR_VAL);
+
+    // Reduces value modulo K_MODULUS.
+    pub fn new(value: U256) -> Self {
+        Felt252(value.reduce_mod(K_MODULUS))
+    }
+
+    // The element whose Montgomery form is value, i.e. value / K_MONTGOMERY_R.
+    pub fn from_montgomery(value: U256) -> Self {
+        Felt252(value.mul_mod(K_MONTGOMERY_R_INV, K_MODULUS))
+    }
+
+    pub fn to_montgomery(self) -> U256 {
+        self.0.mul_mod(K_MONTGOMERY_R, K_MODULUS)
+    }
+
+    // The standard form of the element.
+    pub fn value(self) -> U256 {
+        self.0
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.0 == U256::ZERO
+    }
+
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    pub fn pow(self, exponent: U256) -> Self {
+        Felt252(self.0.pow_mod(exponent, K_MODULUS))
+    }
+
+    // Zero has no inverse and is mapped to zero, as PrimeFieldElement0.inverse does.
+    pub fn inverse(self) -> Self {
+        self.pow(K_MODULUS - U256::from(2))
+    }
+
+    // Inverts all the values with a single exponentiation (Montgomery's trick). Zeros are mapped
+    // to zero, like in `inverse`.
+    pub fn batch_inverse(values: &[Felt252]) -> Vec<Felt252> {
+        let mut prefix_products = Vec::with_capacity(values.len());
+        let mut acc = Felt252::ONE;
+        for value in values {
+            prefix_products.push(acc);
+            if !value.is_zero() {
+                acc *= *value;
+            }
+        }
+
+        let mut inverses = alloc::vec![Felt252::ZERO; values.len()];
+        let mut acc_inverse = acc.inverse();
+        for (i, value) in values.iter().enumerate().rev() {
+            if value.is_zero() {
+                continue;
+            }
+            inverses[i] = acc_inverse * prefix_products[i];
+            acc_inverse *= *value;
+        }
+        inverses
+    }
+
+    // The Legendre symbol: 1 for a non-zero square, -1 for a non-square and 0 for zero.
+    pub fn legendre(self) -> i8 {
+        let symbol = self.pow((K_MODULUS - U256::from(1)) >> 1);
+        if symbol.is_zero() {
+            0
+        } else if symbol == Felt252::ONE {
+            1
+        } else {
+            -1
+        }
+    }
+
+    // A square root of the element if it is a square, found with Tonelli-Shanks. The other root
+    // is its negation.
+    pub fn sqrt(self) -> Option<Self> {
+        match self.legendre() {
+            0 => return Some(Felt252::ZERO),
+            -1 => return None,
+            _ => {}
+        }
+
+        let odd_part = (K_MODULUS - U256::from(1)) >> TWO_ADICITY;
+        let mut m = TWO_ADICITY;
+        let mut c = Felt252::GENERATOR.pow(odd_part);
+        let mut t = self.pow(odd_part);
+        let mut root = self.pow((odd_part + U256::from(1)) >> 1);
+        while t != Felt252::ONE {
+            // The least i such that t^(2^i) == 1, i < m since t is a square.
+            let mut i = 0;
+            let mut t_pow = t;
+            while t_pow != Felt252::ONE {
+                t_pow = t_pow.square();
+                i += 1;
+            }
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b = b.square();
+            }
+            m = i;
+            c = b.square();
+            t *= c;
+            root *= b;
+        }
+        Some(root)
+    }
+}
+
+impl From<u64> for Felt252 {
+    fn from(value: u64) -> Self {
+        Felt252(U256::from(value))
+    }
+}
+
+impl From<Felt252> for U256 {
+    fn from(value: Felt252) -> U256 {
+        value.0
+    }
+}
+
+impl fmt::Display for Felt252 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Add for Felt252 {
+    type Output = Felt252;
+
+    fn add(self, rhs: Felt252) -> Felt252 {
+        Felt252(self.0.add_mod(rhs.0, K_MODULUS))
+    }
+}
+
+impl Sub for Felt252 {
+    type Output = Felt252;
+
+    fn sub(self, rhs: Felt252) -> Felt252 {
+        Felt252(self.0.add_mod(K_MODULUS - rhs.0, K_MODULUS))
+    }
+}
+
+impl Neg for Felt252 {
+    type Output = Felt252;
+
+    fn neg(self) -> Felt252 {
+        if self.is_zero() {
+            self
+        } else {
+            Felt252(K_MODULUS - self.0)
+        }
+    }
+}
+
+impl Mul for Felt252 {
+    type Output = Felt252;
+
+    fn mul(self, rhs: Felt252) -> Felt252 {
+        Felt252(self.0.mul_mod(rhs.0, K_MODULUS))
+    }
+}
+
+impl AddAssign for Felt252 {
+    fn add_assign(&mut self, rhs: Felt252) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Felt252 {
+    fn sub_assign(&mut self, rhs: Felt252) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Felt252 {
+    fn mul_assign(&mut self, rhs: Felt252) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_montgomery_round_trip() {
+        assert_eq!(K_MONTGOMERY_R.mul_mod(K_MONTGOMERY_R_INV, K_MODULUS), U256::from(1));
+        let x = Felt252::from(123456789);
+        assert_eq!(Felt252::from_montgomery(x.to_montgomery()), x);
+        assert_eq!(Felt252::from_montgomery(K_MONTGOMERY_R), Felt252::ONE);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let x = Felt252::new(K_MODULUS + U256::from(5));
+        assert_eq!(x, Felt252::from(5));
+        assert_eq!(Felt252::from(3) - x, -Felt252::from(2));
+        assert_eq!(x * x.inverse(), Felt252::ONE);
+        assert_eq!(Felt252::ZERO.inverse(), Felt252::ZERO);
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        let values = [Felt252::from(2), Felt252::ZERO, Felt252::from(7), -Felt252::ONE];
+        let inverses = Felt252::batch_inverse(&values);
+        for (value, inverse) in values.iter().zip(&inverses) {
+            assert_eq!(*inverse, value.inverse());
+        }
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(Felt252::GENERATOR.legendre(), -1);
+        assert_eq!(Felt252::GENERATOR.sqrt(), None);
+        assert_eq!(Felt252::ZERO.sqrt(), Some(Felt252::ZERO));
+
+        let x = Felt252::new(uint!(0x49ee3eba8c1600700ee1b87eb599f16716b0b1022947733551fde4050ca6804_U256));
+        let root = x.square().sqrt().unwrap();
+        assert!(root == x || root == -x);
+    }
+}
@@ -1,6 +1,16 @@
 #[path = "require.rs"]
 pub mod require;
+#[path = "felt252.rs"]
+pub mod felt252;
 #[path = "prime-field-element0.rs"]
 pub mod prime_field_element0;
 #[path = "public-memory-offset.rs"]
-pub mod public_memory_offset;
\ No newline at end of file
+pub mod public_memory_offset;
+#[path = "merkle-verifier.rs"]
+pub mod merkle_verifier;
+#[path = "fact-registry.rs"]
+pub mod fact_registry;
+#[path = "ownable.rs"]
+pub mod ownable;
+#[path = "dependencies.rs"]
+pub mod dependencies;
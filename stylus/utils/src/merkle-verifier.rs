@@ -0,0 +1,82 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use stylus_sdk::{
+    alloy_primitives::{uint, FixedBytes, U256},
+    crypto::keccak,
+};
+
+use crate::require;
+use crate::require::*;
+
+pub struct MerkleVerifier {}
+
+impl MerkleVerifier {
+    pub const MAX_N_MERKLE_VERIFIER_QUERIES: usize = 128;
+    // Commitments are masked to 160bit using the following mask to save gas costs.
+    pub const COMMITMENT_MASK: U256 = uint!(0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF000000000000000000000000_U256);
+
+    pub fn hash_node(left: U256, right: U256) -> U256 {
+        let mut input_data = Vec::with_capacity(64);
+        input_data.extend_from_slice(&left.to_be_bytes::<32>());
+        input_data.extend_from_slice(&right.to_be_bytes::<32>());
+        U256::from_be_bytes::<32>(keccak(&input_data).into()) & Self::COMMITMENT_MASK
+    }
+
+    // Verifies a Merkle tree decommitment for n leaves in a Merkle tree with N leaves.
+    // The queue holds n (index, hash) pairs, the indices need to be in the range [N..2*N-1]
+    // and strictly incrementing. Decommitments are read from proof starting at proof_ptr,
+    // which is advanced past the consumed nodes. The queue is destroyed during verification.
+    pub fn verify_merkle(
+        proof: &[U256],
+        proof_ptr: &mut usize,
+        queue: &mut [U256],
+        root: U256,
+        n: usize,
+    ) -> Result<U256, Vec<u8>> {
+        require!(n <= Self::MAX_N_MERKLE_VERIFIER_QUERIES, TooManyMerkleQueries { nQueries: U256::from(n) });
+        require!(n > 0 && queue.len() >= 2 * n, InvalidMerkleQueue {});
+
+        let mut rd_idx = 0;
+        let mut wr_idx = 0;
+        let mut index = queue[0];
+
+        while index > U256::from(1) {
+            let sibling_index = index ^ U256::from(1);
+            let hash = queue[2 * rd_idx + 1];
+            rd_idx = (rd_idx + 1) % n;
+
+            // Push index/2 into the queue before reading the next index, otherwise we may try to
+            // read from an empty queue when working on a single item.
+            queue[2 * wr_idx] = index >> 1;
+
+            index = queue[2 * rd_idx];
+            let sibling_hash = if index == sibling_index {
+                // Take the sibling from the queue rather than from the proof.
+                let sibling_hash = queue[2 * rd_idx + 1];
+                rd_idx = (rd_idx + 1) % n;
+                index = queue[2 * rd_idx];
+                sibling_hash
+            } else {
+                require!(*proof_ptr < proof.len(), MerkleDecommitmentTooShort {});
+                let sibling_hash = proof[*proof_ptr];
+                *proof_ptr += 1;
+                sibling_hash
+            };
+
+            queue[2 * wr_idx + 1] = if sibling_index.bit(0) {
+                Self::hash_node(hash, sibling_hash)
+            } else {
+                Self::hash_node(sibling_hash, hash)
+            };
+            wr_idx = (wr_idx + 1) % n;
+        }
+
+        let hash = queue[2 * rd_idx + 1];
+        require!(
+            hash == root,
+            InvalidMerkleProof { root: FixedBytes::from(hash), expectedRoot: FixedBytes::from(root) }
+        );
+        Ok(hash)
+    }
+}
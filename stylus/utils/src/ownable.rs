@@ -0,0 +1,99 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use alloy_sol_types::sol;
+use stylus_sdk::{alloy_primitives::Address, prelude::*};
+
+use crate::{require, require::*};
+
+sol! {
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+    event Paused(address account);
+    event Unpaused(address account);
+}
+
+// The owner, init-once flag and pause switch of the contracts that store the addresses of other
+// contracts. The contract borrows it, sets the owner from its constructor and calls `initialize`
+// from its init, so only the owner can run init, and only once.
+sol_storage! {
+    pub struct Ownable {
+        address owner;
+        bool initialized;
+        bool paused;
+    }
+}
+
+impl Ownable {
+    // Called from the constructor, which runs in the deployment transaction, so nobody can take
+    // the contract over between the deployment and init. The owner is an argument rather than the
+    // sender, since `cargo stylus deploy` runs the constructor from the StylusDeployer contract.
+    pub fn init_owner(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        require!(!owner.is_zero(), InvalidOwner { owner });
+        self.set_owner(owner);
+        Ok(())
+    }
+
+    pub fn initialize(&mut self) -> Result<(), Vec<u8>> {
+        self.only_owner()?;
+        require!(!self.initialized.get(), AlreadyInitialized {});
+        self.initialized.set(true);
+        Ok(())
+    }
+
+    pub fn only_owner(&self) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        require!(caller == self.owner.get(), OnlyOwner { caller });
+        Ok(())
+    }
+
+    pub fn only_initialized(&self) -> Result<(), Vec<u8>> {
+        require!(self.initialized.get(), NotInitialized {});
+        Ok(())
+    }
+
+    pub fn when_not_paused(&self) -> Result<(), Vec<u8>> {
+        require!(!self.paused.get(), ContractPaused {});
+        Ok(())
+    }
+
+    fn set_owner(&mut self, owner: Address) {
+        log(
+            self.vm(),
+            OwnershipTransferred { previousOwner: self.owner.get(), newOwner: owner },
+        );
+        self.owner.set(owner);
+    }
+}
+
+#[public]
+impl Ownable {
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    pub fn paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Vec<u8>> {
+        self.only_owner()?;
+        require!(!new_owner.is_zero(), InvalidOwner { owner: new_owner });
+        self.set_owner(new_owner);
+        Ok(())
+    }
+
+    // Stops the verification entrypoints of the contract until unpause is called.
+    pub fn pause(&mut self) -> Result<(), Vec<u8>> {
+        self.only_owner()?;
+        self.paused.set(true);
+        log(self.vm(), Paused { account: self.vm().msg_sender() });
+        Ok(())
+    }
+
+    pub fn unpause(&mut self) -> Result<(), Vec<u8>> {
+        self.only_owner()?;
+        self.paused.set(false);
+        log(self.vm(), Unpaused { account: self.vm().msg_sender() });
+        Ok(())
+    }
+}
@@ -1,13 +1,18 @@
 extern crate alloc;
 use stylus_sdk::alloy_primitives::{uint, U256};
 
+use crate::felt252;
+
+// Field operations on raw U256 values, the way PrimeFieldElement0.sol has them. New code should
+// use `Felt252`, which keeps the standard and the Montgomery form apart.
 pub struct PrimeFieldElement0 {}
 
 impl PrimeFieldElement0 {
+    // The largest multiple of K_MODULUS below 2^256, used by the channel for rejection sampling.
     pub const BOUND: U256 = uint!(0xf80000000000020f00000000000000000000000000000000000000000000001f_U256);
-    pub const K_MODULUS: U256 = uint!(0x800000000000011000000000000000000000000000000000000000000000001_U256);
-    pub const K_MONTGOMERY_R: U256 = uint!(0x7fffffffffffdf0ffffffffffffffffffffffffffffffffffffffffffffffe1_U256);
-    pub const K_MONTGOMERY_R_INV: U256 = uint!(0x40000000000001100000000000012100000000000000000000000000000000_U256);
+    pub const K_MODULUS: U256 = felt252::K_MODULUS;
+    pub const K_MONTGOMERY_R: U256 = felt252::K_MONTGOMERY_R;
+    pub const K_MONTGOMERY_R_INV: U256 = felt252::K_MONTGOMERY_R_INV;
     
     pub fn from_montgomery(val: U256) -> U256 {
         val.mul_mod(Self::K_MONTGOMERY_R_INV, Self::K_MODULUS)
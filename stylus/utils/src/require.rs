@@ -1,21 +1,153 @@
 extern crate alloc;
-use alloy_sol_types::sol;
-use stylus_sdk:: prelude::*;
+use alloc::vec::Vec;
+use alloy_sol_types::{sol, SolError};
+use stylus_sdk::prelude::*;
 
-sol! {
-    error GenericError();
+// The errors the contracts revert with. They are ABI encoded like Solidity custom errors, so a
+// caller can tell them apart by selector and read their parameters.
+macro_rules! stark_errors {
+    ($(error $name:ident($($ty:ident $param:ident),*);)*) => {
+        sol! {
+            $(
+                #[derive(Debug, PartialEq, Eq)]
+                error $name($($ty $param),*);
+            )*
+        }
+
+        #[derive(SolidityError, Debug, PartialEq, Eq)]
+        pub enum StarkError {
+            $($name($name),)*
+        }
+
+        impl StarkError {
+            // Decodes the revert data of a contract call, None if it is not one of our errors.
+            pub fn decode(data: &[u8]) -> Option<StarkError> {
+                $(
+                    if data.starts_with(&$name::SELECTOR) {
+                        return $name::abi_decode(data, true).ok().map(StarkError::$name);
+                    }
+                )*
+                None
+            }
+        }
+
+        $(
+            impl From<$name> for Vec<u8> {
+                fn from(err: $name) -> Vec<u8> {
+                    StarkError::from(err).into()
+                }
+            }
+        )*
+    };
 }
 
-#[derive(SolidityError)]
-pub enum StarkError {
-    GenericError(GenericError),
+stark_errors! {
+    error NotInitialized();
+    error AlreadyInitialized();
+    error OnlyOwner(address caller);
+    error InvalidOwner(address owner);
+    error ContractPaused();
+    // The code at a dependency address is not the code that was there when it was wired.
+    error DependencyCodeChanged(address dependency, bytes32 expected, bytes32 actual);
+    error OverflowProtection();
+    error DivisionByZero();
+    error NotDivisible(uint256 numerator, uint256 denominator);
+
+    // Proof params.
+    error InvalidProofParams();
+    error InvalidSecurityBits(uint256 numSecurityBits);
+    error InvalidBlowupFactor(uint256 logBlowupFactor);
+    error InvalidProofOfWorkBits(uint256 proofOfWorkBits);
+    error InvalidLastLayerDegBound(uint256 logLastLayerDegBound);
+    error InvalidNumberOfFriSteps(uint256 nFriSteps);
+    error InvalidNumberOfQueries(uint256 nQueries);
+    // securityBits = nQueries * logBlowupFactor + proofOfWorkBits.
+    error InsufficientSecurity(uint256 securityBits, uint256 numSecurityBits);
+    error InvalidFriStepSize(uint256 friStepSize);
+    error FriParamsMismatch(uint256 logDegBound, uint256 logTraceLength);
+
+    // Public input.
+    error PublicInputTooShort(uint256 length);
+    error PublicInputLengthMismatch(uint256 expected, uint256 actual);
+    error LayoutCodeMismatch(uint256 layoutCode);
+    error TooManySteps(uint256 logNSteps);
+    error InvalidRangeCheckBounds(uint256 rcMin, uint256 rcMax);
+    error InvalidInitialPc(uint256 pc);
+    error InvalidFinalPc(uint256 pc);
+    error InvalidNumberOfMemoryPages(uint256 nPages);
+    error TooManyPublicMemoryEntries(uint256 nEntries);
+    error InvalidSegment(uint256 beginAddr, uint256 stopPtr);
+
+    // Proof. The section of ProofTruncated is stark_verifier_core::proof::ProofSection.
+    error ProofTruncated(uint8 section, uint256 index);
+    error ProofOfWorkFailed(uint256 proofOfWorkBits);
+    error InvalidFieldElement();
+    error CompositionMismatch(uint256 fromTrace, uint256 claimed);
+    error MemoryPageNotRegistered(bytes32 factHash);
+    error TooManyColumns(uint256 nColumns, uint256 nTotalColumns);
+    error InvalidatedFriStatement(bytes32 statement);
+    error InvalidatedMerkleStatement(bytes32 statement);
+    error InvalidMask(uint256 mask);
+    error InvalidPolynomialSize(uint256 nCoefs);
+    error BatchInverseProductZero();
+    error InvalidPeriodicColumnValues(uint256 nValues);
+    // The byte offset at which the compact encoding of the input could not be decoded.
+    error InvalidCompactInput(uint256 offset);
+
+    // Merkle statements.
+    error TooManyMerkleQueries(uint256 nQueries);
+    error InvalidMerkleQueue();
+    error InvalidMerkleIndices();
+    error InvalidMerkleHeight(uint256 height);
+    error MerkleDecommitmentTooShort();
+    error InvalidMerkleProof(bytes32 root, bytes32 expectedRoot);
+
+    // FRI statements.
+    error InvalidEvalPoint(uint256 evalPoint);
+    error InvalidFriQueue();
+    // The index of the query in the FRI queue whose index, value or inverse point is invalid.
+    error InvalidFriQuery(uint256 query);
+    error InvalidQueriesRange();
+    error FriDecommitmentTooShort();
+
+    // Memory pages.
+    error TooManyMemoryValues(uint256 nValues);
+    error InvalidMemoryPairs();
+    error InvalidInteractionElement(uint256 value);
+    error PrimeTooBig(uint256 prime);
+    error InvalidStartAddr(uint256 startAddr);
+
+    // GPS statements.
+    error InvalidCairoAuxInput();
+    error InvalidMainPageSize(uint256 expected, uint256 actual);
+    error InvalidMainPageHash(uint256 expected, uint256 actual);
+    error InvalidMainPageProduct(uint256 expected, uint256 actual);
+    error InvalidNumberOfTasks(uint256 nTasks);
+    error InvalidExecutionBeginAddress(uint256 initialFp);
+    error SelectedBuiltinsVectorTooLong();
+    error InvalidTaskOutputSize(uint256 outputSize);
+    error InvalidTaskMetadata();
+    error InvalidMemoryPage(uint256 pageAddr, uint256 pageSize);
+    error OutputSizeMismatch(uint256 expected, uint256 actual);
+    error PublicMemoryLengthMismatch(uint256 expected, uint256 actual);
+    error UnknownCairoVerifier(uint256 cairoVerifierId);
+    error CairoVerifierDisabled(uint256 cairoVerifierId);
+    error CairoVerifierNotActive(uint256 cairoVerifierId, uint256 activationTime);
+
+    // Periodic column contract.
+    error ColumnSealed();
+    error ColumnNotSealed();
+    error InvalidCoefficient(uint256 coefficient);
+    error CoefficientsHashMismatch(bytes32 expected, bytes32 actual);
 }
 
+// Returns the error if the condition does not hold, e.g.
+// `require!(n_queries > 0, InvalidNumberOfQueries { nQueries: n_queries })`.
 #[macro_export]
 macro_rules! require {
-    ($cond:expr, $msg:expr) => {
+    ($cond:expr, $err:expr) => {
         if !$cond {
-            return Err(format!($msg).as_bytes().to_vec());
+            return Err($err.into());
         }
     };
 }
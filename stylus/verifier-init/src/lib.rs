@@ -5,213 +5,220 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 use stylus_sdk::{
-    alloy_primitives::{U256, uint},
+    alloy_primitives::{Address, U256},
     prelude::*,
+    storage::StorageU256,
 };
 
-use utils::{
-    require,
-    prime_field_element0::PrimeFieldElement0,
-    public_memory_offset::PublicMemoryOffset
-};
+use utils::{ownable::Ownable, require, require::*};
+pub use stark_verifier_core::layouts;
 
 #[storage]
 #[entrypoint]
-pub struct VerifierInit;
+pub struct VerifierInit {
+    #[borrow]
+    ownable: Ownable,
+    num_security_bits: StorageU256,
+}
 
 #[public]
+#[inherit(Ownable)]
 impl VerifierInit {
+    #[constructor]
+    pub fn constructor(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.ownable.init_owner(owner)
+    }
+
+    // Can only be called once, by the owner, who can later update the security level.
+    pub fn init(&mut self, num_security_bits: U256) -> Result<(), Vec<u8>> {
+        self.ownable.initialize()?;
+        self.set_num_security_bits(num_security_bits)
+    }
+
+    pub fn set_security_bits(&mut self, num_security_bits: U256) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner()?;
+        self.set_num_security_bits(num_security_bits)
+    }
+
+    pub fn get_security_bits(&self) -> U256 {
+        self.num_security_bits.get()
+    }
 
     #[inline]
     fn init_verifier_params(
-        &mut self,
+        &self,
         public_input: Vec<U256>,
         proof_params: Vec<U256>,
     ) -> Result<(Vec<U256>, Vec<U256>), Vec<u8>> {
-        require!(
-            proof_params.len() >= 5,
-            "Invalid proof params"
-        );
-       
-        require!(
-            proof_params.len() == (5 + proof_params[4].to::<usize>()),
-            "Invalid proofParams."
-        );
-        let log_blowup_factor = proof_params[1];
-        require!(log_blowup_factor <= U256::from(16), "logBlowupFactor must be at most 16");
-        require!(log_blowup_factor >= U256::from(1), "logBlowupFactor must be at least 1");
-        
-        let proof_of_work_bits = proof_params[2];
-        require!(proof_of_work_bits <= U256::from(50), "proofOfWorkBits must be at most 50");
-        require!(proof_of_work_bits >= U256::from(1), "minimum proofOfWorkBits not satisfied");
-        // require!(proof_of_work_bits < num_security_bits, "Proofs may not be purely based on PoW.");
-
-        let log_fri_last_layer_deg_bound = proof_params[3];
-        require!(log_fri_last_layer_deg_bound <= U256::from(10), "logFriLastLayerDegBound must be at most 10.");
-
-        let n_fri_steps = proof_params[4].to::<usize>();
-        require!(n_fri_steps <= 10, "Too many fri steps.");
-        require!(n_fri_steps > 1, "Not enough fri steps.");
-
-        let mut fri_step_sizes: Vec<U256> = Vec::new();
-        for i in 0..n_fri_steps {
-            fri_step_sizes.push(proof_params[5 + i]);
-        }
-
-        let (mut ctx, log_trace_length) = Self::air_specific_init(&public_input)?;
-        Self::validate_fri_params(&fri_step_sizes, log_trace_length, log_fri_last_layer_deg_bound)?;
-
-        ctx[315] = U256::from(1) << log_fri_last_layer_deg_bound;
-        ctx[324] = U256::from(1) << log_trace_length;
-        ctx[1] = U256::from(1) << log_blowup_factor;
-        ctx[3] = proof_of_work_bits;
-
-        let n_queries = proof_params[0];
-        require!(n_queries > U256::ZERO, "Number of queries must be at least one");
-        require!(n_queries <= U256::from(48), "Too many queries.");
-        // require!(
-        //     n_queries * log_blowup_factor + proof_of_work_bits >= num_security_bits,
-        //     "Proof params do not satisfy security requirements."
-        // );
-
-        ctx[9] = n_queries;
-        ctx[2] = log_trace_length + log_blowup_factor;
-        ctx[0] = U256::from(1) << ctx[2];
-
-        let gen_eval_domain = PrimeFieldElement0::fpow(U256::from(3), (PrimeFieldElement0::K_MODULUS - U256::from(1)) / ctx[0]);
-        ctx[4] = gen_eval_domain;
-        ctx[350] = PrimeFieldElement0::fpow(gen_eval_domain, ctx[1]);
-
-        Ok((ctx, fri_step_sizes))
+        // Until init, no security level is required from the proofs.
+        self.ownable.only_initialized()?;
+        stark_verifier_core::verifier_init::init_verifier_params(
+            &public_input,
+            &proof_params,
+            self.num_security_bits.get(),
+        )
     }
 }
 
 impl VerifierInit {
 
-    fn validate_fri_params(
-        fri_step_sizes: &[U256],
-        log_trace_length: U256,
-        log_fri_last_layer_deg_bound: U256,
-    ) -> Result<(), Vec<u8>> {
-        require!(fri_step_sizes[0] == U256::ZERO, "Only eta0 == 0 is currently supported");
-
-        let mut expected_log_deg_bound = log_fri_last_layer_deg_bound;
-        let n_fri_steps = fri_step_sizes.len();
-        for i in 1..n_fri_steps {
-            let fri_step_size = fri_step_sizes[i];
-            require!(fri_step_size >= U256::from(2), "Min supported fri step size is 2.");
-            require!(fri_step_size <= U256::from(4), "Max supported fri step size is 4.");
-            expected_log_deg_bound += fri_step_size;
-        }
-
-        require!(expected_log_deg_bound == log_trace_length, "Fri params do not match trace length");
+    fn set_num_security_bits(&mut self, num_security_bits: U256) -> Result<(), Vec<u8>> {
+        require!(
+            num_security_bits > U256::ZERO,
+            InvalidSecurityBits { numSecurityBits: num_security_bits }
+        );
+        self.num_security_bits.set(num_security_bits);
         Ok(())
     }
+}
 
-    fn air_specific_init(public_input: &[U256]) -> Result<(Vec<U256>, U256), Vec<u8>> {
-        require!(public_input.len() >= 22, "publicInput is too short.");
-        let mut ctx = vec![U256::ZERO; 1277];
-        ctx[325] = U256::from(65536);
-        ctx[326] = U256::from(32768);
-
-        let log_n_steps = public_input[1];
-        require!(log_n_steps < U256::from(50), "Number of steps is too large.");
-        ctx[1274] = log_n_steps;
-        let log_trace_length = log_n_steps + U256::from(4);
-        
-        ctx[336] = public_input[2];
-        ctx[337] = public_input[3];
-        require!(ctx[336] <= ctx[337], "rc_min must be <= rc_max");
-        require!(ctx[337] < ctx[325], "rc_max out of range");
-        require!(public_input[4] == uint!(42800643258479064999893963318903811951182475189843316_U256), "Layout code mismatch.");
-
-        ctx[328] = public_input[5];
-        ctx[330] = public_input[6];
-        require!(ctx[328] == U256::from(1), "Invalid initial pc");
-        require!(ctx[330] == U256::from(5), "Invalid final pc");
-
-        ctx[327] = public_input[7];
-        ctx[329] = public_input[8];
-        require!(public_input[21] >= U256::from(1) && public_input[21] < U256::from(100000), "Invalid number of memory pages.");
-
-        ctx[1276] = public_input[21];
-
-        let mut n_public_memory_entries = U256::from(0);
-        for page in 0..ctx[1276].to::<usize>() {
-            let n_page_entries = public_input[PublicMemoryOffset::get_offset_page_size(page)];
-            require!(n_page_entries < U256::from(1073741824), "Too many public memory entries in one page.");
-            n_public_memory_entries += n_page_entries;
-        }
-        ctx[1275] = n_public_memory_entries;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layouts::*;
+    use stark_verifier_core::memory_map::*;
+    use stylus_sdk::testing::*;
+
+    fn parse_input(input: &str) -> Vec<U256> {
+        input
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(',')
+            .map(|val| U256::from_str_radix(val.trim(), 10).unwrap())
+            .collect()
+    }
 
-        let expected_public_input_length = PublicMemoryOffset::get_public_input_length(ctx[1276].to::<usize>());
-        require!(expected_public_input_length == public_input.len(), "Public input length mismatch.");
+    fn verifier_params() -> (Vec<U256>, Vec<U256>) {
+        (
+            parse_input(include_str!("../../../inputs/public_input.txt")),
+            parse_input(include_str!("../../../inputs/proof_params.txt")),
+        )
+    }
 
-        Self::layout_specific_init(&mut ctx, public_input)?;
+    // The proof in inputs/ has 11 queries, a blowup of 2^6 and 30 proof of work bits:
+    // 11 * 6 + 30 = 96 bits of security.
+    const NUM_SECURITY_BITS: u64 = 96;
 
-        Ok((ctx, log_trace_length))
+    fn init_contract(vm: &TestVM) -> VerifierInit {
+        let mut contract = VerifierInit::from(vm);
+        contract.constructor(vm.msg_sender()).unwrap();
+        contract.init(U256::from(NUM_SECURITY_BITS)).unwrap();
+        contract
     }
 
-    fn layout_specific_init(ctx: &mut [U256], public_input: &[U256]) -> Result<(), Vec<u8>> {
-        let output_begin_addr = public_input[9];
-        let output_stop_ptr = public_input[10];
-        require!(output_begin_addr <= output_stop_ptr, "output begin_addr must be <= stop_ptr");
-        require!(output_stop_ptr < uint!(18446744073709551616_U256), "Out of range output stop_ptr.");
+    #[motsu::test]
+    fn test_init_before_owner() {
+        let vm = TestVM::default();
+        let mut contract = VerifierInit::from(&vm);
+        contract.constructor(vm.msg_sender()).unwrap();
+        let owner = vm.msg_sender();
+        let (public_input, proof_params) = verifier_params();
+        let err = contract.init_verifier_params(public_input, proof_params).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::NotInitialized(NotInitialized {})));
+
+        // A front-runner cannot set a low security level and take over setSecurityBits.
+        let attacker = Address::repeat_byte(0x11);
+        vm.set_sender(attacker);
+        let err = contract.init(U256::from(1)).unwrap_err();
+        assert_eq!(StarkError::decode(&err), Some(StarkError::OnlyOwner(OnlyOwner { caller: attacker })));
+
+        vm.set_sender(owner);
+        contract.init(U256::from(NUM_SECURITY_BITS)).unwrap();
+        assert_eq!(contract.ownable.owner(), owner);
+        assert_eq!(contract.get_security_bits(), U256::from(NUM_SECURITY_BITS));
+    }
 
-        let n_steps = U256::from(1) << ctx[1274];
-        ctx[346] = public_input[11];
-        Self::validate_builtin_pointers(ctx[346], public_input[12], U256::from(128), U256::from(3), n_steps)?;
+    #[motsu::test]
+    fn test_init_verifier_params_layout7() {
+        let vm = TestVM::default();
+        let contract = init_contract(&vm);
+        let (public_input, proof_params) = verifier_params();
+        let (ctx, fri_step_sizes) = contract
+            .init_verifier_params(public_input.clone(), proof_params)
+            .unwrap();
+
+        assert_eq!(ctx.len(), LAYOUT7.context_size);
+        assert_eq!(fri_step_sizes.len(), 8);
+        // log_n_steps + log_cpu_component_height.
+        assert_eq!(ctx[LAYOUT7.ctx.trace_length], U256::from(1) << 26);
+        assert_eq!(ctx[LAYOUT7.ctx.n_public_mem_pages], public_input[21]);
+        for builtin in LAYOUT7.builtins {
+            assert_eq!(ctx[builtin.ctx_initial_addr], public_input[builtin.begin_addr_offset]);
+        }
+        assert_eq!(ctx[MM_PEDERSEN__SHIFT_POINT_X], PEDERSEN_SHIFT_POINT_X);
+        assert_eq!(ctx[MM_PEDERSEN__SHIFT_POINT_Y], PEDERSEN_SHIFT_POINT_Y);
+    }
 
-        ctx[344] = uint!(2089986280348253421170679821480865132823066470938446095505822317253594081284_U256);
-        ctx[345] = uint!(1713931329540660377023406109199410414810705867260802078187082345529207694986_U256);
-        ctx[347] = public_input[13];
-        Self::validate_builtin_pointers(ctx[347], public_input[14], U256::from(8), U256::from(1), n_steps)?;
+    #[motsu::test]
+    fn test_init_verifier_params_unknown_layout() {
+        let vm = TestVM::default();
+        let contract = init_contract(&vm);
+        let (mut public_input, proof_params) = verifier_params();
+        public_input[OFFSET_LAYOUT_CODE] += U256::from(1);
+        let layout_code = public_input[OFFSET_LAYOUT_CODE];
+        let err = contract
+            .init_verifier_params(public_input, proof_params)
+            .unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::LayoutCodeMismatch(LayoutCodeMismatch {
+                layoutCode: layout_code
+            }))
+        );
+    }
 
-        ctx[335] = U256::from(1);
-        ctx[348] = public_input[15];
-        Self::validate_builtin_pointers(ctx[348], public_input[16], U256::from(8), U256::from(5), n_steps)?;
+    #[motsu::test]
+    fn test_init_verifier_params_rejects_low_security() {
+        let vm = TestVM::default();
+        let contract = init_contract(&vm);
+        let (public_input, mut proof_params) = verifier_params();
+        proof_params[0] = U256::from(1);
+        let err = contract
+            .init_verifier_params(public_input, proof_params)
+            .unwrap_err();
+        assert!(matches!(
+            StarkError::decode(&err),
+            Some(StarkError::InsufficientSecurity(_))
+        ));
+    }
 
-        ctx[339] = U256::from(1);
-        ctx[340] = U256::from(0);
+    #[motsu::test]
+    fn test_init_verifier_params_rejects_pow_only() {
+        let vm = TestVM::default();
+        let mut contract = init_contract(&vm);
+        contract.set_security_bits(U256::from(30)).unwrap();
+        let (public_input, proof_params) = verifier_params();
+        let err = contract
+            .init_verifier_params(public_input, proof_params)
+            .unwrap_err();
+        assert!(matches!(
+            StarkError::decode(&err),
+            Some(StarkError::InvalidProofOfWorkBits(_))
+        ));
+    }
 
-        ctx[349] = public_input[17];
-        Self::validate_builtin_pointers(ctx[349], public_input[18], U256::from(8), U256::from(6), n_steps)?;
+    #[motsu::test]
+    fn test_set_security_bits() {
+        let vm = TestVM::default();
+        let mut contract = init_contract(&vm);
+        assert_eq!(contract.get_security_bits(), U256::from(NUM_SECURITY_BITS));
 
-        Ok(())
-    }
+        contract.set_security_bits(U256::from(80)).unwrap();
+        assert_eq!(contract.get_security_bits(), U256::from(80));
 
-    fn validate_builtin_pointers(
-        initial_address: U256,
-        stop_address: U256,
-        builtin_ratio: U256,
-        cells_per_instance: U256,
-        n_steps: U256
-    ) -> Result<(), Vec<u8>> {
-        require!(
-            initial_address < uint!(18446744073709551616_U256),
-            "Out of range begin_addr."
-        );
-        let max_stop_ptr = initial_address + cells_per_instance * Self::safe_div(n_steps, builtin_ratio)?;
-        require!(
-            initial_address <= stop_address && stop_address <= max_stop_ptr,
-            "Invalid stop_ptr."
+        let err = contract.init(U256::from(NUM_SECURITY_BITS)).unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::AlreadyInitialized(AlreadyInitialized {}))
         );
-        Ok(())
-    }
 
-    fn safe_div(
-        numerator: U256,
-        denominator: U256
-    ) -> Result<U256, Vec<u8>> {
-        require!(
-            denominator != U256::ZERO,
-            "The denominator must not be zero"
-        );
-        require!(
-            numerator % denominator == U256::ZERO,
-            "The numerator is not divisible by the denominator."
+        vm.set_sender(Address::repeat_byte(0x11));
+        let err = contract.set_security_bits(U256::from(1)).unwrap_err();
+        assert_eq!(
+            StarkError::decode(&err),
+            Some(StarkError::OnlyOwner(OnlyOwner { caller: Address::repeat_byte(0x11) }))
         );
-        Ok(numerator / denominator)
+        assert_eq!(contract.get_security_bits(), U256::from(80));
     }
-
-}
\ No newline at end of file
+}
@@ -1,4 +1,4 @@
-pk=0xb6b15c8cb491557369f3c7d2c287b053eb229daa9c22138887752191c9520659
+pk=${PRIVATE_KEY:?export PRIVATE_KEY, the key of the account that sends the proofs}
 rpc_url=http://localhost:8547
 
 PROOF_PARAMS=$(tr '\n' ' ' < ../inputs/proof_params.txt)